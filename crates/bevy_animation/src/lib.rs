@@ -38,7 +38,7 @@ use crate::{
 };
 
 use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
-use bevy_asset::{Asset, AssetApp, AssetEventSystems, Assets};
+use bevy_asset::{memory::AssetMemorySize, Asset, AssetApp, AssetEventSystems, Assets};
 use bevy_ecs::{prelude::*, resource::IsResource, world::EntityMutExcept};
 use bevy_math::FloatOrd;
 use bevy_platform::{collections::HashMap, hash::NoOpHash};
@@ -214,6 +214,34 @@ impl Hash for AnimationTargetId {
 #[reflect(Component, Clone)]
 pub struct AnimatedBy(#[entities] pub Entity);
 
+impl AssetMemorySize for AnimationClip {
+    /// Estimates the memory used by the curve and event storage of this clip.
+    ///
+    /// Curves are type-erased, so only the inline size of each curve is counted, not any keyframes it
+    /// stores on the heap.
+    fn memory_size(&self) -> usize {
+        let curves: usize = self
+            .curves
+            .values()
+            .map(|curves| {
+                size_of::<AnimationTargetId>()
+                    + curves
+                        .iter()
+                        .map(|curve| size_of::<VariableCurve>() + size_of_val(&*curve.0))
+                        .sum::<usize>()
+            })
+            .sum();
+        let events: usize = self
+            .events
+            .values()
+            .map(|events| {
+                size_of::<AnimationEventTarget>() + events.len() * size_of::<TimedAnimationEvent>()
+            })
+            .sum();
+        size_of::<Self>() + curves + events
+    }
+}
+
 impl AnimationClip {
     #[inline]
     /// [`VariableCurve`]s for each animation target. Indexed by the [`AnimationTargetId`].
@@ -1280,6 +1308,7 @@ pub struct AnimationPlugin;
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<AnimationClip>()
+            .init_asset_memory_tracking::<AnimationClip>()
             .init_asset::<AnimationGraph>()
             .init_asset_loader::<AnimationGraphAssetLoader>()
             .register_asset_reflect::<AnimationClip>()
@@ -11,6 +11,7 @@ pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
     error::{ErrorHandler, FallbackErrorHandler},
+    index::IndexableComponent,
     intern::Interned,
     message::{message_update_system, MessageCursor},
     observer::IntoObserver,
@@ -976,6 +977,18 @@ impl App {
         self
     }
 
+    /// Registers a [`ComponentIndex`] for the component `C`, which can then be used through the [`Index`]
+    /// system parameter to look up entities by the value of their `C` component.
+    ///
+    /// See [`World::register_index`] for more details.
+    ///
+    /// [`ComponentIndex`]: bevy_ecs::index::ComponentIndex
+    /// [`Index`]: bevy_ecs::index::Index
+    pub fn register_index<C: IndexableComponent>(&mut self) -> &mut Self {
+        self.world_mut().register_index::<C>();
+        self
+    }
+
     /// Registers the given component `R` as a [required component] for `T`.
     ///
     /// When `T` is added to an entity, `R` and its own required components will also be added
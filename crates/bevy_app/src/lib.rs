@@ -33,6 +33,7 @@ mod plugin_group;
 mod propagate;
 mod schedule_runner;
 mod sub_app;
+mod sub_world;
 mod task_pool_plugin;
 #[cfg(all(any(all(unix, not(target_os = "horizon")), windows), feature = "std"))]
 mod terminal_ctrl_c_handler;
@@ -49,6 +50,7 @@ pub use plugin_group::*;
 pub use propagate::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
+pub use sub_world::*;
 pub use task_pool_plugin::*;
 #[cfg(all(any(all(unix, not(target_os = "horizon")), windows), feature = "std"))]
 pub use terminal_ctrl_c_handler::*;
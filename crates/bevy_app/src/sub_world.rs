@@ -0,0 +1,297 @@
+use crate::{App, Plugin, PluginsState, SubApp, Update};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use bevy_ecs::{message::MessageId, prelude::*};
+use bevy_platform::cell::SyncCell;
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+#[cfg(feature = "trace")]
+use tracing::info_span;
+
+/// Adds the [`SubWorlds`] resource, which runs isolated simulation worlds side by side with the main world.
+///
+/// Every sub-world has its own [`World`], schedules and plugins, and is updated once per update of the main
+/// world, in the [`SubWorldsSystems`] set of the [`Update`] schedule. Sub-worlds are independent of each other,
+/// so they are updated in parallel on the [`ComputeTaskPool`]. Sub-worlds can be created and destroyed at
+/// any time, for example one per game room on a server.
+///
+/// Messages are sent to a sub-world with [`SubWorlds::send`], where they are read as regular [`Message`]s.
+/// Sub-worlds send messages back with the [`ParentWorld`] resource, and the main world reads them as
+/// [`FromSubWorld`] messages.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::{prelude::*, FromSubWorld, ParentWorld, SubWorlds, SubWorldsPlugin};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Message)]
+/// struct PlayerJoined(u32);
+///
+/// #[derive(Message)]
+/// struct MatchEnded {
+///     winner: u32,
+/// }
+///
+/// fn end_match(mut joined: MessageReader<PlayerJoined>, mut parent: ResMut<ParentWorld>) {
+///     for PlayerJoined(player) in joined.read() {
+///         parent.send(MatchEnded { winner: *player });
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(SubWorldsPlugin)
+///     .add_message::<FromSubWorld<MatchEnded>>();
+///
+/// // Create a sub-world for a new match.
+/// let mut room = App::new();
+/// room.add_message::<PlayerJoined>()
+///     .add_systems(Update, end_match);
+/// let mut sub_worlds = app.world_mut().resource_mut::<SubWorlds>();
+/// let id = sub_worlds.spawn(room);
+/// sub_worlds.send(id, PlayerJoined(7));
+///
+/// app.update();
+///
+/// let messages = app.world().resource::<Messages<FromSubWorld<MatchEnded>>>();
+/// let ended = messages.iter_current_update_messages().next().unwrap();
+/// assert_eq!(ended.world, id);
+/// assert_eq!(ended.message.winner, 7);
+/// ```
+#[derive(Default)]
+pub struct SubWorldsPlugin;
+
+impl Plugin for SubWorldsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SubWorlds>()
+            .add_systems(Update, update_sub_worlds.in_set(SubWorldsSystems));
+    }
+}
+
+/// The [`SystemSet`] of the system that updates the [`SubWorlds`].
+///
+/// Messages sent to sub-worlds before this set are read by the sub-worlds in the same update.
+/// [`FromSubWorld`] messages are written to the main world during this set.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubWorldsSystems;
+
+/// The identifier of a sub-world in [`SubWorlds`].
+///
+/// Identifiers are never reused by the same [`SubWorlds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubWorldId(u64);
+
+/// A [`Resource`] of the main world that owns the sub-worlds created at runtime. See [`SubWorldsPlugin`].
+#[derive(Resource, Default)]
+pub struct SubWorlds {
+    // `SubApp` is not `Sync`, but it is only ever accessed mutably from here.
+    sub_apps: BTreeMap<SubWorldId, SyncCell<SubApp>>,
+    next_id: u64,
+}
+
+impl SubWorlds {
+    /// Adds the main world of `app` as a new sub-world, and returns its identifier.
+    ///
+    /// Plugins of `app` are finished and cleaned up if they are ready. Other sub-apps of `app` are dropped.
+    /// If the main world has no update schedule, it will never be updated, but can still receive messages.
+    pub fn spawn(&mut self, mut app: App) -> SubWorldId {
+        if app.plugins_state() == PluginsState::Ready {
+            app.finish();
+            app.cleanup();
+        }
+        let mut sub_app = core::mem::take(app.main_mut());
+
+        let id = SubWorldId(self.next_id);
+        self.next_id += 1;
+        sub_app.insert_resource(ParentWorld {
+            id,
+            outbox: Vec::new(),
+        });
+        self.sub_apps.insert(id, SyncCell::new(sub_app));
+        id
+    }
+
+    /// Removes the sub-world with the given `id`, and returns it, if it exists.
+    ///
+    /// Messages it sent since its last update are dropped.
+    pub fn despawn(&mut self, id: SubWorldId) -> Option<SubApp> {
+        self.sub_apps.remove(&id).map(SyncCell::to_inner)
+    }
+
+    /// Returns the sub-world with the given `id`, if it exists.
+    pub fn get_mut(&mut self, id: SubWorldId) -> Option<&mut SubApp> {
+        self.sub_apps.get_mut(&id).map(SyncCell::get)
+    }
+
+    /// Returns `true` if a sub-world with the given `id` exists.
+    pub fn contains(&self, id: SubWorldId) -> bool {
+        self.sub_apps.contains_key(&id)
+    }
+
+    /// Returns the number of sub-worlds.
+    pub fn len(&self) -> usize {
+        self.sub_apps.len()
+    }
+
+    /// Returns `true` if there are no sub-worlds.
+    pub fn is_empty(&self) -> bool {
+        self.sub_apps.is_empty()
+    }
+
+    /// Returns an iterator over the identifiers of the sub-worlds, in the order they were spawned.
+    pub fn ids(&self) -> impl Iterator<Item = SubWorldId> + '_ {
+        self.sub_apps.keys().copied()
+    }
+
+    /// Returns a mutable iterator over the sub-worlds and their identifiers, in the order they were spawned.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SubWorldId, &mut SubApp)> {
+        self.sub_apps
+            .iter_mut()
+            .map(|(&id, sub_app)| (id, sub_app.get()))
+    }
+
+    /// Writes `message` to the sub-world with the given `id`.
+    ///
+    /// The message type must have been added to the sub-world with [`App::add_message`].
+    /// Returns `None` if the sub-world does not exist or the message could not be written.
+    pub fn send<M: Message>(&mut self, id: SubWorldId, message: M) -> Option<MessageId<M>> {
+        self.sub_apps
+            .get_mut(&id)?
+            .get()
+            .world_mut()
+            .write_message(message)
+    }
+
+    /// Writes a clone of `message` to every sub-world.
+    pub fn broadcast<M: Message + Clone>(&mut self, message: M) {
+        for sub_app in self.sub_apps.values_mut() {
+            sub_app.get().world_mut().write_message(message.clone());
+        }
+    }
+}
+
+type SendFn = Box<dyn FnOnce(&mut World, SubWorldId) + Send + Sync>;
+
+/// A [`Resource`] of every sub-world in [`SubWorlds`], used to send messages to the main world.
+#[derive(Resource)]
+pub struct ParentWorld {
+    id: SubWorldId,
+    outbox: Vec<SendFn>,
+}
+
+impl ParentWorld {
+    /// Returns the identifier of this sub-world.
+    pub fn id(&self) -> SubWorldId {
+        self.id
+    }
+
+    /// Sends `message` to the main world, where it is written as a [`FromSubWorld<M>`] message after this
+    /// sub-world is updated.
+    ///
+    /// [`FromSubWorld<M>`] must have been added to the main app with [`App::add_message`].
+    pub fn send<M: Message>(&mut self, message: M) {
+        self.outbox.push(Box::new(move |world, id| {
+            world.write_message(FromSubWorld { world: id, message });
+        }));
+    }
+}
+
+/// A [`Message`] sent to the main world by a sub-world with [`ParentWorld::send`].
+#[derive(Debug, Clone)]
+pub struct FromSubWorld<M> {
+    /// The sub-world that sent the message.
+    pub world: SubWorldId,
+    /// The message.
+    pub message: M,
+}
+
+impl<M: Message> Message for FromSubWorld<M> {}
+
+/// Updates every sub-world in parallel, then writes the messages they sent to the main world.
+fn update_sub_worlds(world: &mut World) {
+    world.resource_scope(|world, mut sub_worlds: Mut<SubWorlds>| {
+        ComputeTaskPool::get_or_init(TaskPool::default).scope(|scope| {
+            for (_id, sub_app) in sub_worlds.iter_mut() {
+                scope.spawn(async move {
+                    #[cfg(feature = "trace")]
+                    let _sub_world_span = info_span!("sub world", id = _id.0).entered();
+                    sub_app.update();
+                });
+            }
+        });
+
+        // Messages are written in the order the sub-worlds were spawned, regardless of which finished first.
+        for (id, sub_app) in sub_worlds.iter_mut() {
+            let Some(mut parent) = sub_app.world_mut().get_resource_mut::<ParentWorld>() else {
+                continue;
+            };
+            for send in core::mem::take(&mut parent.outbox) {
+                send(world, id);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromSubWorld, ParentWorld, SubWorlds, SubWorldsPlugin};
+    use crate::{App, Update};
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::prelude::*;
+
+    #[derive(Message, Clone)]
+    struct Ping(u32);
+
+    #[derive(Resource, Default)]
+    struct Count(u32);
+
+    #[test]
+    fn sub_worlds_exchange_messages() {
+        let mut app = App::new();
+        app.add_plugins(SubWorldsPlugin)
+            .add_message::<FromSubWorld<Ping>>();
+
+        let new_room = || {
+            let mut room = App::new();
+            room.add_message::<Ping>()
+                .init_resource::<Count>()
+                .add_systems(
+                    Update,
+                    |mut pings: MessageReader<Ping>,
+                     mut count: ResMut<Count>,
+                     mut parent: ResMut<ParentWorld>| {
+                        for ping in pings.read() {
+                            count.0 += 1;
+                            parent.send(Ping(ping.0 + 1));
+                        }
+                    },
+                );
+            room
+        };
+
+        let mut sub_worlds = app.world_mut().resource_mut::<SubWorlds>();
+        let a = sub_worlds.spawn(new_room());
+        let b = sub_worlds.spawn(new_room());
+        assert_ne!(a, b);
+        sub_worlds.broadcast(Ping(0));
+        sub_worlds.send(b, Ping(10));
+
+        app.update();
+
+        let replies: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<FromSubWorld<Ping>>>()
+            .drain()
+            .map(|reply| (reply.world, reply.message.0))
+            .collect();
+        assert_eq!(replies, vec![(a, 1), (b, 1), (b, 11)]);
+
+        let mut sub_worlds = app.world_mut().resource_mut::<SubWorlds>();
+        assert_eq!(
+            sub_worlds.get_mut(b).unwrap().world().resource::<Count>().0,
+            2
+        );
+        let room = sub_worlds.despawn(a).unwrap();
+        assert_eq!(room.world().resource::<Count>().0, 1);
+        assert!(!sub_worlds.contains(a));
+        assert_eq!(sub_worlds.len(), 1);
+    }
+}
@@ -1,6 +1,7 @@
-use crate::{App, Plugin};
+use crate::{App, Last, Plugin};
 
 use alloc::string::ToString;
+use bevy_ecs::world::{apply_async_world_requests, AsyncWorldRequests};
 use bevy_platform::sync::Arc;
 use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
 use core::fmt::Debug;
@@ -8,8 +9,8 @@ use log::trace;
 
 cfg_select! {
     not(all(target_arch = "wasm32", feature = "web")) => {
-        use {crate::Last, bevy_tasks::tick_global_task_pools_on_main_thread};
-        use bevy_ecs::system::NonSendMarker;
+        use bevy_tasks::tick_global_task_pools_on_main_thread;
+        use bevy_ecs::{schedule::IntoScheduleConfigs, system::NonSendMarker};
 
         /// A system used to check and advanced our task pools.
         ///
@@ -23,6 +24,9 @@ cfg_select! {
 }
 
 /// Setup of default task pools: [`AsyncComputeTaskPool`], [`ComputeTaskPool`], [`IoTaskPool`].
+///
+/// Also initializes the [`AsyncWorldRequests`] resource, whose [`AsyncWorld`](bevy_ecs::world::AsyncWorld) handles
+/// let tasks access the world. Their requests are applied in [`Last`].
 #[derive(Default)]
 pub struct TaskPoolPlugin {
     /// Options for the [`TaskPool`](bevy_tasks::TaskPool) created at application start.
@@ -30,12 +34,20 @@ pub struct TaskPoolPlugin {
 }
 
 impl Plugin for TaskPoolPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, app: &mut App) {
         // Setup the default bevy task pools
         self.task_pool_options.create_default_pools();
 
+        // Requests made by tasks through `AsyncWorld` handles are applied at the end of every frame, right before
+        // the task pools are ticked so that local tasks can make progress in the same frame.
+        app.init_resource::<AsyncWorldRequests>()
+            .add_systems(Last, apply_async_world_requests);
+
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-        _app.add_systems(Last, tick_global_task_pools);
+        app.add_systems(
+            Last,
+            tick_global_task_pools.after(apply_async_world_requests),
+        );
     }
 }
 
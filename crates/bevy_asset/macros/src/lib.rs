@@ -5,13 +5,14 @@
 use bevy_macro_utils::{as_member, BevyManifest};
 use proc_macro::{Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Path};
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, LitStr, Path};
 
 pub(crate) fn bevy_asset_path() -> Path {
     BevyManifest::shared(|manifest| manifest.get_path("bevy_asset"))
 }
 
 const DEPENDENCY_ATTRIBUTE: &str = "dependency";
+const ASSET_ATTRIBUTE: &str = "asset";
 
 /// Implement the `Asset` trait.
 #[proc_macro_derive(Asset, attributes(dependency))]
@@ -108,3 +109,113 @@ fn derive_dependency_visitor_internal(
         }
     })
 }
+
+/// How a field of an `AssetCollection` is loaded.
+enum CollectionMember {
+    /// `#[asset(path = "...")]`: loads a single asset into a `Handle<T>`.
+    Path(LitStr),
+    /// `#[asset(folder = "...")]`: loads a folder into a `Handle<LoadedFolder>`.
+    Folder(LitStr),
+    /// `#[asset(glob = "...")]`: loads the files matching a glob pattern into a `Handle<LoadedFolder>`.
+    Glob(LitStr),
+}
+
+/// Implement the `AssetCollection` trait.
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs",
+        ));
+    };
+
+    let mut field_loaders = Vec::new();
+    let mut member_visitors = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let member = as_member(field.ident.as_ref(), i);
+        let mut collection_member = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+        {
+            attr.parse_nested_meta(|meta| {
+                if collection_member.is_some() {
+                    return Err(
+                        meta.error("only one of `path`, `folder` or `glob` may be specified")
+                    );
+                }
+                if meta.path.is_ident("path") {
+                    collection_member = Some(CollectionMember::Path(meta.value()?.parse()?));
+                    Ok(())
+                } else if meta.path.is_ident("folder") {
+                    collection_member = Some(CollectionMember::Folder(meta.value()?.parse()?));
+                    Ok(())
+                } else if meta.path.is_ident("glob") {
+                    collection_member = Some(CollectionMember::Glob(meta.value()?.parse()?));
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `path`, `folder` or `glob`"))
+                }
+            })?;
+        }
+
+        let Some(collection_member) = collection_member else {
+            field_loaders.push(quote!(#member: ::core::default::Default::default()));
+            continue;
+        };
+        let loader = match collection_member {
+            CollectionMember::Path(path) => quote!(asset_server.load(#path)),
+            CollectionMember::Folder(path) => quote!(asset_server.load_folder(#path)),
+            CollectionMember::Glob(pattern) => quote!(asset_server.load_glob(#pattern)),
+        };
+        field_loaders.push(quote!(#member: #loader));
+        let member_name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => i.to_string(),
+        };
+        member_visitors.push(quote!(
+            visit(#member_name, #bevy_asset_path::UntypedAssetId::from(&self.#member));
+        ));
+    }
+
+    // prevent unused variable warnings in case there are no members
+    let (asset_server, visit) = if member_visitors.is_empty() {
+        (quote!(_asset_server), quote!(_visit))
+    } else {
+        (quote!(asset_server), quote!(visit))
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load(#asset_server: &#bevy_asset_path::AssetServer) -> Self {
+                Self {
+                    #(#field_loaders,)*
+                }
+            }
+
+            fn visit_members(
+                &self,
+                #visit: &mut impl ::core::ops::FnMut(&'static str, #bevy_asset_path::UntypedAssetId),
+            ) {
+                #(#member_visitors)*
+            }
+        }
+    })
+}
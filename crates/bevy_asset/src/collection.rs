@@ -0,0 +1,277 @@
+//! Collections of assets that are loaded as a single unit.
+//!
+//! See [`AssetCollection`] for more information.
+
+use crate::{
+    AssetLoadError, AssetPath, AssetServer, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use alloc::{sync::Arc, vec::Vec};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::SystemSet,
+    system::{Res, ResMut},
+};
+use core::marker::PhantomData;
+use disqualified::ShortName;
+use tracing::error;
+
+/// A [`Resource`] holding a fixed set of handles that are loaded together.
+///
+/// This is usually derived. Each field annotated with `#[asset(path = "...")]` is loaded as a [`Handle`](crate::Handle)
+/// of the field's asset type, and each field annotated with `#[asset(folder = "...")]` is loaded as a
+/// `Handle<LoadedFolder>` using [`AssetServer::load_folder`]. Fields annotated with `#[asset(glob = "...")]` load the
+/// matching files as a `Handle<LoadedFolder>` using [`AssetServer::load_glob`]. Fields without an `#[asset]` attribute
+/// are initialized with [`Default::default`] and are not tracked.
+///
+/// ```
+/// # use bevy_asset::{prelude::*, AssetCollection, LoadedFolder};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::TypePath;
+/// # #[derive(Asset, TypePath)]
+/// # struct Image;
+/// # #[derive(Asset, TypePath)]
+/// # struct Font;
+/// #[derive(Resource, AssetCollection)]
+/// struct UiAssets {
+///     #[asset(path = "textures/button.png")]
+///     button: Handle<Image>,
+///     #[asset(path = "fonts/FiraSans-Bold.ttf")]
+///     font: Handle<Font>,
+///     #[asset(folder = "textures/icons")]
+///     icons: Handle<LoadedFolder>,
+///     #[asset(glob = "textures/backgrounds/**/*.png")]
+///     backgrounds: Handle<LoadedFolder>,
+/// }
+/// ```
+///
+/// Register a collection with [`AssetApp::init_asset_collection`](crate::AssetApp::init_asset_collection) to start
+/// loading it immediately, or with `AssetApp::init_asset_collection_in_state` (behind the `bevy_state` feature) to
+/// load it when entering a loading state and advance to the next state once it has finished loading.
+///
+/// Loading progress is reported by the [`AssetCollectionProgress`] resource.
+pub trait AssetCollection: Resource + Sized {
+    /// Starts loading every member of the collection.
+    fn load(asset_server: &AssetServer) -> Self;
+
+    /// Calls `visit` with the name and id of every member of the collection.
+    fn visit_members(&self, visit: &mut impl FnMut(&'static str, UntypedAssetId));
+}
+
+/// A member of an [`AssetCollection`] that failed to load.
+#[derive(Debug, Clone)]
+pub struct AssetCollectionFailure {
+    /// The name of the field the member is stored in.
+    pub member: &'static str,
+    /// The path of the member, if it has one.
+    pub path: Option<AssetPath<'static>>,
+    /// The error that caused the member, or one of its dependencies, to fail.
+    pub error: Arc<AssetLoadError>,
+}
+
+/// The loading progress of the [`AssetCollection`] `C`.
+///
+/// A member counts as loaded once it and all of its recursive dependencies are loaded.
+#[derive(Resource, Debug)]
+pub struct AssetCollectionProgress<C: AssetCollection> {
+    loaded: usize,
+    total: usize,
+    failures: Vec<AssetCollectionFailure>,
+    /// Whether the progress has been updated since the collection started loading.
+    updated: bool,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: AssetCollection> Default for AssetCollectionProgress<C> {
+    fn default() -> Self {
+        Self {
+            loaded: 0,
+            total: 0,
+            failures: Vec::new(),
+            updated: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: AssetCollection> AssetCollectionProgress<C> {
+    /// The number of members that have finished loading, including their dependencies.
+    pub fn loaded(&self) -> usize {
+        self.loaded
+    }
+
+    /// The total number of members in the collection.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The fraction of members that have finished loading, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+
+    /// The members that failed to load and have not been loaded successfully since.
+    pub fn failures(&self) -> &[AssetCollectionFailure] {
+        &self.failures
+    }
+
+    /// Returns `true` if any member failed to load.
+    pub fn is_failed(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    /// Returns `true` if every member has finished loading.
+    pub fn is_loaded(&self) -> bool {
+        self.updated && self.loaded == self.total
+    }
+
+    /// Updates the progress of `collection`, logging members that have newly failed to load.
+    pub fn update(
+        collection: Option<Res<C>>,
+        asset_server: Res<AssetServer>,
+        mut progress: ResMut<Self>,
+    ) {
+        let Some(collection) = collection else {
+            return;
+        };
+        let progress = &mut *progress;
+        progress.loaded = 0;
+        progress.total = 0;
+        progress.updated = true;
+        collection.visit_members(&mut |member, id| {
+            progress.total += 1;
+            let error = match asset_server.get_load_states(id) {
+                Some((LoadState::Loaded, _, RecursiveDependencyLoadState::Loaded)) => {
+                    progress.loaded += 1;
+                    // The member may have failed before and been reloaded since.
+                    progress.failures.retain(|failure| failure.member != member);
+                    return;
+                }
+                Some((LoadState::Failed(error), _, _))
+                | Some((_, _, RecursiveDependencyLoadState::Failed(error))) => error,
+                _ => return,
+            };
+            if progress
+                .failures
+                .iter()
+                .any(|failure| failure.member == member)
+            {
+                return;
+            }
+            let path = asset_server.get_path(id).map(AssetPath::into_owned);
+            error!(
+                "Member `{member}` of asset collection {} failed to load: {error}",
+                ShortName::of::<C>()
+            );
+            progress.failures.push(AssetCollectionFailure {
+                member,
+                path,
+                error,
+            });
+        });
+    }
+}
+
+/// A run condition that returns `true` once every member of the [`AssetCollection`] `C` has finished loading.
+pub fn asset_collection_loaded<C: AssetCollection>(
+    progress: Option<Res<AssetCollectionProgress<C>>>,
+) -> bool {
+    progress.is_some_and(|progress| progress.is_loaded())
+}
+
+/// A system set that updates every [`AssetCollectionProgress`].
+///
+/// This runs in [`PreUpdate`](bevy_app::PreUpdate), after [`AssetTrackingSystems`](crate::AssetTrackingSystems).
+#[derive(SystemSet, Hash, Debug, PartialEq, Eq, Clone)]
+pub struct AssetCollectionSystems;
+
+#[cfg(feature = "bevy_state")]
+pub use loading_state::*;
+
+#[cfg(feature = "bevy_state")]
+mod loading_state {
+    use super::{AssetCollection, AssetCollectionProgress};
+    use crate::AssetServer;
+    use alloc::vec::Vec;
+    use bevy_ecs::{
+        resource::Resource,
+        system::{Commands, Res},
+        world::World,
+    };
+    use bevy_platform::collections::HashMap;
+    use bevy_state::state::{FreelyMutableState, NextState, State};
+
+    type IsCollectionLoaded = fn(&World) -> bool;
+
+    /// The loading states of `S` that were registered with
+    /// [`AssetApp::init_asset_collection_in_state`](crate::AssetApp::init_asset_collection_in_state).
+    ///
+    /// While in a loading state, [`advance_loading_states`] moves to the next state once all of the state's
+    /// [`AssetCollection`]s have finished loading.
+    #[derive(Resource)]
+    pub struct AssetLoadingStates<S: FreelyMutableState> {
+        states: HashMap<S, (S, Vec<IsCollectionLoaded>)>,
+    }
+
+    impl<S: FreelyMutableState> Default for AssetLoadingStates<S> {
+        fn default() -> Self {
+            Self {
+                states: HashMap::default(),
+            }
+        }
+    }
+
+    impl<S: FreelyMutableState> AssetLoadingStates<S> {
+        /// Adds the [`AssetCollection`] `C` to the `loading` state, which advances to `next` when finished.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `loading` was already registered with a different `next` state.
+        pub(crate) fn add<C: AssetCollection>(&mut self, loading: S, next: S) {
+            let (registered_next, collections) = self
+                .states
+                .entry(loading.clone())
+                .or_insert_with(|| (next.clone(), Vec::new()));
+            assert!(
+                *registered_next == next,
+                "Loading state {loading:?} advances to {registered_next:?}, but another asset collection requested {next:?}",
+            );
+            collections.push(|world| {
+                world
+                    .get_resource::<AssetCollectionProgress<C>>()
+                    .is_some_and(AssetCollectionProgress::is_loaded)
+            });
+        }
+    }
+
+    /// Starts loading the [`AssetCollection`] `C`, replacing any existing instance and resetting its progress.
+    pub(crate) fn load_asset_collection<C: AssetCollection>(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+    ) {
+        commands.insert_resource(C::load(&asset_server));
+        commands.insert_resource(AssetCollectionProgress::<C>::default());
+    }
+
+    /// Moves from the current loading state of `S` to its next state once all of its [`AssetCollection`]s have
+    /// finished loading.
+    pub fn advance_loading_states<S: FreelyMutableState>(world: &mut World) {
+        let Some(state) = world.get_resource::<State<S>>() else {
+            return;
+        };
+        let Some((next, collections)) = world
+            .resource::<AssetLoadingStates<S>>()
+            .states
+            .get(state.get())
+        else {
+            return;
+        };
+        if collections.iter().all(|is_loaded| is_loaded(world)) {
+            let next = next.clone();
+            world.resource_mut::<NextState<S>>().set(next);
+        }
+    }
+}
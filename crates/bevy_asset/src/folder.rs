@@ -1,11 +1,14 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+use core::any::TypeId;
 
-use crate::{Asset, UntypedHandle};
+use crate::{Asset, ErasedLoadedAsset, Handle, LoadedAsset, UntypedHandle};
 use bevy_reflect::TypePath;
 
 /// A "loaded folder" containing handles for all assets stored in a given [`AssetPath`].
 ///
-/// This is produced by [`AssetServer::load_folder`](crate::prelude::AssetServer::load_folder).
+/// This is produced by [`AssetServer::load_folder`](crate::prelude::AssetServer::load_folder),
+/// [`AssetServer::load_folder_with`](crate::prelude::AssetServer::load_folder_with) and
+/// [`AssetServer::load_glob`](crate::prelude::AssetServer::load_glob).
 ///
 /// [`AssetPath`]: crate::AssetPath
 #[derive(Asset, TypePath)]
@@ -14,3 +17,236 @@ pub struct LoadedFolder {
     #[dependency]
     pub handles: Vec<UntypedHandle>,
 }
+
+impl LoadedFolder {
+    /// Returns an iterator over the handles in the folder whose asset type is `A`.
+    pub fn iter_typed<A: Asset>(&self) -> impl Iterator<Item = Handle<A>> + '_ {
+        self.handles
+            .iter()
+            .filter_map(|handle| handle.clone().try_typed::<A>().ok())
+    }
+}
+
+/// A "loaded folder" containing typed handles for the assets of type `A` stored in a given [`AssetPath`].
+///
+/// This is produced by [`AssetServer::load_typed_folder`](crate::prelude::AssetServer::load_typed_folder). The asset
+/// type must be registered with `app.init_asset::<TypedLoadedFolder<A>>()` before loading typed folders of `A`.
+///
+/// [`AssetPath`]: crate::AssetPath
+#[derive(Asset, TypePath)]
+pub struct TypedLoadedFolder<A: Asset> {
+    /// The handles of all assets of type `A` stored in the folder.
+    #[dependency]
+    pub handles: Vec<Handle<A>>,
+}
+
+/// Creates the folder asset from the handles of the assets loaded into it.
+pub(crate) type BuildFolder = fn(Vec<UntypedHandle>) -> ErasedLoadedAsset;
+
+/// Builds a [`LoadedFolder`].
+pub(crate) fn build_loaded_folder(handles: Vec<UntypedHandle>) -> ErasedLoadedAsset {
+    LoadedAsset::new_with_dependencies(LoadedFolder { handles }).into()
+}
+
+/// Builds a [`TypedLoadedFolder`], dropping any handle that is not of type `A`.
+pub(crate) fn build_typed_loaded_folder<A: Asset>(
+    handles: Vec<UntypedHandle>,
+) -> ErasedLoadedAsset {
+    let handles = handles
+        .into_iter()
+        .filter_map(|handle| handle.try_typed::<A>().ok())
+        .collect();
+    LoadedAsset::new_with_dependencies(TypedLoadedFolder { handles }).into()
+}
+
+/// Selects which files are loaded by [`AssetServer::load_folder_with`](crate::prelude::AssetServer::load_folder_with).
+///
+/// By default, every file in the folder and its subfolders is loaded, and the folder fails to load if one of them
+/// has no registered [`AssetLoader`](crate::AssetLoader), just like with
+/// [`AssetServer::load_folder`](crate::prelude::AssetServer::load_folder). Each filter narrows this down further.
+/// Files that are filtered out are never read, so they do not produce any errors.
+///
+/// ```
+/// # use bevy_asset::{prelude::*, FolderFilter};
+/// # use bevy_reflect::TypePath;
+/// # #[derive(Asset, TypePath)]
+/// # struct Image;
+/// fn load_sprites(asset_server: Res<AssetServer>) {
+///     let sprites = asset_server.load_folder_with(
+///         "sprites",
+///         FolderFilter::new()
+///             .with_glob("**/*.png")
+///             .with_asset_type::<Image>(),
+///     );
+/// }
+/// # use bevy_ecs::prelude::*;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FolderFilter {
+    glob: Option<String>,
+    extensions: Vec<String>,
+    asset_type: Option<TypeId>,
+    non_recursive: bool,
+    skip_missing_loaders: bool,
+}
+
+impl FolderFilter {
+    /// Creates a filter that matches every loadable file in the folder and its subfolders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only loads files whose path, relative to the loaded folder, matches the glob `pattern`.
+    ///
+    /// Path segments are separated by `/`. Within a segment, `*` matches any number of characters and `?` matches a
+    /// single character. A `**` segment matches any number of nested folders, including none.
+    pub fn with_glob(mut self, pattern: impl Into<String>) -> Self {
+        self.glob = Some(pattern.into());
+        self
+    }
+
+    /// Only loads files with the given extension, such as `"png"` or `"cool.ron"`.
+    ///
+    /// This can be called multiple times to allow several extensions.
+    pub fn with_extension(mut self, extension: impl Into<String>) -> Self {
+        self.extensions.push(extension.into());
+        self
+    }
+
+    /// Only loads files whose [`AssetLoader`](crate::AssetLoader) produces assets of type `A`.
+    pub fn with_asset_type<A: Asset>(mut self) -> Self {
+        self.asset_type = Some(TypeId::of::<A>());
+        self
+    }
+
+    /// Only loads files directly in the folder, skipping its subfolders.
+    pub fn non_recursive(mut self) -> Self {
+        self.non_recursive = true;
+        self
+    }
+
+    /// Skips files that no [`AssetLoader`](crate::AssetLoader) can load, instead of failing to load the folder.
+    ///
+    /// This is implied by [`FolderFilter::with_asset_type`], since only files with a matching loader are loaded.
+    pub fn skip_missing_loaders(mut self) -> Self {
+        self.skip_missing_loaders = true;
+        self
+    }
+
+    /// Returns `true` if files without a matching [`AssetLoader`](crate::AssetLoader) should be skipped.
+    pub(crate) fn skips_missing_loaders(&self) -> bool {
+        self.skip_missing_loaders
+    }
+
+    /// Returns `true` if subfolders should be loaded.
+    pub(crate) fn is_recursive(&self) -> bool {
+        !self.non_recursive
+    }
+
+    /// The type of asset that files must be loaded as, if any.
+    pub(crate) fn asset_type(&self) -> Option<TypeId> {
+        self.asset_type
+    }
+
+    /// Returns `true` if the file at `relative_path` (relative to the loaded folder) passes the glob and extension
+    /// filters.
+    pub(crate) fn matches_path(&self, relative_path: &str) -> bool {
+        if let Some(glob) = &self.glob
+            && !glob_matches(glob, relative_path)
+        {
+            return false;
+        }
+        if self.extensions.is_empty() {
+            return true;
+        }
+        let file_name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+        self.extensions.iter().any(|extension| {
+            file_name
+                .strip_suffix(extension.as_str())
+                .is_some_and(|stem| stem.ends_with('.') && stem.len() > 1)
+        })
+    }
+}
+
+/// Splits `path` at the first segment containing a glob wildcard, returning the folder to load and the remaining
+/// pattern. Returns `None` if `path` does not contain any wildcards.
+pub(crate) fn split_glob(path: &str) -> Option<(&str, &str)> {
+    let wildcard = path.find(['*', '?'])?;
+    match path[..wildcard].rfind('/') {
+        Some(separator) => Some((&path[..separator], &path[separator + 1..])),
+        None => Some(("", path)),
+    }
+}
+
+/// Returns `true` if `path` matches the glob `pattern`. See [`FolderFilter::with_glob`] for the supported syntax.
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    segments_match(&pattern, &path)
+}
+
+fn segments_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| segments_match(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                segment_matches(segment.as_bytes(), name.as_bytes())
+                    && segments_match(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn segment_matches(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|skip| segment_matches(rest, &name[skip..])),
+        Some((b'?', rest)) => !name.is_empty() && segment_matches(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && segment_matches(rest, &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob_matches, split_glob, FolderFilter};
+
+    #[test]
+    fn glob_matching() {
+        assert!(glob_matches("*.png", "a.png"));
+        assert!(!glob_matches("*.png", "dir/a.png"));
+        assert!(glob_matches("**/*.png", "a.png"));
+        assert!(glob_matches("**/*.png", "dir/nested/a.png"));
+        assert!(!glob_matches("**/*.png", "dir/a.png.meta"));
+        assert!(glob_matches("dir/?.png", "dir/a.png"));
+        assert!(!glob_matches("dir/?.png", "dir/ab.png"));
+        assert!(glob_matches("dir/**", "dir/nested/a.txt"));
+    }
+
+    #[test]
+    fn glob_splitting() {
+        assert_eq!(
+            split_glob("sprites/**/*.png"),
+            Some(("sprites", "**/*.png"))
+        );
+        assert_eq!(
+            split_glob("sprites/a*/b.png"),
+            Some(("sprites", "a*/b.png"))
+        );
+        assert_eq!(split_glob("*.png"), Some(("", "*.png")));
+        assert_eq!(split_glob("sprites/a.png"), None);
+    }
+
+    #[test]
+    fn extension_filter() {
+        let filter = FolderFilter::new()
+            .with_extension("png")
+            .with_extension("cool.ron");
+        assert!(filter.matches_path("dir/a.png"));
+        assert!(filter.matches_path("a.cool.ron"));
+        assert!(!filter.matches_path("a.ron"));
+        assert!(!filter.matches_path("a.blend"));
+        assert!(!filter.matches_path("apng"));
+    }
+}
@@ -152,6 +152,7 @@ extern crate self as bevy_asset;
 
 pub mod asset_changed;
 pub mod io;
+pub mod memory;
 pub mod meta;
 pub mod processor;
 pub mod saver;
@@ -172,6 +173,7 @@ pub mod prelude {
 }
 
 mod assets;
+mod collection;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -185,8 +187,9 @@ mod render_asset;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::{Asset, VisitAssetDependencies};
+pub use bevy_asset_macros::{Asset, AssetCollection, VisitAssetDependencies};
 use bevy_diagnostic::{Diagnostic, DiagnosticsStore, RegisterDiagnostic};
+pub use collection::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -204,7 +207,10 @@ pub use uuid;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
-    processor::{AssetProcessor, Process},
+    memory::{
+        publish_asset_memory_diagnostics, AssetMemorySize, AssetMemoryTracker, AssetMemoryUsage,
+    },
+    processor::{AssetProcessor, AssetValidator, Process},
 };
 use alloc::{
     boxed::Box,
@@ -222,6 +228,8 @@ use bevy_ecs::{
 };
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
+#[cfg(feature = "bevy_state")]
+use bevy_state::state::{FreelyMutableState, OnEnter};
 use core::any::TypeId;
 use tracing::error;
 
@@ -397,7 +405,8 @@ impl Plugin for AssetPlugin {
                             self.unapproved_path_mode.clone(),
                         ))
                         .insert_resource(processor)
-                        .add_systems(bevy_app::Startup, AssetProcessor::start);
+                        .add_systems(bevy_app::Startup, AssetProcessor::start)
+                        .add_systems(bevy_app::Last, AssetProcessor::exit_on_validation_failure);
                     } else {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
                         let sources = builders.build_sources(false, watch);
@@ -435,7 +444,20 @@ impl Plugin for AssetPlugin {
                 )
                     .chain(),
             )
-            .register_diagnostic(Diagnostic::new(AssetServer::STARTED_LOAD_COUNT));
+            .register_diagnostic(Diagnostic::new(AssetServer::STARTED_LOAD_COUNT))
+            .init_resource::<AssetMemoryUsage>()
+            .configure_sets(PostUpdate, AssetMemorySystems.after(AssetEventSystems))
+            .add_systems(
+                PostUpdate,
+                publish_asset_memory_diagnostics
+                    .run_if(resource_exists::<DiagnosticsStore>)
+                    .after(AssetMemorySystems),
+            )
+            .register_diagnostic(Diagnostic::new(AssetMemoryUsage::TOTAL).with_suffix(" bytes"))
+            .configure_sets(
+                PreUpdate,
+                AssetCollectionSystems.after(AssetTrackingSystems),
+            );
     }
 }
 
@@ -573,6 +595,8 @@ pub trait AssetApp {
     fn register_asset_loader<L: AssetLoader>(&mut self, loader: L) -> &mut Self;
     /// Registers the given `processor` in the [`App`]'s [`AssetProcessor`].
     fn register_asset_processor<P: Process>(&mut self, processor: P) -> &mut Self;
+    /// Registers the given [`AssetValidator`] in the [`App`]'s [`AssetProcessor`].
+    fn register_asset_validator<V: AssetValidator>(&mut self, validator: V) -> &mut Self;
     /// Registers the given [`AssetSourceBuilder`] with the given `id`.
     ///
     /// Note that asset sources must be registered before adding [`AssetPlugin`] to your application,
@@ -604,6 +628,33 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Enables memory accounting for the given [`Asset`] by:
+    /// * Adding an [`AssetMemoryTracker`] resource, which reports sizes and can enforce a memory budget
+    /// * Recording the memory used by the [`Asset`] in the [`AssetMemoryUsage`] resource
+    /// * Publishing the memory used by the [`Asset`] to [`bevy_diagnostic`] under [`AssetMemoryUsage::diagnostic_path`]
+    ///
+    /// The [`Asset`] must already have been initialized with [`AssetApp::init_asset`].
+    fn init_asset_memory_tracking<A: Asset + AssetMemorySize>(&mut self) -> &mut Self;
+    /// Starts loading the given [`AssetCollection`] by:
+    /// * Inserting the [`AssetCollection`] resource, which holds the handles of its members
+    /// * Adding an [`AssetCollectionProgress`] resource, which reports how many members have loaded and which have failed
+    fn init_asset_collection<C: AssetCollection>(&mut self) -> &mut Self;
+    /// Loads the given [`AssetCollection`] whenever the `loading` state is entered, and sets the state to `next`
+    /// once every [`AssetCollection`] registered for the `loading` state has finished loading.
+    ///
+    /// The [`AssetCollection`] resource is not removed when leaving the `loading` state, so it can be used in `next`.
+    ///
+    /// The state must be initialized separately, for example with `App::init_state`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `loading` state was already registered with a different `next` state.
+    #[cfg(feature = "bevy_state")]
+    fn init_asset_collection_in_state<C: AssetCollection, S: FreelyMutableState>(
+        &mut self,
+        loading: S,
+        next: S,
+    ) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -621,6 +672,13 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_validator<V: AssetValidator>(&mut self, validator: V) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.register_validator(validator);
+        }
+        self
+    }
+
     fn register_asset_source(
         &mut self,
         id: impl Into<AssetSourceId<'static>>,
@@ -713,6 +771,73 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn init_asset_collection<C: AssetCollection>(&mut self) -> &mut Self {
+        let collection = C::load(self.world().resource::<AssetServer>());
+        self.insert_resource(collection);
+        if !self
+            .world()
+            .contains_resource::<AssetCollectionProgress<C>>()
+        {
+            add_asset_collection_systems::<C>(self);
+        }
+        self.insert_resource(AssetCollectionProgress::<C>::default())
+    }
+
+    #[cfg(feature = "bevy_state")]
+    fn init_asset_collection_in_state<C: AssetCollection, S: FreelyMutableState>(
+        &mut self,
+        loading: S,
+        next: S,
+    ) -> &mut Self {
+        if !self.world().contains_resource::<AssetLoadingStates<S>>() {
+            self.init_resource::<AssetLoadingStates<S>>().add_systems(
+                PreUpdate,
+                advance_loading_states::<S>.after(AssetCollectionSystems),
+            );
+        }
+        self.world_mut()
+            .resource_mut::<AssetLoadingStates<S>>()
+            .add::<C>(loading.clone(), next);
+        if !self
+            .world()
+            .contains_resource::<AssetCollectionProgress<C>>()
+        {
+            add_asset_collection_systems::<C>(self);
+            self.init_resource::<AssetCollectionProgress<C>>();
+        }
+        self.add_systems(OnEnter(loading), load_asset_collection::<C>)
+    }
+
+    fn init_asset_memory_tracking<A: Asset + AssetMemorySize>(&mut self) -> &mut Self {
+        if self.world().contains_resource::<AssetMemoryTracker<A>>() {
+            return self;
+        }
+        self.init_resource::<AssetMemoryTracker<A>>()
+            .register_diagnostic(
+                Diagnostic::new(AssetMemoryUsage::diagnostic_path::<A>()).with_suffix(" bytes"),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    AssetMemoryTracker::<A>::update,
+                    AssetMemoryTracker::<A>::publish_diagnostics
+                        .run_if(resource_exists::<DiagnosticsStore>),
+                )
+                    .chain()
+                    .in_set(AssetMemorySystems),
+            )
+    }
+}
+
+/// Adds the systems that track the loading progress of the [`AssetCollection`] `C`.
+fn add_asset_collection_systems<C: AssetCollection>(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        AssetCollectionProgress::<C>::update
+            .run_if(resource_exists::<C>)
+            .in_set(AssetCollectionSystems),
+    );
 }
 
 /// A system set that holds all "track asset" operations.
@@ -725,10 +850,14 @@ pub struct AssetTrackingSystems;
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub struct AssetEventSystems;
 
+/// A system set where [`AssetMemoryTracker`]s are updated and assets over their memory budget are evicted.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct AssetMemorySystems;
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        folder::LoadedFolder,
+        folder::{FolderFilter, LoadedFolder, TypedLoadedFolder},
         handle::Handle,
         io::{
             gated::{GateOpener, GatedReader},
@@ -737,9 +866,11 @@ mod tests {
             AssetWatcher, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, InvalidGenerationError, LoadState, LoadedAsset,
-        UnapprovedPathMode, UntypedHandle, VisitAssetDependencies, WriteDefaultMetaError,
+        memory::{AssetMemorySize, AssetMemoryTracker, AssetMemoryUsage},
+        Asset, AssetApp, AssetCollection, AssetCollectionProgress, AssetEvent, AssetId,
+        AssetLoadError, AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetServer, Assets,
+        InvalidGenerationError, LoadState, LoadedAsset, UnapprovedPathMode, UntypedHandle,
+        VisitAssetDependencies, WriteDefaultMetaError,
     };
     use alloc::{
         boxed::Box,
@@ -2734,6 +2865,247 @@ mod tests {
         assert_eq!(get_started_load_count(app.world()), 2);
     }
 
+    impl AssetMemorySize for CoolText {
+        fn memory_size(&self) -> usize {
+            self.text.len()
+        }
+    }
+
+    #[test]
+    fn memory_budget_evicts_and_reloads_assets() {
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(Path::new("a.cool.ron"), &serialize_as_cool_text("aaaa"));
+        dir.insert_asset_text(Path::new("b.cool.ron"), &serialize_as_cool_text("bbbb"));
+
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .init_asset_memory_tracking::<CoolText>();
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        run_app_until(&mut app, |world| get::<CoolText>(world, a.id()).map(|_| ()));
+
+        assert_eq!(
+            app.world()
+                .resource::<AssetMemoryTracker<CoolText>>()
+                .size(&a),
+            Some(4)
+        );
+        assert_eq!(
+            app.world().resource::<AssetMemoryUsage>().get::<CoolText>(),
+            Some(4)
+        );
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<DiagnosticsStore>()
+                .get_measurement(&AssetMemoryUsage::TOTAL)
+                .map(|measurement| measurement.value),
+            Some(4.0)
+        );
+
+        // Loading `b` exceeds the budget, so the least recently used asset is evicted.
+        app.world_mut()
+            .resource_mut::<AssetMemoryTracker<CoolText>>()
+            .set_budget(Some(6));
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        run_app_until(&mut app, |world| get::<CoolText>(world, b.id()).map(|_| ()));
+
+        assert!(get::<CoolText>(app.world(), a.id()).is_none());
+        let mut tracker = app
+            .world_mut()
+            .resource_mut::<AssetMemoryTracker<CoolText>>();
+        assert!(tracker.is_evicted(&a));
+        assert_eq!(tracker.total(), 4);
+
+        // Requesting the evicted asset reloads it from its path.
+        tracker.set_budget(None);
+        assert!(!tracker.request(&a));
+        run_app_until(&mut app, |world| get::<CoolText>(world, a.id()).map(|_| ()));
+
+        let tracker = app.world().resource::<AssetMemoryTracker<CoolText>>();
+        assert!(!tracker.is_evicted(&a));
+        assert_eq!(tracker.total(), 8);
+    }
+
+    #[derive(Resource, AssetCollection)]
+    struct CoolTextCollection {
+        #[asset(path = "a.cool.ron")]
+        a: Handle<CoolText>,
+        #[asset(folder = "folder")]
+        folder: Handle<LoadedFolder>,
+        #[asset(path = "missing.cool.ron")]
+        missing: Handle<CoolText>,
+        untracked: Handle<CoolText>,
+    }
+
+    #[test]
+    fn asset_collection_reports_progress_and_failures() {
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(Path::new("a.cool.ron"), &serialize_as_cool_text("a"));
+        dir.insert_asset_text(Path::new("folder/b.cool.ron"), &serialize_as_cool_text("b"));
+        dir.insert_asset_text(Path::new("folder/c.cool.ron"), &serialize_as_cool_text("c"));
+
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .init_asset_collection::<CoolTextCollection>();
+
+        let collection = app.world().resource::<CoolTextCollection>();
+        assert_eq!(collection.untracked, Handle::default());
+        let a = collection.a.id();
+
+        run_app_until(&mut app, |world| {
+            let progress = world.resource::<AssetCollectionProgress<CoolTextCollection>>();
+            (progress.loaded() == 2 && progress.is_failed()).then_some(())
+        });
+
+        assert!(get::<CoolText>(app.world(), a).is_some());
+        let progress = app
+            .world()
+            .resource::<AssetCollectionProgress<CoolTextCollection>>();
+        assert_eq!(progress.total(), 3);
+        assert!(!progress.is_loaded());
+        let [failure] = progress.failures() else {
+            panic!("Expected exactly one failure");
+        };
+        assert_eq!(failure.member, "missing");
+        assert_eq!(failure.path, Some(AssetPath::from("missing.cool.ron")));
+
+        // Once the missing member is added and reloaded, its failure is cleared.
+        dir.insert_asset_text(
+            Path::new("missing.cool.ron"),
+            &serialize_as_cool_text("missing"),
+        );
+        app.world()
+            .resource::<AssetServer>()
+            .reload("missing.cool.ron");
+        run_app_until(&mut app, |world| {
+            let progress = world.resource::<AssetCollectionProgress<CoolTextCollection>>();
+            progress.is_loaded().then_some(())
+        });
+        let progress = app
+            .world()
+            .resource::<AssetCollectionProgress<CoolTextCollection>>();
+        assert!(!progress.is_failed());
+        assert_eq!(progress.loaded(), 3);
+    }
+
+    #[cfg(feature = "bevy_state")]
+    #[test]
+    fn asset_collection_advances_loading_state() {
+        use bevy_state::{
+            app::{AppExtStates, StatesPlugin},
+            state::{State, States},
+        };
+
+        #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+        enum GameState {
+            #[default]
+            Loading,
+            InGame,
+        }
+
+        #[derive(Resource, AssetCollection)]
+        struct LevelTexts {
+            #[asset(path = "a.cool.ron")]
+            a: Handle<CoolText>,
+            #[asset(folder = "folder")]
+            folder: Handle<LoadedFolder>,
+        }
+
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(Path::new("a.cool.ron"), &serialize_as_cool_text("a"));
+        dir.insert_asset_text(Path::new("folder/b.cool.ron"), &serialize_as_cool_text("b"));
+
+        app.add_plugins(StatesPlugin)
+            .init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .init_state::<GameState>()
+            .init_asset_collection_in_state::<LevelTexts, _>(GameState::Loading, GameState::InGame);
+
+        run_app_until(&mut app, |world| {
+            (*world.resource::<State<GameState>>().get() == GameState::InGame).then_some(())
+        });
+
+        let collection = app.world().resource::<LevelTexts>();
+        let asset_server = app.world().resource::<AssetServer>();
+        assert!(asset_server.is_loaded_with_dependencies(&collection.a));
+        assert!(asset_server.is_loaded_with_dependencies(&collection.folder));
+    }
+
+    #[test]
+    fn load_folder_with_filters() {
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(Path::new("text/a.cool.ron"), &serialize_as_cool_text("a"));
+        dir.insert_asset_text(
+            Path::new("text/nested/b.cool.ron"),
+            &serialize_as_cool_text("b"),
+        );
+        dir.insert_asset_text(Path::new("text/readme.txt"), "not an asset");
+        dir.insert_asset_text(Path::new("text/nested/source.blend"), "not an asset");
+
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_asset::<TypedLoadedFolder<CoolText>>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+
+        let glob = asset_server.load_glob("text/**/*.cool.ron");
+        let top_level = asset_server.load_folder_with(
+            "text",
+            FolderFilter::new()
+                .non_recursive()
+                .with_asset_type::<CoolText>(),
+        );
+        let by_extension = asset_server.load_folder_with(
+            "text",
+            FolderFilter::new()
+                .with_extension("blend")
+                .skip_missing_loaders(),
+        );
+        let typed = asset_server.load_typed_folder::<CoolText>("text", FolderFilter::new());
+        // Without a filter, files without a loader make the folder fail to load.
+        let unfiltered = asset_server.load_folder("text");
+        assert_ne!(glob.id(), top_level.id());
+        // Loading the same folder with the same filter reuses the existing handle.
+        assert_eq!(glob.id(), asset_server.load_glob("text/**/*.cool.ron").id());
+
+        run_app_until(&mut app, |world| {
+            let asset_server = world.resource::<AssetServer>();
+            ([&glob, &top_level, &by_extension]
+                .into_iter()
+                .all(|handle| asset_server.is_loaded_with_dependencies(handle))
+                && asset_server.is_loaded_with_dependencies(&typed)
+                && asset_server.load_state(&unfiltered).is_failed())
+            .then_some(())
+        });
+
+        let folders = app.world().resource::<Assets<LoadedFolder>>();
+        let cool_texts = app.world().resource::<Assets<CoolText>>();
+        let texts = |handle: &Handle<LoadedFolder>| {
+            let mut texts = folders
+                .get(handle)
+                .unwrap()
+                .iter_typed::<CoolText>()
+                .map(|handle| cool_texts.get(&handle).unwrap().text.clone())
+                .collect::<Vec<_>>();
+            texts.sort();
+            texts
+        };
+        assert_eq!(texts(&glob), ["a", "b"]);
+        assert_eq!(texts(&top_level), ["a"]);
+        // There is no loader for `.blend` files, so they are skipped.
+        assert!(folders.get(&by_extension).unwrap().handles.is_empty());
+        // Typed folders only contain handles to assets of the requested type.
+        let typed_folders = app
+            .world()
+            .resource::<Assets<TypedLoadedFolder<CoolText>>>();
+        assert_eq!(typed_folders.get(&typed).unwrap().handles.len(), 2);
+    }
+
     #[test]
     fn immediate_nested_asset_loads_dependency() {
         let (mut app, dir) = create_app();
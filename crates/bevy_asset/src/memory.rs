@@ -0,0 +1,411 @@
+//! Memory accounting and budget-driven eviction for [`Assets`] collections.
+//!
+//! Asset types opt in by implementing [`AssetMemorySize`] and calling
+//! [`AssetApp::init_asset_memory_tracking`](crate::AssetApp::init_asset_memory_tracking).
+//! Tracked types report their estimated footprint through [`AssetMemoryTracker`],
+//! the [`AssetMemoryUsage`] resource and [`bevy_diagnostic`].
+//!
+//! If a tracker is given a [`budget`](AssetMemoryTracker::set_budget), assets that were loaded from a path are
+//! evicted from [`Assets`] whenever the budget is exceeded. Which assets are evicted is decided by an
+//! [`AssetEvictionPolicy`]: [`LeastRecentlyUsed`] by default, or [`WeaklyReferencedFirst`].
+//! Evicted assets keep their handles and go back to [`LoadState::NotLoaded`](crate::LoadState::NotLoaded).
+//! They can be brought back with [`AssetMemoryTracker::request`] or by loading their path again.
+
+use crate::{Asset, AssetEvent, AssetId, AssetPath, AssetServer, Assets};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_diagnostic::{DiagnosticPath, Diagnostics};
+use bevy_ecs::{
+    message::MessageReader,
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_utils::TypeIdMap;
+use core::any::TypeId;
+use tracing::debug;
+
+/// Estimates how much memory an [`Asset`] occupies.
+///
+/// This is used by [`AssetMemoryTracker`] to report per-type memory usage and to enforce memory budgets.
+/// Implementations should be cheap, as they are called every time the asset is added or modified.
+pub trait AssetMemorySize {
+    /// Returns the estimated number of bytes used by this asset, including any heap allocations it owns.
+    fn memory_size(&self) -> usize;
+}
+
+/// An [`Asset`] that an [`AssetEvictionPolicy`] may choose to evict.
+pub struct AssetEvictionCandidate<A: Asset> {
+    /// The asset that can be evicted.
+    pub id: AssetId<A>,
+    /// The estimated size of the asset in bytes, as reported by [`AssetMemorySize`].
+    pub size: usize,
+    /// The tracker frame in which the asset was last added, modified or [requested](AssetMemoryTracker::request).
+    pub last_used: u32,
+    /// The number of live strong [`Handle`](crate::Handle)s to the asset.
+    pub strong_handles: usize,
+}
+
+/// Decides which assets to evict when an [`AssetMemoryTracker`] exceeds its budget.
+pub trait AssetEvictionPolicy<A: Asset>: Send + Sync + 'static {
+    /// Selects the assets to evict from `candidates`, which contains every tracked asset that can be reloaded from its path.
+    ///
+    /// The selection should free at least `bytes_to_free` bytes if possible. Returning fewer assets leaves the
+    /// collection over budget until the next frame. `current_frame` is the tracker frame that
+    /// [`AssetEvictionCandidate::last_used`] is measured against.
+    fn select(
+        &mut self,
+        candidates: Vec<AssetEvictionCandidate<A>>,
+        bytes_to_free: usize,
+        current_frame: u32,
+    ) -> Vec<AssetId<A>>;
+}
+
+/// Picks candidates in order until `bytes_to_free` bytes are freed, skipping assets used in `current_frame`.
+fn take_until_freed<A: Asset>(
+    candidates: Vec<AssetEvictionCandidate<A>>,
+    bytes_to_free: usize,
+    current_frame: u32,
+) -> Vec<AssetId<A>> {
+    let mut freed = 0;
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.last_used != current_frame)
+        .take_while(|candidate| {
+            let needed = freed < bytes_to_free;
+            freed += candidate.size;
+            needed
+        })
+        .map(|candidate| candidate.id)
+        .collect()
+}
+
+/// An [`AssetEvictionPolicy`] that evicts the assets that have gone the longest without being used.
+///
+/// Assets used in the current frame are never evicted.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LeastRecentlyUsed;
+
+impl<A: Asset> AssetEvictionPolicy<A> for LeastRecentlyUsed {
+    fn select(
+        &mut self,
+        mut candidates: Vec<AssetEvictionCandidate<A>>,
+        bytes_to_free: usize,
+        current_frame: u32,
+    ) -> Vec<AssetId<A>> {
+        candidates.sort_by_key(|candidate| {
+            core::cmp::Reverse(current_frame.wrapping_sub(candidate.last_used))
+        });
+        take_until_freed(candidates, bytes_to_free, current_frame)
+    }
+}
+
+/// An [`AssetEvictionPolicy`] that evicts the assets with the fewest live strong handles first, falling back to
+/// [`LeastRecentlyUsed`] order between assets that are referenced equally often.
+///
+/// Assets that are only kept alive by a single handle (for example by a cache or a [`LoadedFolder`](crate::LoadedFolder))
+/// are evicted before assets that are shared widely. Assets used in the current frame are never evicted.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WeaklyReferencedFirst;
+
+impl<A: Asset> AssetEvictionPolicy<A> for WeaklyReferencedFirst {
+    fn select(
+        &mut self,
+        mut candidates: Vec<AssetEvictionCandidate<A>>,
+        bytes_to_free: usize,
+        current_frame: u32,
+    ) -> Vec<AssetId<A>> {
+        candidates.sort_by_key(|candidate| {
+            (
+                candidate.strong_handles,
+                core::cmp::Reverse(current_frame.wrapping_sub(candidate.last_used)),
+            )
+        });
+        take_until_freed(candidates, bytes_to_free, current_frame)
+    }
+}
+
+struct TrackedAsset {
+    size: usize,
+    last_used: u32,
+}
+
+/// Tracks the estimated memory used by every asset in [`Assets<A>`] and evicts assets when a budget is exceeded.
+///
+/// This resource is added by [`AssetApp::init_asset_memory_tracking`](crate::AssetApp::init_asset_memory_tracking).
+#[derive(Resource)]
+pub struct AssetMemoryTracker<A: Asset> {
+    tracked: HashMap<AssetId<A>, TrackedAsset>,
+    total: usize,
+    frame: u32,
+    budget: Option<usize>,
+    policy: Box<dyn AssetEvictionPolicy<A>>,
+    evicted: HashMap<AssetId<A>, AssetPath<'static>>,
+    reload_requests: HashSet<AssetId<A>>,
+}
+
+impl<A: Asset> Default for AssetMemoryTracker<A> {
+    fn default() -> Self {
+        Self {
+            tracked: HashMap::default(),
+            total: 0,
+            frame: 0,
+            budget: None,
+            policy: Box::new(LeastRecentlyUsed),
+            evicted: HashMap::default(),
+            reload_requests: HashSet::default(),
+        }
+    }
+}
+
+impl<A: Asset> AssetMemoryTracker<A> {
+    /// Returns the estimated number of bytes used by all assets of type `A`.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the estimated number of bytes used by the asset with the given `id`, if it is loaded.
+    pub fn size(&self, id: impl Into<AssetId<A>>) -> Option<usize> {
+        self.tracked.get(&id.into()).map(|tracked| tracked.size)
+    }
+
+    /// Returns the memory budget in bytes for assets of type `A`, if one is set.
+    pub fn budget(&self) -> Option<usize> {
+        self.budget
+    }
+
+    /// Sets the memory budget in bytes for assets of type `A`. When the budget is exceeded, assets chosen by the
+    /// [`AssetEvictionPolicy`] are removed from [`Assets<A>`] until usage is back under budget.
+    ///
+    /// Only assets that were loaded from a path by the [`AssetServer`] are ever evicted.
+    pub fn set_budget(&mut self, budget: Option<usize>) {
+        self.budget = budget;
+    }
+
+    /// Replaces the [`AssetEvictionPolicy`] used when the budget is exceeded.
+    pub fn set_policy(&mut self, policy: impl AssetEvictionPolicy<A>) {
+        self.policy = Box::new(policy);
+    }
+
+    /// Marks the asset with the given `id` as used in the current frame, which protects it from eviction by
+    /// [`LeastRecentlyUsed`].
+    ///
+    /// If the asset was evicted, it is reloaded from its path. Returns `true` if the asset is currently loaded.
+    pub fn request(&mut self, id: impl Into<AssetId<A>>) -> bool {
+        let id = id.into();
+        if let Some(tracked) = self.tracked.get_mut(&id) {
+            tracked.last_used = self.frame;
+            return true;
+        }
+        if self.evicted.contains_key(&id) {
+            self.reload_requests.insert(id);
+        }
+        false
+    }
+
+    /// Returns `true` if the asset with the given `id` was evicted and has not been reloaded yet.
+    pub fn is_evicted(&self, id: impl Into<AssetId<A>>) -> bool {
+        self.evicted.contains_key(&id.into())
+    }
+
+    /// Returns an iterator over the assets that were evicted and have not been reloaded yet.
+    pub fn evicted(&self) -> impl Iterator<Item = AssetId<A>> + '_ {
+        self.evicted.keys().copied()
+    }
+
+    fn track(&mut self, id: AssetId<A>, size: usize) {
+        let previous = self.tracked.insert(
+            id,
+            TrackedAsset {
+                size,
+                last_used: self.frame,
+            },
+        );
+        self.total -= previous.map_or(0, |tracked| tracked.size);
+        self.total += size;
+    }
+
+    fn untrack(&mut self, id: AssetId<A>) {
+        if let Some(tracked) = self.tracked.remove(&id) {
+            self.total -= tracked.size;
+        }
+    }
+
+    /// A system that updates the tracked memory usage from [`AssetEvent`]s, evicts assets that exceed the budget and
+    /// reloads [requested](Self::request) assets.
+    pub fn update(
+        mut tracker: ResMut<Self>,
+        mut assets: ResMut<Assets<A>>,
+        mut usage: ResMut<AssetMemoryUsage>,
+        mut events: MessageReader<AssetEvent<A>>,
+        asset_server: Res<AssetServer>,
+    ) where
+        A: AssetMemorySize,
+    {
+        let tracker = &mut *tracker;
+        tracker.frame = tracker.frame.wrapping_add(1);
+
+        for event in events.read() {
+            match *event {
+                AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                    if let Some(asset) = assets.get(id) {
+                        tracker.track(id, asset.memory_size());
+                    }
+                    tracker.evicted.remove(&id);
+                }
+                AssetEvent::Removed { id } => tracker.untrack(id),
+                AssetEvent::Unused { id } => {
+                    tracker.untrack(id);
+                    tracker.evicted.remove(&id);
+                    tracker.reload_requests.remove(&id);
+                }
+                AssetEvent::LoadedWithDependencies { .. } => {}
+            }
+        }
+
+        for id in tracker.reload_requests.drain() {
+            if let Some(path) = tracker.evicted.get(&id) {
+                debug!("Reloading evicted asset {path}");
+                asset_server.reload(path.clone());
+            }
+        }
+
+        if let Some(budget) = tracker.budget
+            && tracker.total > budget
+        {
+            let candidates = tracker
+                .tracked
+                .iter()
+                .filter(|(id, _)| asset_server.get_path(id.untyped()).is_some())
+                .map(|(id, tracked)| AssetEvictionCandidate {
+                    id: *id,
+                    size: tracked.size,
+                    last_used: tracked.last_used,
+                    strong_handles: asset_server.strong_handle_count(id.untyped()),
+                })
+                .collect();
+            let to_evict = tracker
+                .policy
+                .select(candidates, tracker.total - budget, tracker.frame);
+            for id in to_evict {
+                let Some(path) = asset_server.get_path(id.untyped()) else {
+                    continue;
+                };
+                if assets.remove(id).is_some() {
+                    debug!("Evicted asset {path} to stay within its memory budget");
+                    asset_server.mark_unloaded(id.untyped());
+                    tracker.untrack(id);
+                    tracker.evicted.insert(id, path.into_owned());
+                }
+            }
+        }
+
+        usage.set::<A>(tracker.total);
+    }
+
+    /// A system publishing the memory used by assets of type `A` to [`bevy_diagnostic`].
+    pub fn publish_diagnostics(tracker: Res<Self>, mut diagnostics: Diagnostics) {
+        diagnostics.add_measurement(&AssetMemoryUsage::diagnostic_path::<A>(), || {
+            tracker.total as f64
+        });
+    }
+}
+
+/// The estimated memory used by every asset type registered with
+/// [`AssetApp::init_asset_memory_tracking`](crate::AssetApp::init_asset_memory_tracking).
+#[derive(Resource, Default, Debug)]
+pub struct AssetMemoryUsage {
+    per_type: TypeIdMap<usize>,
+}
+
+impl AssetMemoryUsage {
+    /// The total number of bytes used by all tracked asset types.
+    pub const TOTAL: DiagnosticPath = DiagnosticPath::const_new("asset_memory/total");
+
+    /// Returns the [`DiagnosticPath`] under which the memory used by assets of type `A` is published.
+    pub fn diagnostic_path<A: Asset>() -> DiagnosticPath {
+        DiagnosticPath::from_components(["asset_memory", A::type_path()])
+    }
+
+    /// Returns the estimated number of bytes used by assets of type `A`, or `None` if `A` is not tracked.
+    pub fn get<A: Asset>(&self) -> Option<usize> {
+        self.per_type.get(&TypeId::of::<A>()).copied()
+    }
+
+    /// Returns the estimated number of bytes used by all tracked asset types.
+    pub fn total(&self) -> usize {
+        self.per_type.values().sum()
+    }
+
+    fn set<A: Asset>(&mut self, bytes: usize) {
+        self.per_type.insert(TypeId::of::<A>(), bytes);
+    }
+}
+
+/// A system publishing the total memory used by tracked assets to [`bevy_diagnostic`].
+pub fn publish_asset_memory_diagnostics(
+    usage: Res<AssetMemoryUsage>,
+    mut diagnostics: Diagnostics,
+) {
+    diagnostics.add_measurement(&AssetMemoryUsage::TOTAL, || usage.total() as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AssetEvictionCandidate, AssetEvictionPolicy, LeastRecentlyUsed, WeaklyReferencedFirst,
+    };
+    use crate::{Asset, AssetId};
+    use bevy_reflect::TypePath;
+    use uuid::Uuid;
+
+    #[derive(Asset, TypePath)]
+    struct Blob;
+
+    fn candidate(n: u128, size: usize, last_used: u32) -> AssetEvictionCandidate<Blob> {
+        AssetEvictionCandidate {
+            id: AssetId::Uuid {
+                uuid: Uuid::from_u128(n),
+            },
+            size,
+            last_used,
+            strong_handles: 1,
+        }
+    }
+
+    #[test]
+    fn least_recently_used_evicts_oldest_first() {
+        let candidates = alloc::vec![
+            candidate(1, 100, 5),
+            candidate(2, 100, 2),
+            candidate(3, 100, 9),
+            candidate(4, 100, 1),
+        ];
+        let evicted = LeastRecentlyUsed.select(candidates, 150, 10);
+        assert_eq!(
+            evicted,
+            alloc::vec![candidate(4, 0, 0).id, candidate(2, 0, 0).id]
+        );
+    }
+
+    #[test]
+    fn least_recently_used_keeps_current_frame() {
+        let candidates = alloc::vec![candidate(1, 100, 3), candidate(2, 100, 3)];
+        assert!(LeastRecentlyUsed.select(candidates, 150, 3).is_empty());
+
+        // Assets that have not been used for a while are evicted, even if they were the last ones used.
+        let candidates = alloc::vec![candidate(1, 100, 3), candidate(2, 100, 3)];
+        assert_eq!(LeastRecentlyUsed.select(candidates, 150, 20).len(), 2);
+    }
+
+    #[test]
+    fn weakly_referenced_first_prefers_fewer_handles() {
+        let mut shared = candidate(1, 100, 1);
+        shared.strong_handles = 4;
+        let candidates = alloc::vec![shared, candidate(2, 100, 8), candidate(3, 100, 9)];
+        let evicted = WeaklyReferencedFirst.select(candidates, 150, 10);
+        assert_eq!(
+            evicted,
+            alloc::vec![candidate(2, 0, 0).id, candidate(3, 0, 0).id]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Sharing processed assets between machines.
+//!
+//! The [`AssetProcessor`](crate::processor::AssetProcessor) can fetch processed assets from a
+//! [`ProcessedAssetCache`] instead of processing them again, keyed by a [`ProcessedAssetCacheKey`] derived from the
+//! source asset, its settings and the processor. [`FileProcessedAssetCache`] stores entries in a directory, which
+//! can be a network share used by a whole team or CI. Other backends, such as an HTTP service, can be added by
+//! implementing [`ProcessedAssetCache`].
+
+use crate::meta::AssetHash;
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use bevy_ecs::error::BevyError;
+use bevy_tasks::BoxedFuture;
+use core::fmt::Write;
+use futures_lite::AsyncWriteExt;
+use std::path::PathBuf;
+
+/// Identifies a processed asset in a [`ProcessedAssetCache`] by the content it was produced from.
+///
+/// The key is derived from the hash of the source asset and its `.meta` file (which contains the processor settings),
+/// the type path of the processor, and the processor's [`VERSION`](crate::processor::Process::VERSION). When
+/// [`AssetValidator`](crate::processor::AssetValidator)s are registered, their fingerprint is part of the key as well,
+/// so registering, removing or updating a validator invalidates previously cached entries. Two machines processing
+/// the same source with the same processor and validators will therefore produce the same key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcessedAssetCacheKey(pub AssetHash);
+
+impl ProcessedAssetCacheKey {
+    /// Creates the key for an asset whose source and `.meta` bytes hash to `source_hash`, processed by the processor
+    /// with the given type path and version.
+    pub fn new(source_hash: AssetHash, processor_type_path: &str, processor_version: u32) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&source_hash);
+        hasher.update(processor_type_path.as_bytes());
+        hasher.update(&processor_version.to_le_bytes());
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// Returns this key combined with the fingerprint of the registered
+    /// [`AssetValidator`](crate::processor::AssetValidator)s.
+    pub fn with_validators(self, validators_fingerprint: AssetHash) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.0);
+        hasher.update(&validators_fingerprint);
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// Returns the key as a lowercase hexadecimal string, suitable for use as a file name or URL segment.
+    pub fn to_hex(&self) -> String {
+        let mut hex = String::with_capacity(self.0.len() * 2);
+        for byte in self.0 {
+            write!(hex, "{byte:02x}").unwrap();
+        }
+        hex
+    }
+}
+
+/// A processed asset stored in a [`ProcessedAssetCache`].
+#[derive(Debug, Clone, Default)]
+pub struct CachedProcessedAsset {
+    /// The serialized processed `.meta` file, including its [`ProcessedInfo`](crate::meta::ProcessedInfo).
+    pub meta: Vec<u8>,
+    /// The processed asset bytes.
+    pub asset: Vec<u8>,
+}
+
+/// A content-addressed store of processed assets, which can be shared between machines.
+///
+/// Before running a processor, the [`AssetProcessor`](crate::processor::AssetProcessor) looks up the asset's
+/// [`ProcessedAssetCacheKey`] in the cache. On a hit, the cached bytes are written to the processed asset source
+/// instead of processing the asset again. Newly processed assets are stored in the cache.
+///
+/// Errors returned by a cache are logged, and the processor falls back to processing the asset locally.
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Fetches the processed asset stored for `key`, or `None` if the cache does not contain it.
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, BevyError>>;
+
+    /// Stores `asset` for `key`, replacing any existing entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), BevyError>>;
+}
+
+/// A [`ProcessedAssetCache`] that stores processed assets in a directory, such as a network share.
+///
+/// Each entry is stored as two files named after the [`ProcessedAssetCacheKey`]: the processed asset, and its `.meta`
+/// file. The `.meta` file is written last, so a reader never observes a partially written entry.
+pub struct FileProcessedAssetCache {
+    /// The directory that the cache entries are stored in.
+    pub root: PathBuf,
+}
+
+impl FileProcessedAssetCache {
+    /// Creates a new cache that stores its entries in `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn entry_paths(&self, key: &ProcessedAssetCacheKey) -> (PathBuf, PathBuf) {
+        let hex = key.to_hex();
+        // Spread entries across subdirectories to avoid very large directories.
+        let dir = self.root.join(&hex[..2]);
+        (dir.join(&hex), dir.join(format!("{hex}.meta")))
+    }
+}
+
+async fn read_if_exists(path: &PathBuf) -> Result<Option<Vec<u8>>, BevyError> {
+    match async_fs::read(path).await {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(err) if err.kind() == futures_io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Writes `bytes` to a temporary file next to `path`, then renames it into place.
+async fn write_atomic(path: &PathBuf, bytes: &[u8]) -> Result<(), BevyError> {
+    let temp_path = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+    let mut file = async_fs::File::create(&temp_path).await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await?;
+    drop(file);
+    async_fs::rename(&temp_path, path).await?;
+    Ok(())
+}
+
+impl ProcessedAssetCache for FileProcessedAssetCache {
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, BevyError>> {
+        Box::pin(async move {
+            let (asset_path, meta_path) = self.entry_paths(key);
+            let Some(meta) = read_if_exists(&meta_path).await? else {
+                return Ok(None);
+            };
+            let Some(asset) = read_if_exists(&asset_path).await? else {
+                return Ok(None);
+            };
+            Ok(Some(CachedProcessedAsset { meta, asset }))
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), BevyError>> {
+        Box::pin(async move {
+            let (asset_path, meta_path) = self.entry_paths(key);
+            if let Some(parent) = asset_path.parent() {
+                async_fs::create_dir_all(parent).await?;
+            }
+            write_atomic(&asset_path, &asset.asset).await?;
+            write_atomic(&meta_path, &asset.meta).await?;
+            Ok(())
+        })
+    }
+}
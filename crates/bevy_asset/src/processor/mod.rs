@@ -37,14 +37,19 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
+mod validate;
 
 use async_lock::RwLockReadGuardArc;
+pub use cache::*;
 pub use log::*;
 pub use process::*;
+pub use validate::*;
 
 use crate::{
+    io::Writer,
     io::{
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
         AssetSources, AssetWriterError, ErasedAssetReader, MissingAssetSourceError,
@@ -54,15 +59,18 @@ use crate::{
         AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
-    MissingAssetLoaderForExtensionError, UnapprovedPathMode, WriteDefaultMetaError,
+    ErasedLoadedAsset, MissingAssetLoaderForExtensionError, UnapprovedPathMode,
+    WriteDefaultMetaError,
 };
 use alloc::{borrow::ToOwned, boxed::Box, string::String, sync::Arc, vec, vec::Vec};
+use bevy_app::AppExit;
 use bevy_ecs::prelude::*;
 use bevy_platform::{
     collections::{hash_map::Entry, HashMap, HashSet},
     sync::{PoisonError, RwLock},
 };
 use bevy_tasks::IoTaskPool;
+use core::sync::atomic::{AtomicBool, Ordering};
 use futures_io::ErrorKind;
 use futures_lite::{AsyncWriteExt, StreamExt};
 use futures_util::{select_biased, FutureExt};
@@ -93,6 +101,10 @@ use {
 /// A [`ProcessorTransactionLog`] is produced, which uses "write-ahead logging" to make the [`AssetProcessor`] crash and failure resistant. If a failed/unfinished
 /// transaction from a previous run is detected, the affected asset(s) will be re-processed.
 ///
+/// Processed assets can be shared between machines (for example between CI and developer checkouts) by setting a
+/// [`ProcessedAssetCache`] with [`AssetProcessorData::set_processed_asset_cache`]. Assets whose processed result is
+/// already in the cache are copied from it instead of being processed again.
+///
 /// [`AssetProcessor`] can be cloned. It is backed by an [`Arc`] so clones will share state. Clones can be freely used in parallel.
 #[derive(Resource, Clone)]
 pub struct AssetProcessor {
@@ -113,6 +125,16 @@ pub struct AssetProcessorData {
     log: async_lock::RwLock<Option<Box<dyn ProcessorTransactionLog>>>,
     /// The processors that will be used to process assets.
     processors: RwLock<Processors>,
+    /// The cache that processed assets are fetched from and stored in, if any.
+    processed_asset_cache: RwLock<Option<Arc<dyn ProcessedAssetCache>>>,
+    /// The validators that are run on assets loaded during processing.
+    validators: RwLock<Validators>,
+    /// The issues reported by `validators`.
+    validation_report: RwLock<ValidationReport>,
+    /// Whether a processing run that reports validation errors should fail.
+    fail_on_validation_error: AtomicBool,
+    /// Set when a processing run failed validation, and cleared once the app has been told to exit.
+    validation_failed: AtomicBool,
     sources: Arc<AssetSources>,
 }
 
@@ -354,10 +376,7 @@ impl AssetProcessor {
         // to the finished state (otherwise we'd be sitting around stuck in the `Initialized`
         // state).
         if new_task_receiver.is_empty() {
-            self.data
-                .processing_state
-                .set_state(ProcessorState::Finished)
-                .await;
+            self.finish_processing().await;
         }
         enum ProcessorTaskEvent {
             Start(AssetSourceId<'static>, PathBuf),
@@ -413,16 +432,54 @@ impl AssetProcessor {
                     if pending_tasks == 0 {
                         // clean up metadata in asset server
                         self.server.write_infos().consume_handle_drop_events();
-                        self.data
-                            .processing_state
-                            .set_state(ProcessorState::Finished)
-                            .await;
+                        self.finish_processing().await;
                     }
                 }
             }
         }
     }
 
+    /// Moves the processor to [`ProcessorState::Finished`], failing the run if
+    /// [`AssetProcessorData::set_fail_on_validation_error`] is enabled and any asset failed validation.
+    async fn finish_processing(&self) {
+        if self.data.fail_on_validation_error.load(Ordering::Relaxed) {
+            let report = self.data.validation_report();
+            if report.has_errors() {
+                error!(
+                    "Asset processing failed: {} validation error(s) were reported",
+                    report.errors().count()
+                );
+                self.data.validation_failed.store(true, Ordering::Relaxed);
+            }
+        }
+        self.data
+            .processing_state
+            .set_state(ProcessorState::Finished)
+            .await;
+    }
+
+    /// A system that makes the app exit with an error once a processing run has failed validation.
+    ///
+    /// See [`AssetProcessorData::set_fail_on_validation_error`].
+    pub fn exit_on_validation_failure(processor: Res<Self>, mut exit: MessageWriter<AppExit>) {
+        if processor
+            .data
+            .validation_failed
+            .swap(false, Ordering::Relaxed)
+        {
+            exit.write(AppExit::error());
+        }
+    }
+
+    /// Returns a fingerprint of the registered [`AssetValidator`]s, or `None` if none are registered.
+    fn validators_fingerprint(&self) -> Option<AssetHash> {
+        self.data
+            .validators
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .fingerprint()
+    }
+
     /// Writes the default meta file for the provided `path`.
     ///
     /// This function generates the appropriate meta file to process `path` with the default
@@ -777,6 +834,69 @@ impl AssetProcessor {
         }
     }
 
+    /// Register a new [`AssetValidator`], which will run on every asset of type [`AssetValidator::Asset`] loaded
+    /// during processing.
+    pub fn register_validator<V: AssetValidator>(&self, validator: V) {
+        self.data
+            .validators
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(validator);
+    }
+
+    /// Returns `true` if an [`AssetValidator`] is registered for the asset type with the given [`TypeId`](core::any::TypeId).
+    fn has_validators_for(&self, asset_type_id: core::any::TypeId) -> bool {
+        self.data
+            .validators
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(asset_type_id)
+    }
+
+    /// Runs the registered [`AssetValidator`]s on `asset` and records the reported issues in the
+    /// [`ValidationReport`]. Returns an error if any [`ValidationSeverity::Error`] was reported.
+    pub(crate) fn validate_loaded_asset(
+        &self,
+        asset_path: &AssetPath<'static>,
+        asset: &ErasedLoadedAsset,
+    ) -> Result<(), AssetValidationError> {
+        let mut issues = Vec::new();
+        {
+            let validators = self
+                .data
+                .validators
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+            if validators.is_empty() {
+                return Ok(());
+            }
+            validators.validate(asset, asset_path, &mut issues);
+        }
+        for issue in &issues {
+            if issue.severity == ValidationSeverity::Warning {
+                warn!("Asset validation {issue}");
+            }
+        }
+        let errors: Vec<_> = issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+            .cloned()
+            .collect();
+        self.data
+            .validation_report
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .set(asset_path, issues);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AssetValidationError {
+                path: asset_path.clone(),
+                errors,
+            })
+        }
+    }
+
     /// Set the default processor for the given `extension`. Make sure `P` is registered with [`AssetProcessor::register_processor`].
     pub fn set_default_processor<P: Process>(&self, extension: &str) {
         let mut processors = self
@@ -1049,31 +1169,38 @@ impl AssetProcessor {
             path: asset_path.clone(),
             err,
         };
+        let write_io_err = |err| ProcessError::AssetWriterError {
+            path: asset_path.clone(),
+            err: AssetWriterError::Io(err),
+        };
 
-        let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
+        let (mut source_meta, meta_bytes, processor, loader) = match reader
+            .read_meta_bytes(path)
+            .await
+        {
             Ok(meta_bytes) => {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
-                let (meta, processor) = match minimal.asset {
+                let (meta, processor, loader) = match minimal.asset {
                     AssetActionMinimal::Load { loader } => {
                         let loader = server.get_asset_loader_with_type_name(&loader).await?;
                         let meta = loader.deserialize_meta(&meta_bytes)?;
-                        (meta, None)
+                        (meta, None, Some(loader))
                     }
                     AssetActionMinimal::Process { processor } => {
                         let processor = self.get_processor(&processor)?;
                         let meta = processor.deserialize_meta(&meta_bytes)?;
-                        (meta, Some(processor))
+                        (meta, Some(processor), None)
                     }
                     AssetActionMinimal::Ignore => {
                         return Ok(ProcessResult::Ignored);
                     }
                 };
-                (meta, meta_bytes, processor)
+                (meta, meta_bytes, processor, loader)
             }
             Err(AssetReaderError::NotFound(_path)) => {
-                let (meta, processor) = if let Some(processor) = asset_path
+                let (meta, processor, loader) = if let Some(processor) = asset_path
                     .get_full_extension()
                     .and_then(|ext| self.get_default_processor(ext))
                 {
@@ -1081,19 +1208,19 @@ impl AssetProcessor {
                     // returning the processor here anyway, and we're only using this meta to pass
                     // along the processor settings.
                     let meta = processor.default_meta(MetaTypePathKind::Long);
-                    (meta, Some(processor))
+                    (meta, Some(processor), None)
                 } else {
                     match server.get_path_asset_loader(asset_path.clone()).await {
-                        Ok(loader) => (loader.default_meta(), None),
+                        Ok(loader) => (loader.default_meta(), None, Some(loader)),
                         Err(MissingAssetLoaderForExtensionError { .. }) => {
                             let meta: Box<dyn AssetMetaDyn> =
                                 Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
-                            (meta, None)
+                            (meta, None, None)
                         }
                     }
                 };
                 let meta_bytes = meta.serialize();
-                (meta, meta_bytes, processor)
+                (meta, meta_bytes, processor, loader)
             }
             Err(err) => {
                 return Err(ProcessError::ReadAssetMetaError {
@@ -1167,62 +1294,131 @@ impl AssetProcessor {
             // `AssetAction::Process` (which includes its settings).
             let settings = source_meta.process_settings().unwrap();
 
-            // Create a reader just for the actual process. Note: this means that we're performing
-            // two reads for the same file (but we avoid having to load the whole file into memory).
-            // For some sources (like local file systems), this is not a big deal, but for other
-            // sources like an HTTP asset sources, this could be an entire additional download (if
-            // the asset source doesn't do any caching). In practice, most sources being processed
-            // are likely to be local, and processing in general is a publish-time operation, so
-            // it's not likely to be too big a deal. If in the future, we decide we want to avoid
-            // this repeated read, we could "ask" the asset source if it prefers avoiding repeated
-            // reads or not.
-            let reader_for_process = reader.read(path).await.map_err(reader_err)?;
+            let cache = self.data.processed_asset_cache();
+            // Validators run while processing, so a cache entry is only trusted if it was produced with the same
+            // set of validators; otherwise an asset that fails the current validators could be served from the cache.
+            let mut cache_key =
+                ProcessedAssetCacheKey::new(new_hash, processor.type_path(), processor.version());
+            if let Some(fingerprint) = self.validators_fingerprint() {
+                cache_key = cache_key.with_validators(fingerprint);
+            }
+            let cached = match &cache {
+                Some(cache) => {
+                    self.get_cached_processed_asset(&**cache, &cache_key, asset_path, new_hash)
+                        .await
+                }
+                None => None,
+            };
 
-            let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
-            let mut processed_meta = {
-                let mut context = ProcessContext::new(
-                    self,
-                    asset_path,
-                    reader_for_process,
-                    &mut new_processed_info,
-                );
-                let process = processor.process(&mut context, settings, &mut *writer);
-                #[cfg(feature = "trace")]
-                let process = {
-                    let span = info_span!(
-                        "asset processing",
-                        processor = processor.type_path(),
-                        asset = asset_path.to_string(),
+            if let Some((cached, cached_processed_info)) = cached {
+                debug!("Using cached processed asset for {}", asset_path);
+                let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+                writer
+                    .write_all(&cached.asset)
+                    .await
+                    .map_err(write_io_err)?;
+                writer.flush().await.map_err(write_io_err)?;
+                processed_writer
+                    .write_meta_bytes(path, &cached.meta)
+                    .await
+                    .map_err(writer_err)?;
+                new_processed_info = cached_processed_info;
+            } else {
+                // Create a reader just for the actual process. Note: this means that we're performing
+                // two reads for the same file (but we avoid having to load the whole file into memory).
+                // For some sources (like local file systems), this is not a big deal, but for other
+                // sources like an HTTP asset sources, this could be an entire additional download (if
+                // the asset source doesn't do any caching). In practice, most sources being processed
+                // are likely to be local, and processing in general is a publish-time operation, so
+                // it's not likely to be too big a deal. If in the future, we decide we want to avoid
+                // this repeated read, we could "ask" the asset source if it prefers avoiding repeated
+                // reads or not.
+                let reader_for_process = reader.read(path).await.map_err(reader_err)?;
+
+                let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+                // When caching, the processed bytes are buffered so they can also be stored in the cache.
+                let mut cache_buffer = Vec::new();
+                let mut processed_meta = {
+                    let mut context = ProcessContext::new(
+                        self,
+                        asset_path,
+                        reader_for_process,
+                        &mut new_processed_info,
                     );
-                    process.instrument(span)
+                    let output: &mut Writer = if cache.is_some() {
+                        &mut cache_buffer
+                    } else {
+                        &mut *writer
+                    };
+                    let process = processor.process(&mut context, settings, output);
+                    #[cfg(feature = "trace")]
+                    let process = {
+                        let span = info_span!(
+                            "asset processing",
+                            processor = processor.type_path(),
+                            asset = asset_path.to_string(),
+                        );
+                        process.instrument(span)
+                    };
+                    process.await?
                 };
-                process.await?
-            };
 
-            writer
-                .flush()
-                .await
-                .map_err(|e| ProcessError::AssetWriterError {
-                    path: asset_path.clone(),
-                    err: AssetWriterError::Io(e),
-                })?;
-
-            let full_hash = get_full_asset_hash(
-                new_hash,
-                new_processed_info
-                    .process_dependencies
-                    .iter()
-                    .map(|i| i.full_hash),
-            );
-            new_processed_info.full_hash = full_hash;
-            *processed_meta.processed_info_mut() = Some(new_processed_info.clone());
-            let meta_bytes = processed_meta.serialize();
-
-            processed_writer
-                .write_meta_bytes(path, &meta_bytes)
-                .await
-                .map_err(writer_err)?;
+                if cache.is_some() {
+                    writer
+                        .write_all(&cache_buffer)
+                        .await
+                        .map_err(write_io_err)?;
+                }
+                writer.flush().await.map_err(write_io_err)?;
+
+                let full_hash = get_full_asset_hash(
+                    new_hash,
+                    new_processed_info
+                        .process_dependencies
+                        .iter()
+                        .map(|i| i.full_hash),
+                );
+                new_processed_info.full_hash = full_hash;
+                *processed_meta.processed_info_mut() = Some(new_processed_info.clone());
+                let meta_bytes = processed_meta.serialize();
+
+                processed_writer
+                    .write_meta_bytes(path, &meta_bytes)
+                    .await
+                    .map_err(writer_err)?;
+
+                if let Some(cache) = &cache {
+                    let cached = CachedProcessedAsset {
+                        meta: meta_bytes,
+                        asset: cache_buffer,
+                    };
+                    if let Err(err) = cache.put(&cache_key, &cached).await {
+                        warn!("Failed to store processed asset {asset_path} in the processed asset cache: {err}");
+                    }
+                }
+            }
         } else {
+            if let Some(loader) = loader
+                && self.has_validators_for(loader.asset_type_id())
+            {
+                // Unwrap is ok since we have a loader, so the `AssetAction` must have been
+                // `AssetAction::Load` (which includes its settings).
+                let settings = source_meta.loader_settings().unwrap();
+                let mut reader_for_validation = reader.read(path).await.map_err(reader_err)?;
+                let loaded_asset = server
+                    .load_with_settings_loader_and_reader(
+                        asset_path,
+                        settings,
+                        &*loader,
+                        &mut reader_for_validation,
+                        false,
+                        true,
+                    )
+                    .await?;
+                self.validate_loaded_asset(asset_path, &loaded_asset)
+                    .map_err(AssetLoadError::from)?;
+            }
+
             // See the reasoning for processing why it's ok to do a second read here.
             let mut reader_for_copy = reader.read(path).await.map_err(reader_err)?;
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
@@ -1244,6 +1440,48 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Fetches the entry for `key` from `cache`, returning it if it was produced from a source with the given `hash`
+    /// and the current versions of all of its process dependencies.
+    async fn get_cached_processed_asset(
+        &self,
+        cache: &dyn ProcessedAssetCache,
+        key: &ProcessedAssetCacheKey,
+        asset_path: &AssetPath<'static>,
+        hash: AssetHash,
+    ) -> Option<(CachedProcessedAsset, ProcessedInfo)> {
+        let cached = match cache.get(key).await {
+            Ok(cached) => cached?,
+            Err(err) => {
+                warn!("Failed to read {asset_path} from the processed asset cache: {err}");
+                return None;
+            }
+        };
+        let processed_info = match ron::de::from_bytes::<ProcessedInfoMinimal>(&cached.meta) {
+            Ok(ProcessedInfoMinimal {
+                processed_info: Some(processed_info),
+            }) if processed_info.hash == hash => processed_info,
+            _ => {
+                warn!("Ignoring invalid processed asset cache entry for {asset_path}");
+                return None;
+            }
+        };
+        // The cached asset is only valid if it was processed using the same dependencies that we have locally.
+        for dependency in &processed_info.process_dependencies {
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.processing_state.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|info| info.processed_info.as_ref())
+                .map(|info| info.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                return None;
+            }
+        }
+        Some((cached, processed_info))
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         let log_factory = self
             .data
@@ -1348,6 +1586,11 @@ impl AssetProcessorData {
             log_factory: Mutex::new(Some(Box::new(FileTransactionLogFactory::default()))),
             log: Default::default(),
             processors: Default::default(),
+            processed_asset_cache: Default::default(),
+            validators: Default::default(),
+            validation_report: Default::default(),
+            fail_on_validation_error: AtomicBool::new(false),
+            validation_failed: AtomicBool::new(false),
         }
     }
 
@@ -1373,6 +1616,49 @@ impl AssetProcessorData {
         Ok(())
     }
 
+    /// Sets the [`ProcessedAssetCache`] that processed assets are fetched from and stored in.
+    ///
+    /// This should be called before asset processing begins (in the `Startup` schedule), otherwise assets
+    /// that have already been processed will not be stored in the cache.
+    pub fn set_processed_asset_cache(&self, cache: Box<dyn ProcessedAssetCache>) {
+        *self
+            .processed_asset_cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Arc::from(cache));
+    }
+
+    /// Returns the [`ProcessedAssetCache`] used by the processor, if one was set.
+    pub fn processed_asset_cache(&self) -> Option<Arc<dyn ProcessedAssetCache>> {
+        self.processed_asset_cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns a snapshot of the issues reported by [`AssetValidator`]s so far.
+    pub fn validation_report(&self) -> ValidationReport {
+        self.validation_report
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sets whether a processing run that reports any [`ValidationSeverity::Error`] should fail.
+    ///
+    /// Assets that fail validation are never written to the processed source. When this is enabled, the run as a whole
+    /// also fails once processing finishes: the errors are logged and [`AssetProcessor::exit_on_validation_failure`]
+    /// makes the app exit with an error, which is useful for rejecting bad content in CI builds. Disabled by default.
+    pub fn set_fail_on_validation_error(&self, fail: bool) {
+        self.fail_on_validation_error.store(fail, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if a processing run that reports validation errors fails.
+    ///
+    /// See [`AssetProcessorData::set_fail_on_validation_error`].
+    pub fn fail_on_validation_error(&self) -> bool {
+        self.fail_on_validation_error.load(Ordering::Relaxed)
+    }
+
     /// Returns a future that will not finish until the path has been processed.
     pub async fn wait_until_processed(&self, path: AssetPath<'static>) -> ProcessStatus {
         self.processing_state.wait_until_processed(path).await
@@ -33,6 +33,12 @@ pub trait Process: TypePath + Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of this processor's output format.
+    ///
+    /// This is part of the [`ProcessedAssetCacheKey`](crate::processor::ProcessedAssetCacheKey), so it should be
+    /// incremented whenever a change to the processor makes it produce different output for the same input and settings.
+    /// Otherwise, stale results may be fetched from a [`ProcessedAssetCache`](crate::processor::ProcessedAssetCache).
+    const VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -244,6 +250,8 @@ pub trait ErasedProcessor: Send + Sync {
     fn type_path(&self) -> &'static str;
     /// Returns the short type path of this processor.
     fn short_type_path(&self) -> &'static str;
+    /// Returns the [`Process::VERSION`] of the original [`Process`].
+    fn version(&self) -> u32;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self, processor_path_kind: MetaTypePathKind) -> Box<dyn AssetMetaDyn>;
 }
@@ -288,6 +296,10 @@ impl<P: Process> ErasedProcessor for P {
         P::short_type_path()
     }
 
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
+
     fn default_meta(&self, processor_path_kind: MetaTypePathKind) -> Box<dyn AssetMetaDyn> {
         let type_path = match processor_path_kind {
             MetaTypePathKind::Short => P::short_type_path(),
@@ -345,6 +357,9 @@ impl<'a> ProcessContext<'a> {
     /// This will take the "load dependencies" (asset values used when loading with `L`]) and
     /// register them as "process dependencies" because they are asset values required to process the
     /// current asset.
+    ///
+    /// The loaded asset is checked by any registered [`AssetValidator`](crate::processor::AssetValidator)s,
+    /// and an error is returned if validation fails.
     pub async fn load_source_asset<L: AssetLoader>(
         &mut self,
         settings: &L::Settings,
@@ -370,6 +385,8 @@ impl<'a> ProcessContext<'a> {
                     path: path.to_owned(),
                 });
         }
+        self.processor
+            .validate_loaded_asset(self.path, &loaded_asset)?;
         Ok(loaded_asset)
     }
 
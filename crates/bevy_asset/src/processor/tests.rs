@@ -18,7 +18,7 @@ use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
-use bevy_app::{App, TaskPoolPlugin};
+use bevy_app::{App, AppExit, TaskPoolPlugin};
 use bevy_ecs::error::BevyError;
 use bevy_tasks::BoxedFuture;
 
@@ -29,8 +29,10 @@ use crate::{
         AssetSourceId, AssetWatcher, PathStream, Reader,
     },
     processor::{
-        AssetProcessor, GetProcessorError, LoadTransformAndSave, LogEntry, Process, ProcessContext,
-        ProcessError, ProcessorState, ProcessorTransactionLog, ProcessorTransactionLogFactory,
+        AssetProcessor, AssetValidator, CachedProcessedAsset, FileProcessedAssetCache,
+        GetProcessorError, LoadTransformAndSave, LogEntry, Process, ProcessContext, ProcessError,
+        ProcessedAssetCache, ProcessedAssetCacheKey, ProcessorState, ProcessorTransactionLog,
+        ProcessorTransactionLogFactory, ValidationContext, ValidationSeverity,
     },
     saver::{tests::CoolTextSaver, AssetSaver},
     tests::{
@@ -546,6 +548,286 @@ fn asset_processor_transforms_asset_default_processor() {
     );
 }
 
+/// A [`ProcessedAssetCache`] that stores its entries in memory, so it can be shared between apps.
+#[derive(Default, Clone)]
+struct MemoryProcessedAssetCache(Arc<Mutex<HashMap<ProcessedAssetCacheKey, CachedProcessedAsset>>>);
+
+impl ProcessedAssetCache for MemoryProcessedAssetCache {
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, BevyError>> {
+        Box::pin(async move {
+            Ok(self
+                .0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(key)
+                .cloned())
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), BevyError>> {
+        Box::pin(async move {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(*key, asset.clone());
+            Ok(())
+        })
+    }
+}
+
+/// Processes a [`CoolText`] source in a fresh app using `cache`, with a processor appending `suffix` to the text.
+///
+/// Each call acts as a separate machine sharing the same cache. Returns the processed asset and its meta.
+fn process_cool_text_with_cache(
+    cache: Box<dyn ProcessedAssetCache>,
+    suffix: &str,
+) -> (String, String) {
+    type CoolTextProcessor = LoadTransformAndSave<
+        CoolTextLoader,
+        RootAssetTransformer<AddText, CoolText>,
+        CoolTextSaver,
+    >;
+
+    let path = Path::new("abc.cool.ron");
+    let AppWithProcessor {
+        mut app,
+        source_gate,
+        default_source_dirs:
+            ProcessingDirs {
+                source: source_dir,
+                processed: processed_dir,
+                ..
+            },
+        ..
+    } = create_app_with_asset_processor(&[]);
+
+    app.register_asset_loader(CoolTextLoader)
+        .register_asset_processor(CoolTextProcessor::new(
+            RootAssetTransformer::new(AddText(suffix.into())),
+            CoolTextSaver,
+        ))
+        .set_default_asset_processor::<CoolTextProcessor>("cool.ron");
+    app.world()
+        .resource::<AssetProcessor>()
+        .data()
+        .set_processed_asset_cache(cache);
+
+    let guard = source_gate.write_blocking();
+    source_dir.insert_asset_text(path, &serialize_as_cool_text("abc"));
+    run_app_until_finished_processing(&mut app, guard);
+
+    (
+        read_asset_as_string(&processed_dir, path),
+        read_meta_as_string(&processed_dir, path),
+    )
+}
+
+#[test]
+fn asset_processor_uses_processed_asset_cache() {
+    let cache = MemoryProcessedAssetCache::default();
+
+    // Processing on the first machine populates the cache.
+    let (first_asset, first_meta) = process_cool_text_with_cache(Box::new(cache.clone()), "_first");
+    assert_eq!(first_asset, serialize_as_cool_text("abc_first"));
+    assert_eq!(cache.0.lock().unwrap().len(), 1);
+
+    // The "second machine" has an identically named processor that would produce different output, which proves
+    // that the processed asset was fetched from the cache rather than processed again.
+    let (second_asset, second_meta) =
+        process_cool_text_with_cache(Box::new(cache.clone()), "_second");
+    assert_eq!(second_asset, first_asset);
+    assert_eq!(second_meta, first_meta);
+}
+
+#[test]
+fn file_processed_asset_cache_round_trip() {
+    let root =
+        std::env::temp_dir().join(alloc::format!("bevy_asset_cache_{}", uuid::Uuid::new_v4()));
+
+    let (first_asset, first_meta) =
+        process_cool_text_with_cache(Box::new(FileProcessedAssetCache::new(&root)), "_first");
+    assert_eq!(first_asset, serialize_as_cool_text("abc_first"));
+
+    // A fresh cache reading the same directory is hit instead of processing again.
+    let (second_asset, second_meta) =
+        process_cool_text_with_cache(Box::new(FileProcessedAssetCache::new(&root)), "_second");
+    assert_eq!(second_asset, first_asset);
+    assert_eq!(second_meta, first_meta);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+/// Rejects [`CoolText`]s with empty text and warns about uppercase text.
+#[derive(TypePath)]
+struct CoolTextValidator;
+
+impl AssetValidator for CoolTextValidator {
+    type Asset = CoolText;
+
+    fn validate(&self, asset: &CoolText, context: &mut ValidationContext) {
+        if asset.text.is_empty() {
+            context.error("text must not be empty");
+        } else if asset.text.chars().any(char::is_uppercase) {
+            context.warn("text should be lowercase");
+        }
+    }
+}
+
+#[test]
+fn asset_processor_runs_validators() {
+    let AppWithProcessor {
+        mut app,
+        source_gate,
+        default_source_dirs:
+            ProcessingDirs {
+                source: source_dir,
+                processed: processed_dir,
+                ..
+            },
+        ..
+    } = create_app_with_asset_processor(&[]);
+
+    type CoolTextProcessor = LoadTransformAndSave<
+        CoolTextLoader,
+        RootAssetTransformer<AddText, CoolText>,
+        CoolTextSaver,
+    >;
+    app.register_asset_loader(CoolTextLoader)
+        .register_asset_processor(CoolTextProcessor::new(
+            RootAssetTransformer::new(AddText("_def".into())),
+            CoolTextSaver,
+        ))
+        .set_default_asset_processor::<CoolTextProcessor>("cool.ron")
+        .register_asset_validator(CoolTextValidator);
+    app.world()
+        .resource::<AssetProcessor>()
+        .data()
+        .set_fail_on_validation_error(true);
+
+    let guard = source_gate.write_blocking();
+
+    // Processed assets are validated when loaded through the `ProcessContext`.
+    let valid_path = Path::new("valid.cool.ron");
+    let warning_path = Path::new("warning.cool.ron");
+    let invalid_path = Path::new("invalid.cool.ron");
+    source_dir.insert_asset_text(valid_path, &serialize_as_cool_text("abc"));
+    source_dir.insert_asset_text(warning_path, &serialize_as_cool_text("ABC"));
+    source_dir.insert_asset_text(invalid_path, &serialize_as_cool_text(""));
+
+    // Assets that are copied without processing are validated too.
+    let copied_path = Path::new("copied.cool.ron");
+    source_dir.insert_asset_text(copied_path, &serialize_as_cool_text(""));
+    source_dir.insert_meta_text(
+        copied_path,
+        r#"(
+    meta_format_version: "1.0",
+    asset: Load(
+        loader: "bevy_asset::tests::CoolTextLoader",
+        settings: (),
+    ),
+)"#,
+    );
+
+    run_app_until_finished_processing(&mut app, guard);
+
+    assert!(processed_dir.get_asset(valid_path).is_some());
+    assert!(processed_dir.get_asset(warning_path).is_some());
+    assert!(processed_dir.get_asset(invalid_path).is_none());
+    assert!(processed_dir.get_asset(copied_path).is_none());
+
+    let report = app
+        .world()
+        .resource::<AssetProcessor>()
+        .data()
+        .validation_report();
+    assert!(report.has_errors());
+    assert!(report
+        .issues_for(&AssetPath::from_path(valid_path))
+        .is_empty());
+
+    let warnings = report.issues_for(&AssetPath::from_path(warning_path));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].severity, ValidationSeverity::Warning);
+    assert_eq!(warnings[0].message, "text should be lowercase");
+
+    for path in [invalid_path, copied_path] {
+        let errors = report.issues_for(&AssetPath::from_path(path));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, ValidationSeverity::Error);
+        assert_eq!(errors[0].validator, CoolTextValidator::type_path());
+    }
+    assert_eq!(report.errors().count(), 2);
+
+    // The run as a whole fails, since `set_fail_on_validation_error` is enabled.
+    app.update();
+    assert_eq!(app.should_exit(), Some(AppExit::error()));
+}
+
+#[test]
+fn processed_asset_cache_is_keyed_by_validators() {
+    type CoolTextProcessor = LoadTransformAndSave<
+        CoolTextLoader,
+        RootAssetTransformer<AddText, CoolText>,
+        CoolTextSaver,
+    >;
+
+    let cache = MemoryProcessedAssetCache::default();
+    let path = Path::new("empty.cool.ron");
+
+    let processed_with = |validate: bool| {
+        let AppWithProcessor {
+            mut app,
+            source_gate,
+            default_source_dirs:
+                ProcessingDirs {
+                    source: source_dir,
+                    processed: processed_dir,
+                    ..
+                },
+            ..
+        } = create_app_with_asset_processor(&[]);
+
+        app.register_asset_loader(CoolTextLoader)
+            .register_asset_processor(CoolTextProcessor::new(
+                RootAssetTransformer::new(AddText("".into())),
+                CoolTextSaver,
+            ))
+            .set_default_asset_processor::<CoolTextProcessor>("cool.ron");
+        if validate {
+            app.register_asset_validator(CoolTextValidator);
+        }
+        app.world()
+            .resource::<AssetProcessor>()
+            .data()
+            .set_processed_asset_cache(Box::new(cache.clone()));
+
+        let guard = source_gate.write_blocking();
+        source_dir.insert_asset_text(path, &serialize_as_cool_text(""));
+        run_app_until_finished_processing(&mut app, guard);
+
+        let report = app
+            .world()
+            .resource::<AssetProcessor>()
+            .data()
+            .validation_report();
+        (processed_dir.get_asset(path).is_some(), report.has_errors())
+    };
+
+    // Without validators, the empty asset is processed and cached.
+    assert_eq!(processed_with(false), (true, false));
+
+    // Registering a validator changes the cache key, so the cached result is not used and the asset is rejected.
+    assert_eq!(processed_with(true), (false, true));
+    assert_eq!(cache.0.lock().unwrap().len(), 1);
+}
+
 #[test]
 fn asset_processor_transforms_asset_with_meta() {
     let AppWithProcessor {
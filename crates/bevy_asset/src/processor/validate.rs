@@ -0,0 +1,279 @@
+use crate::{meta::AssetHash, Asset, AssetPath, ErasedLoadedAsset};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::TypePath;
+use core::{any::TypeId, fmt};
+use thiserror::Error;
+
+/// Checks that loaded assets follow project-specific content rules during asset processing.
+///
+/// Validators are registered with [`AssetProcessor::register_validator`](crate::processor::AssetProcessor::register_validator)
+/// (or [`AssetApp::register_asset_validator`](crate::AssetApp::register_asset_validator)). They run on every asset
+/// of type [`AssetValidator::Asset`] that is loaded while processing, including labeled sub-assets. This covers the
+/// source assets loaded by [`ProcessContext::load_source_asset`](crate::processor::ProcessContext::load_source_asset)
+/// and assets that are not processed but are copied to the processed source as-is.
+///
+/// Issues reported with [`ValidationContext::error`] cause processing of the asset to fail, so the asset is not
+/// written to the processed source. All issues are collected in the processor's [`ValidationReport`].
+///
+/// Bevy provides validators for common asset types, such as `ImageValidator`, `MeshValidator` and `AudioValidator`.
+///
+/// There is no validator checking that glTF files don't reference missing files, because validators only see the
+/// loaded asset and can't read from the asset source. This case is covered by loading instead: a glTF whose external
+/// buffers are missing fails to load, and therefore fails processing, while external textures are loaded as separate
+/// assets, and a missing texture is reported as an error when that load fails.
+///
+/// The registered validators are part of the [`ProcessedAssetCacheKey`](crate::processor::ProcessedAssetCacheKey),
+/// so assets are processed (and validated) again after the set of validators changes.
+pub trait AssetValidator: TypePath + Send + Sync + 'static {
+    /// The type of asset this validator checks.
+    type Asset: Asset;
+
+    /// The version of this validator's rules.
+    ///
+    /// This should be incremented whenever the validator starts rejecting assets it previously accepted, so that
+    /// results fetched from a [`ProcessedAssetCache`](crate::processor::ProcessedAssetCache) are validated again.
+    const VERSION: u32 = 0;
+
+    /// Checks `asset`, reporting any issues to `context`.
+    fn validate(&self, asset: &Self::Asset, context: &mut ValidationContext);
+}
+
+/// How serious a [`ValidationIssue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationSeverity {
+    /// The issue is reported, but the asset is still processed.
+    Warning,
+    /// The issue causes processing of the asset to fail.
+    Error,
+}
+
+/// A single issue reported by an [`AssetValidator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The path of the asset the issue was found in. This includes the label for sub-assets.
+    pub path: AssetPath<'static>,
+    /// How serious the issue is.
+    pub severity: ValidationSeverity,
+    /// The type path of the [`AssetValidator`] that reported the issue.
+    pub validator: &'static str,
+    /// A human-readable description of the issue.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            ValidationSeverity::Warning => "warning",
+            ValidationSeverity::Error => "error",
+        };
+        write!(
+            f,
+            "{severity} in '{}' ({}): {}",
+            self.path, self.validator, self.message
+        )
+    }
+}
+
+/// Collects the issues reported by an [`AssetValidator`] for a single asset.
+pub struct ValidationContext<'a> {
+    path: &'a AssetPath<'static>,
+    validator: &'static str,
+    issues: &'a mut Vec<ValidationIssue>,
+}
+
+impl<'a> ValidationContext<'a> {
+    /// The path of the asset being validated. This includes the label for sub-assets.
+    pub fn path(&self) -> &AssetPath<'static> {
+        self.path
+    }
+
+    /// Reports an issue that does not prevent the asset from being processed.
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Warning, message.into());
+    }
+
+    /// Reports an issue that causes processing of the asset to fail.
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Error, message.into());
+    }
+
+    fn report(&mut self, severity: ValidationSeverity, message: String) {
+        self.issues.push(ValidationIssue {
+            path: self.path.clone(),
+            severity,
+            validator: self.validator,
+            message,
+        });
+    }
+}
+
+/// A type-erased variant of [`AssetValidator`].
+pub(crate) trait ErasedAssetValidator: Send + Sync + 'static {
+    /// The type path and [`AssetValidator::VERSION`] of the validator.
+    fn identity(&self) -> (&'static str, u32);
+
+    fn validate(
+        &self,
+        asset: &ErasedLoadedAsset,
+        path: &AssetPath<'static>,
+        issues: &mut Vec<ValidationIssue>,
+    );
+}
+
+impl<V: AssetValidator> ErasedAssetValidator for V {
+    fn identity(&self) -> (&'static str, u32) {
+        (V::type_path(), V::VERSION)
+    }
+
+    fn validate(
+        &self,
+        asset: &ErasedLoadedAsset,
+        path: &AssetPath<'static>,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let Some(asset) = asset.get::<V::Asset>() else {
+            return;
+        };
+        let mut context = ValidationContext {
+            path,
+            validator: V::type_path(),
+            issues,
+        };
+        AssetValidator::validate(self, asset, &mut context);
+    }
+}
+
+/// The registered [`AssetValidator`]s, keyed by the [`TypeId`] of the asset they validate.
+#[derive(Default)]
+pub(crate) struct Validators {
+    by_asset_type: HashMap<TypeId, Vec<Box<dyn ErasedAssetValidator>>>,
+}
+
+impl Validators {
+    pub(crate) fn insert<V: AssetValidator>(&mut self, validator: V) {
+        self.by_asset_type
+            .entry(TypeId::of::<V::Asset>())
+            .or_default()
+            .push(Box::new(validator));
+    }
+
+    pub(crate) fn contains(&self, asset_type_id: TypeId) -> bool {
+        self.by_asset_type.contains_key(&asset_type_id)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.by_asset_type.is_empty()
+    }
+
+    /// Returns a hash identifying the registered validators and their versions, or `None` if there are none.
+    pub(crate) fn fingerprint(&self) -> Option<AssetHash> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut identities: Vec<_> = self
+            .by_asset_type
+            .values()
+            .flatten()
+            .map(|validator| validator.identity())
+            .collect();
+        identities.sort_unstable();
+        let mut hasher = blake3::Hasher::new();
+        for (type_path, version) in identities {
+            hasher.update(type_path.as_bytes());
+            hasher.update(&version.to_le_bytes());
+        }
+        Some(*hasher.finalize().as_bytes())
+    }
+
+    /// Runs every matching validator on `asset` and its labeled sub-assets.
+    pub(crate) fn validate(
+        &self,
+        asset: &ErasedLoadedAsset,
+        path: &AssetPath<'static>,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        if let Some(validators) = self.by_asset_type.get(&asset.asset_type_id()) {
+            for validator in validators {
+                validator.validate(asset, path, issues);
+            }
+        }
+        for label in asset.iter_labels() {
+            let labeled = asset.get_labeled(label).unwrap();
+            self.validate(labeled, &path.clone().with_label(label.to_string()), issues);
+        }
+    }
+}
+
+/// The issues reported by [`AssetValidator`]s during asset processing.
+///
+/// Issues are grouped by the asset they were found in. When an asset is processed again, its previous issues are
+/// replaced. Use [`AssetProcessorData::validation_report`](crate::processor::AssetProcessorData::validation_report)
+/// to retrieve the report, for example to fail a CI job once processing has finished.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    issues: HashMap<AssetPath<'static>, Vec<ValidationIssue>>,
+}
+
+impl ValidationReport {
+    /// Returns an iterator over every reported issue.
+    pub fn issues(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.values().flatten()
+    }
+
+    /// Returns the issues reported for the asset at `path`, including its labeled sub-assets.
+    pub fn issues_for(&self, path: &AssetPath<'_>) -> &[ValidationIssue] {
+        self.issues
+            .get(&path.without_label().clone_owned())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over every reported [`ValidationSeverity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues()
+            .filter(|issue| issue.severity == ValidationSeverity::Warning)
+    }
+
+    /// Returns an iterator over every reported [`ValidationSeverity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+    }
+
+    /// Returns `true` if any [`ValidationSeverity::Error`] was reported.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Returns `true` if no issues were reported.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Replaces the issues for the asset at `path`.
+    pub(crate) fn set(&mut self, path: &AssetPath<'static>, issues: Vec<ValidationIssue>) {
+        if issues.is_empty() {
+            self.issues.remove(path);
+        } else {
+            self.issues.insert(path.clone(), issues);
+        }
+    }
+}
+
+/// An error returned when an asset fails validation during asset processing.
+#[derive(Error, Debug, Clone)]
+#[error(
+    "Asset '{path}' failed validation: {}",
+    errors.iter().map(|issue| issue.message.as_str()).collect::<Vec<_>>().join("; ")
+)]
+pub struct AssetValidationError {
+    /// The path of the asset that failed validation.
+    pub path: AssetPath<'static>,
+    /// The [`ValidationSeverity::Error`] issues that were reported.
+    pub errors: Vec<ValidationIssue>,
+}
@@ -1,4 +1,5 @@
 use crate::{
+    folder::{BuildFolder, FolderFilter},
     meta::{AssetHash, MetaTransform},
     Asset, AssetHandleProvider, AssetIndex, AssetLoadError, AssetPath, DependencyLoadState,
     ErasedAssetIndex, ErasedLoadedAsset, Handle, InternalAssetEvent, LoadState,
@@ -24,7 +25,7 @@ use tracing::warn;
 
 #[derive(Debug)]
 pub(crate) struct AssetInfo {
-    weak_handle: Weak<StrongHandle>,
+    pub(crate) weak_handle: Weak<StrongHandle>,
     pub(crate) path: Option<AssetPath<'static>>,
     pub(crate) load_state: LoadState,
     pub(crate) dep_load_state: DependencyLoadState,
@@ -97,6 +98,9 @@ pub(crate) struct AssetInfos {
     pub(crate) pending_tasks: HashMap<ErasedAssetIndex, Task<()>>,
     /// The stats that have collected during usage of the asset server.
     pub(crate) stats: AssetServerStats,
+    /// Folders loaded with a [`FolderFilter`], keyed by their path, filter and folder asset type.
+    pub(crate) filtered_folders:
+        HashMap<(AssetPath<'static>, FolderFilter, TypeId), (ErasedAssetIndex, BuildFolder)>,
 }
 
 impl core::fmt::Debug for AssetInfos {
@@ -354,6 +358,19 @@ impl AssetInfos {
             .filter_map(|id| self.get_index_handle(id))
     }
 
+    /// Returns the live filtered folders loaded from `path`, along with their filters and builders.
+    pub(crate) fn get_filtered_folders<'a>(
+        &'a self,
+        path: &'a AssetPath<'_>,
+    ) -> impl Iterator<Item = (UntypedHandle, FolderFilter, BuildFolder)> + 'a {
+        self.filtered_folders
+            .iter()
+            .filter(move |((folder_path, ..), _)| folder_path == path)
+            .filter_map(|((_, filter, _), (index, build))| {
+                Some((self.get_index_handle(*index)?, filter.clone(), *build))
+            })
+    }
+
     pub(crate) fn get_index_handle(&self, index: ErasedAssetIndex) -> Option<UntypedHandle> {
         let info = self.infos.get(&index)?;
         let strong_handle = info.weak_handle.upgrade()?;
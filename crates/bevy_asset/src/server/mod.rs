@@ -2,7 +2,10 @@ mod info;
 mod loaders;
 
 use crate::{
-    folder::LoadedFolder,
+    folder::{
+        build_loaded_folder, build_typed_loaded_folder, split_glob, BuildFolder, FolderFilter,
+        LoadedFolder, TypedLoadedFolder,
+    },
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         AssetWriterError, ErasedAssetReader, MissingAssetSourceError, MissingAssetWriterError,
@@ -14,6 +17,7 @@ use crate::{
         MetaTransform, Settings,
     },
     path::AssetPath,
+    processor::AssetValidationError,
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetIndex, AssetLoadFailedEvent,
     AssetMetaCheck, Assets, DeserializeMetaError, ErasedAssetIndex, ErasedLoadedAsset, Handle,
     LoadedUntypedAsset, UnapprovedPathMode, UntypedAssetId, UntypedAssetLoadFailedEvent,
@@ -168,6 +172,29 @@ impl AssetServer {
             .unwrap_or_else(PoisonError::into_inner)
     }
 
+    /// Returns the number of live strong handles to the asset with the given `id`.
+    pub(crate) fn strong_handle_count(&self, id: UntypedAssetId) -> usize {
+        let Ok(index) = id.try_into() else {
+            return 0;
+        };
+        self.read_infos()
+            .get(index)
+            .map_or(0, |info| info.weak_handle.strong_count())
+    }
+
+    /// Resets the load states of an asset whose value was removed from [`Assets`] without dropping its handles,
+    /// so that the next [`AssetServer::load`] of its path loads it again.
+    pub(crate) fn mark_unloaded(&self, id: UntypedAssetId) {
+        let Ok(index) = id.try_into() else {
+            return;
+        };
+        if let Some(info) = self.write_infos().get_mut(index) {
+            info.load_state = LoadState::NotLoaded;
+            info.dep_load_state = DependencyLoadState::NotLoaded;
+            info.rec_dep_load_state = RecursiveDependencyLoadState::NotLoaded;
+        }
+    }
+
     fn read_loaders(&self) -> RwLockReadGuard<'_, AssetLoaders> {
         self.data
             .loaders
@@ -1131,15 +1158,131 @@ impl AssetServer {
         // `get_or_create_path_handle` always returns a Strong variant, so this is safe.
         let index = (&handle).try_into().unwrap();
         self.write_infos().stats.started_load_tasks += 1;
-        self.load_folder_internal(index, path);
+        self.load_folder_internal(index, path, FolderFilter::default(), build_loaded_folder);
+
+        handle
+    }
+
+    /// Loads the assets from the specified folder that pass the given [`FolderFilter`]. The [`LoadedFolder`] asset
+    /// (when it loads) will contain handles to the matching assets. Use [`LoadedFolder::iter_typed`] to get typed
+    /// handles when filtering by asset type.
+    ///
+    /// Files that are filtered out are never read, so folders that also contain `.meta` files or source files without
+    /// an [`AssetLoader`] can be loaded without errors.
+    ///
+    /// Like [`AssetServer::load_folder`], loading the same folder with the same filter multiple times returns the same
+    /// handle, and the [`LoadedFolder`] is reloaded when the contents of the folder change if the `file_watcher`
+    /// feature is enabled.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_folder_with<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        filter: FolderFilter,
+    ) -> Handle<LoadedFolder> {
+        self.load_filtered_folder::<LoadedFolder>(path.into(), filter, build_loaded_folder)
+            .typed_debug_checked()
+    }
+
+    /// Loads the assets of type `A` from the specified folder that pass the given [`FolderFilter`]. The
+    /// [`TypedLoadedFolder`] asset (when it loads) will contain typed handles to the matching assets.
+    ///
+    /// Files whose [`AssetLoader`] does not produce assets of type `A` are skipped without being read. The folder asset
+    /// type must be registered with `app.init_asset::<TypedLoadedFolder<A>>()` first.
+    ///
+    /// Like [`AssetServer::load_folder_with`], loads are deduplicated by path and filter and reloaded when the contents
+    /// of the folder change.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_typed_folder<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        filter: FolderFilter,
+    ) -> Handle<TypedLoadedFolder<A>> {
+        self.load_filtered_folder::<TypedLoadedFolder<A>>(
+            path.into(),
+            filter.with_asset_type::<A>(),
+            build_typed_loaded_folder::<A>,
+        )
+        .typed_debug_checked()
+    }
+
+    /// Returns the existing handle for the folder asset of type `F` loaded from `path` with `filter`, or starts loading
+    /// it.
+    fn load_filtered_folder<F: Asset>(
+        &self,
+        path: AssetPath<'_>,
+        filter: FolderFilter,
+        build: BuildFolder,
+    ) -> UntypedHandle {
+        let path = path.into_owned();
+        let key = (path.clone(), filter.clone(), TypeId::of::<F>());
+        let mut infos = self.write_infos();
+        if let Some(handle) = infos
+            .filtered_folders
+            .get(&key)
+            .and_then(|(index, _)| infos.get_index_handle(*index))
+        {
+            return handle;
+        }
+        let handle = infos.create_loading_handle_untyped(TypeId::of::<F>(), type_name::<F>());
+        // `create_loading_handle_untyped` always returns a Strong variant, so this is safe.
+        let index: ErasedAssetIndex = (&handle).try_into().unwrap();
+        // Forget folders whose handles have all been dropped, as they can no longer be reused.
+        let live = |(index, _): &(ErasedAssetIndex, BuildFolder)| infos.contains_key(*index);
+        let stale: Vec<_> = infos
+            .filtered_folders
+            .iter()
+            .filter(|(_, folder)| !live(folder))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for stale_key in stale {
+            infos.filtered_folders.remove(&stale_key);
+        }
+        infos.filtered_folders.insert(key, (index, build));
+        infos.stats.started_load_tasks += 1;
+        drop(infos);
+        self.load_folder_internal(index, path, filter, build);
 
         handle
     }
 
-    pub(crate) fn load_folder_internal(&self, index: ErasedAssetIndex, path: AssetPath) {
+    /// Loads the assets matching the glob `pattern`, such as `"sprites/**/*.png"`.
+    ///
+    /// The part of the pattern before the first segment containing a wildcard is the folder that is loaded, and the
+    /// rest is matched against the paths of the files in that folder. See [`FolderFilter::with_glob`] for the
+    /// supported syntax. If the pattern does not contain any wildcards, the whole folder is loaded.
+    ///
+    /// This is a shorthand for [`AssetServer::load_folder_with`].
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_glob<'a>(&self, pattern: impl Into<AssetPath<'a>>) -> Handle<LoadedFolder> {
+        let pattern = pattern.into();
+        let path_str = pattern
+            .path()
+            .to_str()
+            .expect("Path should be a valid string.");
+        let (folder, filter) = match split_glob(path_str) {
+            Some((folder, glob)) => (
+                AssetPath::from_path(Path::new(folder))
+                    .into_owned()
+                    .with_source(pattern.source().clone_owned()),
+                FolderFilter::new().with_glob(glob),
+            ),
+            None => (pattern.clone_owned(), FolderFilter::new()),
+        };
+        self.load_folder_with(folder, filter)
+    }
+
+    pub(crate) fn load_folder_internal(
+        &self,
+        index: ErasedAssetIndex,
+        path: AssetPath,
+        filter: FolderFilter,
+        build: BuildFolder,
+    ) {
         async fn load_folder<'a>(
             source: AssetSourceId<'static>,
+            root: &'a Path,
             path: &'a Path,
+            filter: &'a FolderFilter,
             reader: &'a dyn ErasedAssetReader,
             server: &'a AssetServer,
             handles: &'a mut Vec<UntypedHandle>,
@@ -1149,17 +1292,43 @@ impl AssetServer {
                 let mut path_stream = reader.read_directory(path.as_ref()).await?;
                 while let Some(child_path) = path_stream.next().await {
                     if reader.is_directory(&child_path).await? {
+                        if !filter.is_recursive() {
+                            continue;
+                        }
                         Box::pin(load_folder(
                             source.clone(),
+                            root,
                             &child_path,
+                            filter,
                             reader,
                             server,
                             handles,
                         ))
                         .await?;
                     } else {
+                        let relative_path = child_path
+                            .strip_prefix(root)
+                            .unwrap_or(&child_path)
+                            .components()
+                            .map(|component| {
+                                component
+                                    .as_os_str()
+                                    .to_str()
+                                    .expect("Path should be a valid string.")
+                            })
+                            .collect::<Vec<_>>()
+                            .join("/");
+                        if !filter.matches_path(&relative_path) {
+                            continue;
+                        }
                         let path = child_path.to_str().expect("Path should be a valid string.");
                         let asset_path = AssetPath::parse(path).with_source(source.clone());
+                        if let Some(asset_type) = filter.asset_type() {
+                            match server.get_path_asset_loader(&asset_path).await {
+                                Ok(loader) if loader.asset_type_id() == asset_type => {}
+                                _ => continue,
+                            }
+                        }
                         match server.load_builder().load_untyped_async(asset_path).await {
                             Ok(handle) => handles.push(handle),
                             // skip assets that cannot be loaded
@@ -1167,6 +1336,8 @@ impl AssetServer {
                                 AssetLoadError::MissingAssetLoaderForTypeName(_)
                                 | AssetLoadError::MissingAssetLoaderForExtension(_),
                             ) => {}
+                            Err(AssetLoadError::MissingAssetLoader { .. })
+                                if filter.skips_missing_loaders() => {}
                             Err(err) => return Err(err),
                         }
                     }
@@ -1202,13 +1373,10 @@ impl AssetServer {
                 };
 
                 let mut handles = Vec::new();
-                match load_folder(source.id(), path.path(), asset_reader, &server, &mut handles).await {
+                match load_folder(source.id(), path.path(), path.path(), &filter, asset_reader, &server, &mut handles).await {
                     Ok(_) => server.send_asset_event(InternalAssetEvent::Loaded {
                         index,
-                        loaded_asset: LoadedAsset::new_with_dependencies(
-                            LoadedFolder { handles },
-                        )
-                        .into(),
+                        loaded_asset: build(handles),
                     }),
                     Err(err) => {
                         error!("Failed to load folder. {err}");
@@ -2144,7 +2312,26 @@ pub fn handle_internal_asset_events(world: &mut World) {
                             "Reloading folder {parent_asset_path} because the content has changed"
                         );
                         new_loads += 1;
-                        folders_to_reload.push((folder_handle, parent_asset_path.clone()));
+                        folders_to_reload.push((
+                            folder_handle,
+                            parent_asset_path.clone(),
+                            FolderFilter::default(),
+                            build_loaded_folder as BuildFolder,
+                        ));
+                    }
+                    for (folder_handle, filter, build) in
+                        infos.get_filtered_folders(&parent_asset_path)
+                    {
+                        info!(
+                            "Reloading filtered folder {parent_asset_path} because the content has changed"
+                        );
+                        new_loads += 1;
+                        folders_to_reload.push((
+                            folder_handle,
+                            parent_asset_path.clone(),
+                            filter,
+                            build,
+                        ));
                     }
                 }
                 infos.stats.started_load_tasks += new_loads;
@@ -2206,10 +2393,10 @@ pub fn handle_internal_asset_events(world: &mut World) {
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         drop(infos);
 
-        for (handle, path) in folders_to_reload {
-            // `get_path_handles` only returns Strong variants, so this is safe.
+        for (handle, path, filter, build) in folders_to_reload {
+            // `get_path_handles` and `get_filtered_folders` only return Strong variants, so this is safe.
             let index = (&handle).try_into().unwrap();
-            server.load_folder_internal(index, path);
+            server.load_folder_internal(index, path, filter, build);
         }
         for path in paths_to_reload {
             server.reload_internal(path, true);
@@ -2419,6 +2606,8 @@ pub enum AssetLoadError {
     AssetLoaderError(#[from] AssetLoaderError),
     #[error(transparent)]
     AddAsyncError(#[from] AddAsyncError),
+    #[error(transparent)]
+    AssetValidationError(#[from] AssetValidationError),
     #[error("The file at '{}' does not contain the labeled asset '{}'; it contains the following {} assets: {}",
             base_path,
             label,
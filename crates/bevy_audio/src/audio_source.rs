@@ -1,5 +1,5 @@
 use alloc::sync::Arc;
-use bevy_asset::{io::Reader, Asset, AssetLoader, LoadContext};
+use bevy_asset::{io::Reader, memory::AssetMemorySize, Asset, AssetLoader, LoadContext};
 use bevy_reflect::TypePath;
 use std::io::Cursor;
 
@@ -20,6 +20,15 @@ pub struct AudioSource {
     pub bytes: Arc<[u8]>,
 }
 
+impl AssetMemorySize for AudioSource {
+    /// Estimates the memory used by the encoded audio data.
+    ///
+    /// Sources that share their bytes through [`Arc`] are each counted in full.
+    fn memory_size(&self) -> usize {
+        size_of::<Self>() + self.bytes.len()
+    }
+}
+
 impl AsRef<[u8]> for AudioSource {
     fn as_ref(&self) -> &[u8] {
         &self.bytes
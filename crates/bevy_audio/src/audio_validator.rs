@@ -0,0 +1,54 @@
+use core::time::Duration;
+use std::io::Cursor;
+
+use bevy_asset::processor::{AssetValidator, ValidationContext};
+use bevy_reflect::TypePath;
+use rodio::Source;
+
+use crate::AudioSource;
+
+/// An [`AssetValidator`] that enforces rules for [`AudioSource`]s during asset processing.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_audio::AudioValidator;
+/// # use core::time::Duration;
+/// # let mut app = App::new();
+/// app.register_asset_validator(AudioValidator {
+///     max_duration: Some(Duration::from_secs(30)),
+/// });
+/// ```
+#[derive(Clone, Debug, Default, TypePath)]
+pub struct AudioValidator {
+    /// The longest allowed duration.
+    ///
+    /// Sources whose duration cannot be determined without decoding them completely are reported as a warning.
+    pub max_duration: Option<Duration>,
+}
+
+impl AssetValidator for AudioValidator {
+    type Asset = AudioSource;
+
+    fn validate(&self, source: &AudioSource, context: &mut ValidationContext) {
+        let Some(max_duration) = self.max_duration else {
+            return;
+        };
+        let decoder = match rodio::Decoder::new(Cursor::new(source.clone())) {
+            Ok(decoder) => decoder,
+            Err(err) => {
+                context.error(format!("audio could not be decoded: {err}"));
+                return;
+            }
+        };
+        match decoder.total_duration() {
+            Some(duration) if duration > max_duration => context.error(format!(
+                "audio is {:.2}s long, which exceeds the maximum of {:.2}s",
+                duration.as_secs_f32(),
+                max_duration.as_secs_f32()
+            )),
+            Some(_) => {}
+            None => context.warn("the duration of the audio could not be determined"),
+        }
+    }
+}
@@ -32,6 +32,7 @@ extern crate alloc;
 mod audio;
 mod audio_output;
 mod audio_source;
+mod audio_validator;
 mod pitch;
 mod sinks;
 mod volume;
@@ -49,6 +50,7 @@ pub mod prelude {
 
 pub use audio::*;
 pub use audio_source::*;
+pub use audio_validator::*;
 pub use pitch::*;
 pub use volume::*;
 
@@ -96,7 +98,8 @@ impl Plugin for AudioPlugin {
 
         #[cfg(any(feature = "mp3", feature = "flac", feature = "wav", feature = "vorbis"))]
         {
-            app.add_audio_source::<AudioSource>();
+            app.add_audio_source::<AudioSource>()
+                .init_asset_memory_tracking::<AudioSource>();
             app.init_asset_loader::<AudioLoader>();
         }
 
@@ -0,0 +1,454 @@
+//! Recording and replaying of input messages, to reproduce bugs deterministically.
+//!
+//! [`InputRecordingPlugin`] records the selected [`Message`] types, such as
+//! [`KeyboardInput`](bevy_input::keyboard::KeyboardInput) or [`GamepadEvent`](bevy_input::gamepad::GamepadEvent),
+//! together with the duration of every frame. The recording can be saved to a [`ron`] file and replayed into
+//! a fresh app, which then receives the exact same messages on the exact same frames, with the same [`Time`]
+//! deltas. Running the replay in a headless app turns a recording of a bug into a reproducible test case.
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_dev_tools::input_recording::{InputRecording, InputRecordingPlugin};
+//! # use bevy_input::keyboard::KeyboardInput;
+//! // While playing, record keyboard input to `bug.ron`, which is written when the app exits.
+//! App::new().add_plugins(
+//!     InputRecordingPlugin::record(Some("bug.ron".into())).with_message::<KeyboardInput>(),
+//! );
+//!
+//! // Later, replay the recording, ignoring real keyboard input.
+//! let recording = InputRecording::load("bug.ron").unwrap();
+//! App::new().add_plugins(
+//!     InputRecordingPlugin::replay(recording).with_message::<KeyboardInput>(),
+//! );
+//! ```
+//!
+//! Messages that contain an [`Entity`](bevy_ecs::entity::Entity), like the window of a
+//! [`KeyboardInput`](bevy_input::keyboard::KeyboardInput), are replayed as-is. The replaying app must spawn its
+//! entities in the same order as the recording app for them to refer to the same entities.
+//!
+//! [`Time`]: bevy_time::Time
+
+use std::{fs, path::Path, path::PathBuf};
+
+use bevy_app::{App, AppExit, First, Last, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_input::{InputBackendSystems, InputSystems};
+use bevy_reflect::TypePath;
+use bevy_time::{Real, Time, TimeSystems, TimeUpdateStrategy};
+use core::time::Duration;
+use ron::ser::PrettyConfig;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// A plugin that records the selected [`Message`] types and frame times, or replays a previous recording.
+///
+/// Message types are selected with [`InputRecordingPlugin::with_message`], and must be the same when recording
+/// and replaying.
+///
+/// See the [module docs](crate::input_recording) for more.
+pub struct InputRecordingPlugin {
+    /// Whether this plugin records or replays messages.
+    pub mode: InputRecordingMode,
+    messages: Vec<fn(&mut App, &InputRecordingMode)>,
+}
+
+/// Whether an [`InputRecordingPlugin`] records or replays messages.
+#[derive(Debug, Clone)]
+pub enum InputRecordingMode {
+    /// Records messages into the [`InputRecorder`] resource.
+    Record {
+        /// The file the recording is written to when the app exits, if any.
+        path: Option<PathBuf>,
+    },
+    /// Replays the given recording through the [`InputReplay`] resource.
+    Replay(InputRecording),
+}
+
+impl InputRecordingPlugin {
+    /// Creates a plugin that records messages, and writes them to `path` when the app exits.
+    pub fn record(path: Option<PathBuf>) -> Self {
+        Self {
+            mode: InputRecordingMode::Record { path },
+            messages: Vec::new(),
+        }
+    }
+
+    /// Creates a plugin that replays the given recording.
+    pub fn replay(recording: InputRecording) -> Self {
+        Self {
+            mode: InputRecordingMode::Replay(recording),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Records or replays messages of type `M`.
+    ///
+    /// When replaying, messages of type `M` that are not part of the recording are discarded until the end of the
+    /// recording, so real input does not interfere with the replay.
+    pub fn with_message<M>(mut self) -> Self
+    where
+        M: Message + TypePath + Serialize + DeserializeOwned,
+    {
+        self.messages.push(add_message_systems::<M>);
+        self
+    }
+}
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        match &self.mode {
+            InputRecordingMode::Record { path } => {
+                app.insert_resource(InputRecorder {
+                    recording: InputRecording::default(),
+                    path: path.clone(),
+                })
+                .add_systems(First, start_recorded_frame.after(TimeSystems))
+                .add_systems(
+                    Last,
+                    save_recording
+                        .run_if(on_message::<AppExit>)
+                        .after(RecordInputSystems),
+                );
+            }
+            InputRecordingMode::Replay(recording) => {
+                app.insert_resource(InputReplay {
+                    recording: recording.clone(),
+                    next_frame: 0,
+                })
+                .add_systems(First, advance_replay.before(TimeSystems))
+                .configure_sets(
+                    PreUpdate,
+                    ReplayInputSystems
+                        .after(InputBackendSystems)
+                        .before(InputSystems),
+                );
+            }
+        }
+
+        for add_systems in &self.messages {
+            add_systems(app, &self.mode);
+        }
+    }
+}
+
+/// The [`SystemSet`] of the systems in [`Last`] that record messages.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordInputSystems;
+
+/// The [`SystemSet`] of the systems in [`PreUpdate`] that write replayed messages.
+///
+/// This runs after [`InputBackendSystems`], so the real input written by backends during this frame is discarded,
+/// and before [`InputSystems`], so input resources like [`ButtonInput`](bevy_input::ButtonInput) are updated from
+/// the replayed messages.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReplayInputSystems;
+
+/// The messages and frame times recorded by an [`InputRecordingPlugin`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Clone)]
+pub struct InputRecording {
+    /// The recorded frames, in order.
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// A single frame of an [`InputRecording`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Clone)]
+pub struct RecordedFrame {
+    /// The [`Time<Real>`] delta of this frame.
+    pub delta: Duration,
+    /// The messages written during this frame, in order for each message type.
+    #[serde(default)]
+    pub messages: Vec<RecordedMessage>,
+}
+
+/// A message of an [`InputRecording`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct RecordedMessage {
+    /// The [`TypePath::type_path`] of the message type.
+    pub message_type: String,
+    /// The message, serialized with [`ron`].
+    pub value: String,
+}
+
+impl InputRecording {
+    /// Reads a recording from a [`ron`] file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InputRecordingError> {
+        let content = fs::read_to_string(path)?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    /// Writes this recording to a [`ron`] file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), InputRecordingError> {
+        // Use \n unconditionally so that Windows formatting is predictable.
+        let serialized = ron::ser::to_string_pretty(self, PrettyConfig::default().new_line("\n"))?;
+        fs::write(path, serialized)?;
+        Ok(())
+    }
+}
+
+/// An error that occurs when loading or saving an [`InputRecording`].
+#[derive(Error, Debug)]
+pub enum InputRecordingError {
+    /// The file could not be read or written.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The recording could not be serialized.
+    #[error(transparent)]
+    Serialize(#[from] ron::Error),
+    /// The file does not contain a valid recording.
+    #[error(transparent)]
+    Deserialize(#[from] ron::error::SpannedError),
+}
+
+/// A [`Resource`] holding the recording of an [`InputRecordingPlugin`] in [`InputRecordingMode::Record`].
+#[derive(Resource, Debug)]
+pub struct InputRecorder {
+    recording: InputRecording,
+    path: Option<PathBuf>,
+}
+
+impl InputRecorder {
+    /// Returns the frames recorded so far.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Returns the file the recording is written to when the app exits, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+/// A [`Resource`] holding the progress of an [`InputRecordingPlugin`] in [`InputRecordingMode::Replay`].
+#[derive(Resource, Debug)]
+pub struct InputReplay {
+    recording: InputRecording,
+    next_frame: usize,
+}
+
+impl InputReplay {
+    /// Returns the recording being replayed.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Returns the recorded frame being replayed during this frame, if the replay has not finished yet.
+    pub fn current_frame(&self) -> Option<&RecordedFrame> {
+        self.next_frame
+            .checked_sub(1)
+            .and_then(|index| self.recording.frames.get(index))
+    }
+
+    /// Returns `true` once every recorded frame has been replayed.
+    ///
+    /// The [`TimeUpdateStrategy`] is left as it was on the last recorded frame.
+    pub fn is_finished(&self) -> bool {
+        self.next_frame > self.recording.frames.len()
+    }
+}
+
+fn add_message_systems<M>(app: &mut App, mode: &InputRecordingMode)
+where
+    M: Message + TypePath + Serialize + DeserializeOwned,
+{
+    app.add_message::<M>();
+    match mode {
+        InputRecordingMode::Record { .. } => {
+            app.add_systems(Last, record_messages::<M>.in_set(RecordInputSystems));
+        }
+        InputRecordingMode::Replay(_) => {
+            app.add_systems(PreUpdate, replay_messages::<M>.in_set(ReplayInputSystems));
+        }
+    }
+}
+
+fn start_recorded_frame(time: Res<Time<Real>>, mut recorder: ResMut<InputRecorder>) {
+    recorder.recording.frames.push(RecordedFrame {
+        delta: time.delta(),
+        messages: Vec::new(),
+    });
+}
+
+fn record_messages<M>(mut reader: MessageReader<M>, mut recorder: ResMut<InputRecorder>)
+where
+    M: Message + TypePath + Serialize,
+{
+    let Some(frame) = recorder.recording.frames.last_mut() else {
+        return;
+    };
+    for message in reader.read() {
+        match ron::to_string(message) {
+            Ok(value) => frame.messages.push(RecordedMessage {
+                message_type: M::type_path().to_string(),
+                value,
+            }),
+            Err(error) => warn!("Failed to record {}: {error}", M::type_path()),
+        }
+    }
+}
+
+fn save_recording(recorder: Res<InputRecorder>) {
+    let Some(path) = &recorder.path else {
+        return;
+    };
+    match recorder.recording.save(path) {
+        Ok(()) => info!(
+            "Saved input recording of {} frames to {}",
+            recorder.recording.frames.len(),
+            path.display()
+        ),
+        Err(error) => warn!(
+            "Failed to save input recording to {}: {error}",
+            path.display()
+        ),
+    }
+}
+
+fn advance_replay(mut replay: ResMut<InputReplay>, mut strategy: ResMut<TimeUpdateStrategy>) {
+    if replay.is_finished() {
+        return;
+    }
+    replay.next_frame += 1;
+    match replay.current_frame() {
+        Some(frame) => *strategy = TimeUpdateStrategy::ManualDuration(frame.delta),
+        None => info!(
+            "Finished replaying {} frames of input",
+            replay.recording.frames.len()
+        ),
+    }
+}
+
+fn replay_messages<M>(replay: Res<InputReplay>, mut messages: ResMut<Messages<M>>)
+where
+    M: Message + TypePath + DeserializeOwned,
+{
+    let Some(frame) = replay.current_frame() else {
+        return;
+    };
+    // Discard real input, so only the recorded messages are read this frame.
+    messages.clear();
+    for message in &frame.messages {
+        if message.message_type != M::type_path() {
+            continue;
+        }
+        match ron::from_str::<M>(&message.value) {
+            Ok(value) => {
+                messages.write(value);
+            }
+            Err(error) => warn!("Failed to replay {}: {error}", M::type_path()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        InputRecorder, InputRecording, InputRecordingPlugin, InputReplay, RecordedFrame,
+        RecordedMessage,
+    };
+    use bevy_app::{App, PreUpdate, Update};
+    use bevy_ecs::prelude::*;
+    use bevy_input::InputBackendSystems;
+    use bevy_reflect::TypePath;
+    use bevy_time::{Real, Time, TimePlugin, TimeUpdateStrategy};
+    use core::time::Duration;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Message, TypePath, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+    struct Jump(u32);
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<(Duration, Jump)>);
+
+    fn log_jumps(mut jumps: MessageReader<Jump>, time: Res<Time<Real>>, mut log: ResMut<Log>) {
+        for &jump in jumps.read() {
+            log.0.push((time.elapsed(), jump));
+        }
+    }
+
+    fn app(plugin: InputRecordingPlugin) -> App {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, plugin.with_message::<Jump>()))
+            .init_resource::<Log>()
+            .add_systems(Update, log_jumps);
+        app
+    }
+
+    #[test]
+    fn replay_discards_live_messages() {
+        let recording = InputRecording {
+            frames: vec![RecordedFrame {
+                delta: Duration::from_millis(10),
+                messages: vec![RecordedMessage {
+                    message_type: Jump::type_path().to_string(),
+                    value: ron::to_string(&Jump(1)).unwrap(),
+                }],
+            }],
+        };
+        let mut app = app(InputRecordingPlugin::replay(recording));
+        // A backend writing real input in the same frame as the replay.
+        app.add_systems(
+            PreUpdate,
+            (|mut jumps: MessageWriter<Jump>| {
+                jumps.write(Jump(100));
+            })
+            .in_set(InputBackendSystems),
+        );
+        app.world_mut().write_message(Jump(100));
+        app.update();
+
+        let replayed: Vec<Jump> = app
+            .world()
+            .resource::<Log>()
+            .0
+            .iter()
+            .map(|(_, jump)| *jump)
+            .collect();
+        assert_eq!(replayed, [Jump(1)]);
+    }
+
+    #[test]
+    fn replay_is_identical_to_recording() {
+        let mut recording_app = app(InputRecordingPlugin::record(None));
+        for frame in 0..10 {
+            recording_app.insert_resource(TimeUpdateStrategy::ManualDuration(
+                Duration::from_millis(10 + frame),
+            ));
+            // Written outside of the schedule, like the messages of real input devices.
+            if frame % 3 == 0 {
+                recording_app.world_mut().write_message(Jump(frame as u32));
+            }
+            recording_app.update();
+        }
+        let recording = recording_app
+            .world()
+            .resource::<InputRecorder>()
+            .recording()
+            .clone();
+        assert_eq!(recording.frames.len(), 10);
+
+        let serialized = ron::to_string(&recording).unwrap();
+        let recording: InputRecording = ron::from_str(&serialized).unwrap();
+
+        let mut replaying_app = app(InputRecordingPlugin::replay(recording));
+        for _ in 0..10 {
+            // Real input is ignored while replaying.
+            replaying_app.world_mut().write_message(Jump(100));
+            replaying_app.update();
+        }
+        assert!(!replaying_app
+            .world()
+            .resource::<InputReplay>()
+            .is_finished());
+        replaying_app.update();
+        assert!(replaying_app
+            .world()
+            .resource::<InputReplay>()
+            .is_finished());
+
+        let recorded = &recording_app.world().resource::<Log>().0;
+        let replayed = &replaying_app.world().resource::<Log>().0;
+        assert_eq!(recorded.len(), 4);
+        assert_eq!(recorded, replayed);
+    }
+}
@@ -18,6 +18,9 @@ mod easy_screenshot;
 pub mod fps_overlay;
 pub mod frame_time_graph;
 
+#[cfg(feature = "input_recording")]
+pub mod input_recording;
+
 pub mod picking_debug;
 
 #[cfg(feature = "schedule_data")]
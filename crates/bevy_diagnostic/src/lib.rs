@@ -22,6 +22,7 @@ mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod system_timing_diagnostics_plugin;
 
 pub use diagnostic::*;
 
@@ -31,6 +32,9 @@ pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::{LogDiagnosticsPlugin, LogDiagnosticsState};
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+pub use system_timing_diagnostics_plugin::{
+    SystemTimingDiagnosticsPlugin, SystemTimingDiagnosticsState,
+};
 
 use bevy_app::prelude::*;
 
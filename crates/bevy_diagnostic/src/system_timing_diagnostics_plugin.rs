@@ -0,0 +1,166 @@
+use alloc::{format, string::String};
+use core::{fmt::Write, time::Duration};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ScheduleLabel, SystemTimings},
+};
+use bevy_platform::{collections::HashMap, time::Instant};
+use bevy_time::{Real, Time, Timer, TimerMode};
+use log::info;
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds per-system "time", "run count" and "skipped count" diagnostics to an App.
+///
+/// This inserts the [`SystemTimings`] resource, which makes the schedule executors measure how long every system
+/// takes to run and how often it is skipped by its run conditions. Once per frame, the measurements of the frame
+/// are added to the [`DiagnosticsStore`] under `system_timing/<schedule>/<system>/time` (in milliseconds),
+/// `.../run_count` and `.../skipped_count`. They remain available in the [`SystemTimings`] resource, which also
+/// keeps running totals.
+///
+/// Unlike the `trace` feature, this does not require an external profiler, so it can be used on players'
+/// machines. System names are only available when the `debug` feature is enabled; without it, every system is
+/// reported under the same name.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemTimingDiagnosticsPlugin {
+    /// The total number of values to keep for each diagnostic.
+    pub max_history_length: usize,
+    /// If `Some`, the given number of systems that took the longest to run during the last frame are logged every
+    /// [`wait_duration`](Self::wait_duration).
+    pub log_slowest: Option<usize>,
+    /// Time to wait between logging the slowest systems and logging them again.
+    pub wait_duration: Duration,
+}
+
+impl Default for SystemTimingDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_history_length: DEFAULT_MAX_HISTORY_LENGTH,
+            log_slowest: None,
+            wait_duration: Duration::from_secs(1),
+        }
+    }
+}
+
+impl SystemTimingDiagnosticsPlugin {
+    /// Creates a new `SystemTimingDiagnosticsPlugin` that also logs the `n` slowest systems every second.
+    pub fn log_slowest(n: usize) -> Self {
+        Self {
+            log_slowest: Some(n),
+            ..Default::default()
+        }
+    }
+}
+
+/// State used by the [`SystemTimingDiagnosticsPlugin`].
+#[derive(Resource)]
+pub struct SystemTimingDiagnosticsState {
+    max_history_length: usize,
+    log_slowest: Option<usize>,
+    timer: Timer,
+}
+
+impl Plugin for SystemTimingDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<SystemTimings>()
+            .insert_resource(SystemTimingDiagnosticsState {
+                max_history_length: self.max_history_length,
+                log_slowest: self.log_slowest,
+                timer: Timer::new(self.wait_duration, TimerMode::Repeating),
+            })
+            .add_systems(
+                Last,
+                (Self::diagnostic_system, Self::log_slowest_system).chain(),
+            );
+    }
+}
+
+impl SystemTimingDiagnosticsPlugin {
+    /// Ends the frame in [`SystemTimings`] and adds the measurements of every system to the [`DiagnosticsStore`].
+    pub fn diagnostic_system(
+        mut timings: ResMut<SystemTimings>,
+        mut diagnostics: ResMut<DiagnosticsStore>,
+        state: Res<SystemTimingDiagnosticsState>,
+    ) {
+        timings.end_frame();
+
+        // Systems with identical names are merged into the same diagnostics.
+        let mut frame: HashMap<String, (Duration, u64, u64)> = HashMap::default();
+        for stats in timings.iter() {
+            let mut path = format!("system_timing/{:?}/", stats.schedule);
+            // `/` separates the components of a diagnostic path, so it can't appear in the system name.
+            for c in stats.name.chars() {
+                path.push(if c == '/' { '_' } else { c });
+            }
+            let entry = frame.entry(path).or_default();
+            entry.0 += stats.last_frame.time;
+            entry.1 += stats.last_frame.run_count;
+            entry.2 += stats.last_frame.skipped_count;
+        }
+
+        let time = Instant::now();
+        for (path, (elapsed, run_count, skipped_count)) in frame {
+            for (suffix, value) in [
+                ("time", elapsed.as_secs_f64() * 1000.0),
+                ("run_count", run_count as f64),
+                ("skipped_count", skipped_count as f64),
+            ] {
+                let path = DiagnosticPath::new(format!("{path}/{suffix}"));
+                if diagnostics.get(&path).is_none() {
+                    let diagnostic = Diagnostic::new(path.clone())
+                        .with_max_history_length(state.max_history_length)
+                        .with_suffix(if suffix == "time" { "ms" } else { "" });
+                    diagnostics.add(diagnostic);
+                }
+                let diagnostic = diagnostics.get_mut(&path).unwrap();
+                if diagnostic.is_enabled {
+                    diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+                }
+            }
+        }
+    }
+
+    /// Logs the systems that took the longest to run during the last frame, if enabled.
+    ///
+    /// The systems of the [`Main`] schedule are not logged, as their time includes every schedule they run.
+    pub fn log_slowest_system(
+        mut state: ResMut<SystemTimingDiagnosticsState>,
+        timings: Res<SystemTimings>,
+        time: Res<Time<Real>>,
+    ) {
+        let Some(n) = state.log_slowest else {
+            return;
+        };
+        if !state.timer.tick(time.delta()).is_finished() {
+            return;
+        }
+
+        let main = Main.intern();
+        let mut message = format!("Slowest {n} systems during the last frame:");
+        for stats in timings
+            .slowest(usize::MAX)
+            .into_iter()
+            .filter(|stats| stats.schedule != main)
+            .take(n)
+        {
+            let _ = write!(
+                message,
+                "\n  {:>10.3}ms {:>4} runs {:>4} skipped  {:?}/{}",
+                stats.last_frame.time.as_secs_f64() * 1000.0,
+                stats.last_frame.run_count,
+                stats.last_frame.skipped_count,
+                stats.schedule,
+                stats.name,
+            );
+        }
+        info!("{message}");
+    }
+}
@@ -1001,6 +1001,18 @@ impl Entities {
         Entity::from_index_and_generation(index, meta.generation)
     }
 
+    /// Sets the [`EntityGeneration`] of a despawned `index`.
+    /// This is used to rewind entity ids when restoring a [`WorldSnapshot`](crate::world::WorldSnapshot).
+    ///
+    /// `index` must be despawned, and the caller must ensure that no other [`Entity`] with this `index`
+    /// is pending reuse in the [`EntityAllocator`].
+    pub(crate) fn set_generation(&mut self, index: EntityIndex, generation: EntityGeneration) {
+        self.ensure_index_index_is_valid(index);
+        let meta = &mut self.meta[index.index() as usize];
+        debug_assert!(meta.location.is_none(), "{index} is spawned");
+        meta.generation = generation;
+    }
+
     /// Mark an [`EntityIndex`] as spawned or despawned in the given tick.
     ///
     /// # Safety
@@ -969,6 +969,67 @@ impl Allocator {
             }
         }
     }
+
+    /// Returns the entities pending reuse in the shared free list and in the local free list, in the order they were freed.
+    ///
+    /// The result is only exact if no remote allocation happens concurrently.
+    pub(crate) fn free_entities(&self) -> (Vec<Entity>, Vec<Entity>) {
+        let len = self.num_free();
+        // SAFETY: `free` requires `&mut self`, so every index below the length has been set and none are being set.
+        let shared = unsafe { self.shared.free.buffer.iter(0..len) }.collect();
+        (shared, self.local_free.to_vec())
+    }
+
+    /// Replaces the free lists and the fresh index cursor with values previously returned by
+    /// [`free_entities`](Self::free_entities) and [`total_entity_indices`](Self::total_entity_indices).
+    /// Unlike [`EntityAllocator::restart`](super::EntityAllocator::restart), this keeps [`RemoteAllocator`]s connected.
+    ///
+    /// The caller must ensure that none of the entities are in use, that no index appears twice,
+    /// and that no index at or above `next_entity_index` is in use.
+    /// Otherwise, entities may be given out twice.
+    pub(crate) fn restore(
+        &mut self,
+        shared_free: &[Entity],
+        local_free: &[Entity],
+        next_entity_index: u32,
+    ) {
+        // Anything that doesn't fit in the local list is freed to the shared list first.
+        let (overflow, local_free) =
+            local_free.split_at(local_free.len().saturating_sub(self.local_free.capacity()));
+
+        // Disable remote allocation while the buffer is rewritten.
+        let state = self
+            .shared
+            .free
+            .len
+            .disable_len_for_state(Ordering::Relaxed);
+        let mut len = 0;
+        shared_free.iter().chain(overflow).for_each(|&entity| {
+            // SAFETY: We have `&mut self` and just disabled remote allocation.
+            unsafe {
+                self.shared.free.buffer.set(len, entity);
+            }
+            len += 1;
+        });
+        self.shared
+            .fresh
+            .next_entity_index
+            .store(next_entity_index, Ordering::Relaxed);
+        // Popping nothing still changes the generation, which makes pending remote allocations retry with the new list.
+        let new_state = state.pop(0).with_length(len);
+        self.shared
+            .free
+            .len
+            .set_state_risky(new_state, Ordering::Release);
+
+        self.local_free.clear();
+        // SAFETY: `local_free` was split to fit the capacity.
+        unsafe {
+            self.local_free
+                .try_extend_from_slice(local_free)
+                .debug_checked_unwrap();
+        }
+    }
 }
 
 impl Drop for Allocator {
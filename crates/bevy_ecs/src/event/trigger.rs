@@ -4,12 +4,13 @@ use crate::{
     component::ComponentId,
     entity::Entity,
     event::{EntityEvent, Event},
-    observer::{CachedObservers, TriggerContext},
+    observer::{CachedObservers, ObserverMap, ObserverRunner, TriggerContext},
     traversal::Traversal,
     world::DeferredWorld,
 };
 use bevy_ptr::PtrMut;
-use core::{fmt, marker::PhantomData};
+use core::{cmp::Reverse, fmt, marker::PhantomData};
+use smallvec::SmallVec;
 
 /// [`Trigger`] determines _how_ an [`Event`] is triggered when [`World::trigger`](crate::world::World::trigger) is called.
 /// This decides which [`Observer`](crate::observer::Observer)s will run, what data gets passed to them, and the order they will
@@ -95,28 +96,27 @@ impl GlobalTrigger {
         mut world: DeferredWorld,
         observers: &CachedObservers,
         trigger_context: &TriggerContext,
-        mut event: PtrMut,
+        event: PtrMut,
     ) {
         // SAFETY: `observers` is the only active reference to something in `world`
         unsafe {
             world.as_unsafe_world_cell().increment_trigger_id();
         }
-        for (observer, runner) in observers.global_observers() {
-            // SAFETY:
-            // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_internal`
-            // - the passed in event pointer is an `Event`, enforced by the call to `trigger_internal`
-            // - `trigger` is a matching trigger type, as it comes from `self`, which is the Trigger for `event`, enforced by `trigger_internal`
-            // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_internal`
-            // - this abides by the nuances defined in the `Trigger` safety docs
-            unsafe {
-                (runner)(
-                    world.reborrow(),
-                    *observer,
-                    trigger_context,
-                    event.reborrow(),
-                    self.into(),
-                );
-            }
+        // SAFETY:
+        // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_internal`
+        // - the passed in event pointer is an `Event`, enforced by the call to `trigger_internal`
+        // - `trigger` is a matching trigger type, as it comes from `self`, which is the Trigger for `event`, enforced by `trigger_internal`
+        // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_internal`
+        // - this abides by the nuances defined in the `Trigger` safety docs
+        unsafe {
+            run_observers(
+                world,
+                observers,
+                &[observers.global_observers()],
+                event,
+                self.into(),
+                trigger_context,
+            );
         }
     }
 }
@@ -176,8 +176,8 @@ unsafe impl<E: EntityEvent + for<'a> Event<Trigger<'a> = Self>> Trigger<E> for E
 pub unsafe fn trigger_entity_internal(
     mut world: DeferredWorld,
     observers: &CachedObservers,
-    mut event: PtrMut,
-    mut trigger: PtrMut,
+    event: PtrMut,
+    trigger: PtrMut,
     target_entity: Entity,
     trigger_context: &TriggerContext,
 ) {
@@ -185,40 +185,135 @@ pub unsafe fn trigger_entity_internal(
     unsafe {
         world.as_unsafe_world_cell().increment_trigger_id();
     }
-    for (observer, runner) in observers.global_observers() {
+    let global_observers = observers.global_observers();
+    let maps = match observers.entity_observers().get(&target_entity) {
+        Some(entity_observers) => &[global_observers, entity_observers][..],
+        None => &[global_observers][..],
+    };
+    // SAFETY:
+    // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_entity_internal`
+    // - the passed in event pointer is an `Event`, enforced by the call to `trigger_entity_internal`
+    // - `trigger` is a matching trigger type, enforced by the call to `trigger_entity_internal`
+    // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_entity_internal`
+    unsafe {
+        run_observers(world, observers, maps, event, trigger, trigger_context);
+    }
+}
+
+/// Trigger observers watching for the given entity event, and observers watching for the given `components`.
+/// The `target_entity` should match the [`EntityEvent::event_target`] on `event` for logical correctness.
+///
+/// # Safety
+/// - `observers` must come from the `world` [`DeferredWorld`], and correspond to observers that match the `event` type
+/// - `event` must point to an [`Event`]
+/// - `trigger` must correspond to the [`Event::Trigger`] type expected by the `event`
+/// - `trigger_context`'s [`TriggerContext::event_key`] must correspond to the `event` type.
+/// - Read, understand, and abide by the [`Trigger`] safety documentation
+#[inline(never)]
+pub(crate) unsafe fn trigger_entity_components_internal(
+    mut world: DeferredWorld,
+    observers: &CachedObservers,
+    event: PtrMut,
+    trigger: PtrMut,
+    target_entity: Entity,
+    components: &[ComponentId],
+    trigger_context: &TriggerContext,
+) {
+    // SAFETY: there are no outstanding world references
+    unsafe {
+        world.as_unsafe_world_cell().increment_trigger_id();
+    }
+    let mut maps = SmallVec::<[&ObserverMap; 4]>::new();
+    maps.push(observers.global_observers());
+    if let Some(entity_observers) = observers.entity_observers().get(&target_entity) {
+        maps.push(entity_observers);
+    }
+    for id in components {
+        if let Some(component_observers) = observers.component_observers().get(id) {
+            maps.push(component_observers.global_observers());
+            if let Some(entity_component_observers) = component_observers
+                .entity_component_observers()
+                .get(&target_entity)
+            {
+                maps.push(entity_component_observers);
+            }
+        }
+    }
+    // SAFETY:
+    // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_entity_components_internal`
+    // - the passed in event pointer is an `Event`, enforced by the call to `trigger_entity_components_internal`
+    // - `trigger` is a matching trigger type, enforced by the call to `trigger_entity_components_internal`
+    // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_entity_components_internal`
+    unsafe {
+        run_observers(world, observers, &maps, event, trigger, trigger_context);
+    }
+}
+
+/// Runs the observers in `maps` in order of descending [`Observer::priority`](crate::observer::Observer::priority),
+/// until an observer calls [`On::stop_observers`](crate::observer::On::stop_observers).
+///
+/// Observers with the same priority run in the order of `maps`, and then in the (unspecified) order of each [`ObserverMap`].
+/// The observers are only sorted if some observer of the event has a non-default priority.
+///
+/// # Safety
+/// - `observers` and `maps` must come from the `world` [`DeferredWorld`], and correspond to observers that match the `event` type
+/// - `event` must point to an [`Event`]
+/// - `trigger` must correspond to the [`Event::Trigger`] type expected by the `event`
+/// - `trigger_context`'s [`TriggerContext::event_key`] must correspond to the `event` type.
+/// - Read, understand, and abide by the [`Trigger`] safety documentation
+pub unsafe fn run_observers(
+    mut world: DeferredWorld,
+    observers: &CachedObservers,
+    maps: &[&ObserverMap],
+    mut event: PtrMut,
+    mut trigger: PtrMut,
+    trigger_context: &TriggerContext,
+) {
+    // Runs a single observer, returning `false` if the trigger was stopped instead.
+    let mut run = |observer: Entity, runner: ObserverRunner| {
+        if world
+            .as_unsafe_world_cell()
+            .observers()
+            .is_trigger_stopped()
+        {
+            return false;
+        }
         // SAFETY:
-        // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_entity_internal`
-        // - the passed in event pointer is an `Event`, enforced by the call to `trigger_entity_internal`
-        // - `trigger` is a matching trigger type, enforced by the call to `trigger_entity_internal`
-        // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_entity_internal`
+        // - `maps` come from `world` and match the `event` type, enforced by the call to `run_observers`
+        // - the passed in event pointer is an `Event`, enforced by the call to `run_observers`
+        // - `trigger` is a matching trigger type, enforced by the call to `run_observers`
+        // - `trigger_context`'s event_key matches `E`, enforced by the call to `run_observers`
         unsafe {
             (runner)(
                 world.reborrow(),
-                *observer,
+                observer,
                 trigger_context,
                 event.reborrow(),
                 trigger.reborrow(),
             );
         }
-    }
+        true
+    };
 
-    if let Some(map) = observers.entity_observers().get(&target_entity) {
-        for (observer, runner) in map {
-            // SAFETY:
-            // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_entity_internal`
-            // - the passed in event pointer is an `Event`, enforced by the call to `trigger_entity_internal`
-            // - `trigger` is a matching trigger type, enforced by the call to `trigger_entity_internal`
-            // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_entity_internal`
-            unsafe {
-                (runner)(
-                    world.reborrow(),
-                    *observer,
-                    trigger_context,
-                    event.reborrow(),
-                    trigger.reborrow(),
-                );
+    let all_observers = maps.iter().flat_map(|map| map.iter());
+    if !observers.has_priorities() {
+        for (&observer, &runner) in all_observers {
+            if !run(observer, runner) {
+                return;
             }
         }
+        return;
+    }
+
+    let mut sorted = all_observers
+        .map(|(&observer, &runner)| (observer, runner, observers.priority(observer)))
+        .collect::<SmallVec<[_; 8]>>();
+    // The sort is stable, so observers with the same priority keep their default order.
+    sorted.sort_by_key(|&(.., priority)| Reverse(priority));
+    for (observer, runner, _) in sorted {
+        if !run(observer, runner) {
+            return;
+        }
     }
 }
 
@@ -299,7 +394,12 @@ unsafe impl<
         }
 
         loop {
-            if !self.propagate {
+            if !self.propagate
+                || world
+                    .as_unsafe_world_cell()
+                    .observers()
+                    .is_trigger_stopped()
+            {
                 return;
             }
             if let Ok(entity) = world.get_entity(current_entity)
@@ -453,70 +553,28 @@ impl<'a> EntityComponentsTrigger<'a> {
     #[inline(never)]
     unsafe fn trigger_internal(
         &mut self,
-        mut world: DeferredWorld,
+        world: DeferredWorld,
         observers: &CachedObservers,
-        mut event: PtrMut,
+        event: PtrMut,
         entity: Entity,
         trigger_context: &TriggerContext,
     ) {
+        let components = self.components;
         // SAFETY:
         // - `observers` come from `world` and match the event type `E`, enforced by the call to `trigger`
         // - the passed in event pointer comes from `event`, which is an `Event`
         // - `trigger` is a matching trigger type, as it comes from `self`, which is the Trigger for `E`
         // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger`
         unsafe {
-            trigger_entity_internal(
-                world.reborrow(),
+            trigger_entity_components_internal(
+                world,
                 observers,
-                event.reborrow(),
+                event,
                 self.into(),
                 entity,
+                components,
                 trigger_context,
             );
         }
-
-        // Trigger observers watching for a specific component
-        for id in self.components {
-            if let Some(component_observers) = observers.component_observers().get(id) {
-                for (observer, runner) in component_observers.global_observers() {
-                    // SAFETY:
-                    // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_internal`
-                    // - the passed in event pointer is an `Event`, enforced by the call to `trigger_internal`
-                    // - `trigger` is a matching trigger type, enforced by the call to `trigger_internal`
-                    // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_internal`
-                    unsafe {
-                        (runner)(
-                            world.reborrow(),
-                            *observer,
-                            trigger_context,
-                            event.reborrow(),
-                            self.into(),
-                        );
-                    }
-                }
-
-                if let Some(map) = component_observers
-                    .entity_component_observers()
-                    .get(&entity)
-                {
-                    for (observer, runner) in map {
-                        // SAFETY:
-                        // - `observers` come from `world` and match the `event` type, enforced by the call to `trigger_internal`
-                        // - the passed in event pointer is an `Event`, enforced by the call to `trigger_internal`
-                        // - `trigger` is a matching trigger type, enforced by the call to `trigger_internal`
-                        // - `trigger_context`'s event_key matches `E`, enforced by the call to `trigger_internal`
-                        unsafe {
-                            (runner)(
-                                world.reborrow(),
-                                *observer,
-                                trigger_context,
-                                event.reborrow(),
-                                self.into(),
-                            );
-                        }
-                    }
-                }
-            }
-        }
     }
 }
@@ -0,0 +1,292 @@
+//! Indexes that look up entities by the value of one of their components.
+//!
+//! Finding the entities with a given component value normally requires iterating over a [`Query`](crate::system::Query).
+//! A [`ComponentIndex`] instead maps each value of an [`IndexableComponent`] to the entities that have it,
+//! so lookups take constant time.
+//!
+//! Indexes are opt-in, and are registered with [`World::register_index`]. Once registered, the index is kept
+//! up to date by the [`on_insert`](crate::lifecycle::ComponentHooks::on_insert) and
+//! [`on_discard`](crate::lifecycle::ComponentHooks::on_discard) hooks of the component.
+//! Because only [immutable](crate::component::Immutable) components can be indexed, every change to an indexed
+//! value goes through these hooks, so the index stays in sync with the [`World`] for as long as its
+//! [`ComponentIndex`] resource exists.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::index::Index;
+//!
+//! #[derive(Component, Clone, PartialEq, Eq, Hash)]
+//! #[component(immutable)]
+//! struct PlayerId(u32);
+//!
+//! let mut world = World::new();
+//! world.register_index::<PlayerId>();
+//! let player = world.spawn(PlayerId(7)).id();
+//!
+//! fn find_player(index: Index<PlayerId>) -> Option<Entity> {
+//!     index.single(&PlayerId(7))
+//! }
+//!
+//! assert_eq!(world.run_system_cached(find_player).unwrap(), Some(player));
+//! ```
+
+use crate::{
+    archetype::{ArchetypeEntity, ArchetypeFlags},
+    component::{Component, Immutable},
+    entity::{Entity, EntityHashSet},
+    lifecycle::{ComponentHook, HookContext},
+    resource::Resource,
+    system::{Res, SystemParam},
+    world::{DeferredWorld, World},
+};
+use alloc::vec::Vec;
+use bevy_platform::collections::HashMap;
+use core::{hash::Hash, ops::Deref};
+
+/// A [`Component`] that can be indexed by [`ComponentIndex`].
+///
+/// This is implemented for every [immutable](crate::component::Immutable) component that implements [`Eq`], [`Hash`]
+/// and [`Clone`].
+pub trait IndexableComponent: Component<Mutability = Immutable> + Eq + Hash + Clone {}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> IndexableComponent for C {}
+
+/// A [`Resource`] mapping each value of the component `C` to the entities that have it.
+///
+/// This is registered with [`World::register_index`], and is usually accessed through the [`Index`] system parameter.
+#[derive(Resource)]
+pub struct ComponentIndex<C: IndexableComponent> {
+    entities: HashMap<C, EntityHashSet>,
+}
+
+impl<C: IndexableComponent> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities: HashMap::default(),
+        }
+    }
+}
+
+impl<C: IndexableComponent> ComponentIndex<C> {
+    /// Returns an iterator over the entities whose `C` component is equal to `value`.
+    pub fn get(&self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.get(value).into_iter().flatten().copied()
+    }
+
+    /// Returns the entity whose `C` component is equal to `value`, if there is exactly one.
+    pub fn single(&self, value: &C) -> Option<Entity> {
+        let entities = self.entities.get(value)?;
+        if entities.len() == 1 {
+            entities.iter().next().copied()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if any entity has a `C` component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.entities.contains_key(value)
+    }
+
+    /// Returns the number of entities whose `C` component is equal to `value`.
+    pub fn count(&self, value: &C) -> usize {
+        self.entities
+            .get(value)
+            .map_or(0, |entities| entities.len())
+    }
+
+    /// Returns an iterator over every distinct value of `C`, along with the entities that have it.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, &EntityHashSet)> {
+        self.entities.iter()
+    }
+
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.entities.entry(value).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(value);
+            }
+        }
+    }
+
+    fn on_insert(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(value) = world.get::<C>(entity).cloned() else {
+            return;
+        };
+        // The index is no longer maintained once its resource has been removed.
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.insert(value, entity);
+        }
+    }
+
+    fn on_discard(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(value) = world.get::<C>(entity).cloned() else {
+            return;
+        };
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.remove(&value, entity);
+        }
+    }
+}
+
+/// A [`SystemParam`] for looking up entities by the value of their `C` component.
+///
+/// The index must have been registered with [`World::register_index`], otherwise the system will fail validation.
+/// See the [module docs](crate::index) for an example.
+#[derive(SystemParam)]
+pub struct Index<'w, C: IndexableComponent> {
+    index: Res<'w, ComponentIndex<C>>,
+}
+
+impl<'w, C: IndexableComponent> Deref for Index<'w, C> {
+    type Target = ComponentIndex<C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+impl World {
+    /// Registers a [`ComponentIndex`] for the component `C`, which can then be used through the [`Index`] system
+    /// parameter.
+    ///
+    /// Entities that already have `C` are added to the index. Registering the same index again has no effect.
+    ///
+    /// If the [`ComponentIndex<C>`] resource is removed, the index is no longer maintained until it is registered again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` has its own [`on_insert`](crate::lifecycle::ComponentHooks::on_insert) or
+    /// [`on_discard`](crate::lifecycle::ComponentHooks::on_discard) hook, since the index is maintained through them.
+    pub fn register_index<C: IndexableComponent>(&mut self) -> &mut Self {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return self;
+        }
+
+        let component_id = self.register_component::<C>();
+        let hooks = self.components.get_hooks_mut(component_id).unwrap();
+        let on_insert: ComponentHook = ComponentIndex::<C>::on_insert;
+        let on_discard: ComponentHook = ComponentIndex::<C>::on_discard;
+        // The hooks stay registered if the index resource was removed, in which case they can be reused.
+        let registered = |hook: Option<ComponentHook>, ours| {
+            hook.is_none_or(|hook| core::ptr::fn_addr_eq(hook, ours))
+        };
+        assert!(
+            registered(hooks.on_insert, on_insert) && registered(hooks.on_discard, on_discard),
+            "Cannot register an index for {} because it already has an on_insert or on_discard hook",
+            core::any::type_name::<C>()
+        );
+        hooks.on_insert = Some(on_insert);
+        hooks.on_discard = Some(on_discard);
+        // Archetypes cache whether their components have hooks, so existing archetypes need to be updated.
+        self.archetypes.update_flags(
+            component_id,
+            ArchetypeFlags::ON_INSERT_HOOK | ArchetypeFlags::ON_DISCARD_HOOK,
+            true,
+        );
+
+        let entities: Vec<Entity> = self
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect();
+        let mut index = ComponentIndex::<C>::default();
+        for entity in entities {
+            if let Some(value) = self.get::<C>(entity) {
+                index.insert(value.clone(), entity);
+            }
+        }
+
+        self.insert_resource(index);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentIndex, Index};
+    use crate::{entity::Entity, prelude::*};
+    use alloc::vec::Vec;
+
+    #[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    #[component(immutable)]
+    enum Team {
+        Red,
+        Blue,
+    }
+
+    fn team(world: &World, team: Team) -> Vec<Entity> {
+        let mut entities: Vec<_> = world
+            .resource::<ComponentIndex<Team>>()
+            .get(&team)
+            .collect();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn index_tracks_insert_replace_remove_and_despawn() {
+        let mut world = World::new();
+        let existing = world.spawn(Team::Red).id();
+        world.register_index::<Team>();
+        assert_eq!(team(&world, Team::Red), [existing]);
+
+        let red = world.spawn(Team::Red).id();
+        let blue = world.spawn(Team::Blue).id();
+        let mut expected = [existing, red];
+        expected.sort();
+        assert_eq!(team(&world, Team::Red), expected);
+        assert_eq!(team(&world, Team::Blue), [blue]);
+
+        // Replacing the value moves the entity to the new value.
+        world.entity_mut(red).insert(Team::Blue);
+        assert_eq!(team(&world, Team::Red), [existing]);
+        let mut expected = [red, blue];
+        expected.sort();
+        assert_eq!(team(&world, Team::Blue), expected);
+
+        world.entity_mut(existing).remove::<Team>();
+        assert!(team(&world, Team::Red).is_empty());
+        assert!(!world
+            .resource::<ComponentIndex<Team>>()
+            .contains(&Team::Red));
+
+        world.despawn(red);
+        assert_eq!(team(&world, Team::Blue), [blue]);
+
+        // Removing the index stops maintaining it, and registering it again rebuilds it.
+        world.remove_resource::<ComponentIndex<Team>>();
+        world.spawn(Team::Red);
+        world.despawn(blue);
+        world.register_index::<Team>();
+        assert_eq!(team(&world, Team::Red).len(), 1);
+        assert!(team(&world, Team::Blue).is_empty());
+    }
+
+    #[test]
+    fn index_system_param() {
+        let mut world = World::new();
+        world.register_index::<Team>();
+        let red = world.spawn(Team::Red).id();
+        world.spawn(Team::Blue);
+        world.spawn(Team::Blue);
+
+        let (red_single, blue_single, blue_count) = world
+            .run_system_cached(|index: Index<Team>| {
+                (
+                    index.single(&Team::Red),
+                    index.single(&Team::Blue),
+                    index.count(&Team::Blue),
+                )
+            })
+            .unwrap();
+        assert_eq!(red_single, Some(red));
+        assert_eq!(blue_single, None);
+        assert_eq!(blue_count, 2);
+    }
+}
@@ -36,6 +36,7 @@ pub mod entity_disabling;
 pub mod error;
 pub mod event;
 pub mod hierarchy;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod lifecycle;
@@ -50,6 +51,7 @@ pub mod relationship;
 pub mod resource;
 pub mod schedule;
 pub mod spawn;
+pub mod stable_id;
 pub mod storage;
 pub mod system;
 pub mod template;
@@ -92,6 +94,7 @@ pub mod prelude {
             Schedules, SystemCondition, SystemSet,
         },
         spawn::{Spawn, SpawnIter, SpawnRelated, SpawnWith, WithOneRelated, WithRelated},
+        stable_id::{StableId, StableIds},
         system::{
             Command, Commands, Deferred, EntityCommand, EntityCommands, If, In, InMut, InRef,
             IntoSystem, Local, NonSend, NonSendMut, ParamSet, Populated, Query, ReadOnlySystem,
@@ -9,10 +9,16 @@
 //!     - These are split by target type, in order to allow for different lookup strategies.
 //!     - [`CachedComponentObservers`] is one of these maps, which contains observers that are specifically targeted at a component.
 
-use bevy_platform::collections::HashMap;
+use bevy_platform::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use crate::{
-    archetype::ArchetypeFlags, component::ComponentId, entity::EntityHashMap, event::EventKey,
+    archetype::ArchetypeFlags,
+    component::ComponentId,
+    entity::{Entity, EntityHashMap},
+    event::EventKey,
     observer::ObserverRunner,
 };
 
@@ -33,9 +39,31 @@ pub struct Observers {
     despawn: CachedObservers,
     // Map from event type to set of observers watching for that event
     cache: HashMap<EventKey, CachedObservers>,
+    // Set by `On::stop_observers` to skip the remaining observers of the current trigger.
+    stopped: AtomicBool,
 }
 
 impl Observers {
+    /// Starts a trigger, returning whether the trigger it is nested in was stopped.
+    pub(crate) fn begin_trigger(&self) -> bool {
+        self.stopped.swap(false, Ordering::Relaxed)
+    }
+
+    /// Ends a trigger started with [`Self::begin_trigger`], restoring the state of the trigger it is nested in.
+    pub(crate) fn end_trigger(&self, outer_stopped: bool) {
+        self.stopped.store(outer_stopped, Ordering::Relaxed);
+    }
+
+    /// Prevents the remaining observers of the current trigger from running.
+    pub(crate) fn stop_trigger(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the remaining observers of the current trigger should not run.
+    pub(crate) fn is_trigger_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
     pub(crate) fn get_observers_mut(&mut self, event_key: EventKey) -> &mut CachedObservers {
         use crate::lifecycle::*;
 
@@ -124,6 +152,8 @@ pub struct CachedObservers {
     pub(super) component_observers: HashMap<ComponentId, CachedComponentObservers>,
     /// Observers watching for triggers of events for a specific entity
     pub(super) entity_observers: EntityHashMap<ObserverMap>,
+    /// The priorities of the observers of this event that don't have the default priority of `0`.
+    pub(super) priorities: EntityHashMap<i32>,
 }
 
 impl CachedObservers {
@@ -142,6 +172,16 @@ impl CachedObservers {
     pub fn entity_observers(&self) -> &EntityHashMap<ObserverMap> {
         &self.entity_observers
     }
+
+    /// Returns the [priority](crate::observer::Observer::with_priority) of the given observer of this event.
+    pub fn priority(&self, observer: Entity) -> i32 {
+        self.priorities.get(&observer).copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if any observer of this event has a priority other than the default of `0`.
+    pub(crate) fn has_priorities(&self) -> bool {
+        !self.priorities.is_empty()
+    }
 }
 
 /// Map between an observer entity and its [`ObserverRunner`]
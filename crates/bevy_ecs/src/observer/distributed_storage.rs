@@ -150,9 +150,29 @@ use crate::prelude::ReflectComponent;
 /// To control the relative ordering of observer trigger commands sent from different systems,
 /// order the systems in the schedule relative to each other.
 ///
-/// Currently, Bevy does not provide [a way to specify the relative ordering of observers](https://github.com/bevyengine/bevy/issues/14890)
-/// watching for the same event. Their ordering is considered to be arbitrary. It is recommended to make no
-/// assumptions about their execution order.
+/// To control the relative ordering of observers watching for the same event, give them a priority with
+/// [`Observer::with_priority`]. Observers with a higher priority run first, and the order of observers with the same
+/// priority is unspecified. This applies across kinds of observers: a high priority entity observer
+/// runs before a low priority global observer of the same event. An observer can prevent the observers after it
+/// from running with [`On::stop_observers`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// #[derive(Event)]
+/// struct Click;
+///
+/// // Runs second, since it has the default priority of 0.
+/// world.add_observer(|_: On<Click>| println!("gameplay"));
+/// // Runs first, and consumes the click.
+/// world.add_observer(
+///     Observer::new(|mut click: On<Click>| {
+///         println!("ui");
+///         click.stop_observers();
+///     })
+///     .with_priority(10),
+/// );
+/// ```
 ///
 /// Commands sent by observers are [currently not immediately applied](https://github.com/bevyengine/bevy/issues/19569).
 /// Instead, all queued observers will run, and then all of the commands from those observers will be applied.
@@ -213,6 +233,7 @@ pub struct Observer {
     pub(crate) despawned_watched_entities: u32,
     pub(crate) runner: ObserverRunner,
     pub(crate) conditions: Vec<ObserverCondition>,
+    pub(crate) priority: i32,
 }
 
 impl Observer {
@@ -240,6 +261,7 @@ impl Observer {
             despawned_watched_entities: 0,
             last_trigger_id: 0,
             conditions: Vec::new(),
+            priority: 0,
         }
     }
 
@@ -278,6 +300,7 @@ impl Observer {
             despawned_watched_entities: 0,
             last_trigger_id: 0,
             conditions: Vec::new(),
+            priority: 0,
         }
     }
 
@@ -352,6 +375,21 @@ impl Observer {
         self
     }
 
+    /// Sets the priority of this observer. Defaults to `0`.
+    ///
+    /// When an event is triggered, observers with a higher priority run before observers with a lower priority.
+    /// The order of observers with the same priority is unspecified.
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the priority of this [`Observer`]. See [`Observer::with_priority`].
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
     /// Returns the [`ObserverDescriptor`] for this [`Observer`].
     pub fn descriptor(&self) -> &ObserverDescriptor {
         &self.descriptor
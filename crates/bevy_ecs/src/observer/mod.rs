@@ -149,8 +149,8 @@ impl World {
     pub unsafe fn trigger_dynamic(
         &mut self,
         event_key: crate::event::EventKey,
-        mut event_data: bevy_ptr::PtrMut,
-        mut trigger_data: bevy_ptr::PtrMut,
+        event_data: bevy_ptr::PtrMut,
+        trigger_data: bevy_ptr::PtrMut,
     ) {
         // SAFETY: We have exclusive access via `&mut self` and will not
         // access observer storage through the returned `DeferredWorld`.
@@ -162,26 +162,27 @@ impl World {
             event_key,
             caller: MaybeLocation::caller(),
         };
+        let outer_stopped = world.as_unsafe_world_cell().observers().begin_trigger();
 
         // SAFETY: no outstanding world references besides `observers`
         unsafe {
             world.as_unsafe_world_cell().increment_trigger_id();
         }
 
-        for (observer, runner) in observers.global_observers() {
-            // SAFETY:
-            // - `observers` come from `world` and correspond to `event_key`
-            // - caller guarantees `event_data` and `trigger_data` are valid
-            unsafe {
-                (runner)(
-                    world.reborrow(),
-                    *observer,
-                    &context,
-                    event_data.reborrow(),
-                    trigger_data.reborrow(),
-                );
-            }
+        // SAFETY:
+        // - `observers` come from `world` and correspond to `event_key`
+        // - caller guarantees `event_data` and `trigger_data` are valid
+        unsafe {
+            crate::event::run_observers(
+                world,
+                observers,
+                &[observers.global_observers()],
+                event_data,
+                trigger_data,
+                &context,
+            );
         }
+        self.observers.end_trigger(outer_stopped);
     }
 
     /// Triggers [`Observer`]s for `event_key` targeting `entity`, with untyped
@@ -206,7 +207,7 @@ impl World {
     ) {
         // SAFETY: We have exclusive access via `&mut self` and will not
         // access observer storage through the returned `DeferredWorld`.
-        let Some((world, observers)) = (unsafe { self.split_for_event(event_key) }) else {
+        let Some((mut world, observers)) = (unsafe { self.split_for_event(event_key) }) else {
             return;
         };
 
@@ -214,6 +215,7 @@ impl World {
             event_key,
             caller: MaybeLocation::caller(),
         };
+        let outer_stopped = world.as_unsafe_world_cell().observers().begin_trigger();
 
         // SAFETY:
         // - `observers` come from `world` and correspond to `event_key`
@@ -229,6 +231,7 @@ impl World {
                 &context,
             );
         }
+        self.observers.end_trigger(outer_stopped);
     }
 
     /// Triggers [`Observer`]s for `event_key` targeting `entity` and
@@ -251,8 +254,8 @@ impl World {
         event_key: crate::event::EventKey,
         entity: Entity,
         components: &[crate::component::ComponentId],
-        mut event_data: bevy_ptr::PtrMut,
-        mut trigger_data: bevy_ptr::PtrMut,
+        event_data: bevy_ptr::PtrMut,
+        trigger_data: bevy_ptr::PtrMut,
     ) {
         // SAFETY: We have exclusive access via `&mut self` and will not
         // access observer storage through the returned `DeferredWorld`.
@@ -264,57 +267,24 @@ impl World {
             event_key,
             caller: MaybeLocation::caller(),
         };
+        let outer_stopped = world.as_unsafe_world_cell().observers().begin_trigger();
 
         // SAFETY:
         // - `observers` come from `world` and correspond to `event_key`
         // - caller guarantees `event_data` and `trigger_data` are valid
-        // - `trigger_entity_internal` increments the trigger id
+        // - `trigger_entity_components_internal` increments the trigger id
         unsafe {
-            crate::event::trigger_entity_internal(
-                world.reborrow(),
+            crate::event::trigger_entity_components_internal(
+                world,
                 observers,
-                event_data.reborrow(),
-                trigger_data.reborrow(),
+                event_data,
+                trigger_data,
                 entity,
+                components,
                 &context,
             );
         }
-
-        // Trigger observers watching for specific components.
-        for id in components {
-            if let Some(component_observers) = observers.component_observers().get(id) {
-                for (observer, runner) in component_observers.global_observers() {
-                    // SAFETY: same as above, caller guarantees data validity
-                    unsafe {
-                        (runner)(
-                            world.reborrow(),
-                            *observer,
-                            &context,
-                            event_data.reborrow(),
-                            trigger_data.reborrow(),
-                        );
-                    }
-                }
-
-                if let Some(map) = component_observers
-                    .entity_component_observers()
-                    .get(&entity)
-                {
-                    for (observer, runner) in map {
-                        // SAFETY: same as above, caller guarantees data validity
-                        unsafe {
-                            (runner)(
-                                world.reborrow(),
-                                *observer,
-                                &context,
-                                event_data.reborrow(),
-                                trigger_data.reborrow(),
-                            );
-                        }
-                    }
-                }
-            }
-        }
+        self.observers.end_trigger(outer_stopped);
     }
 
     /// Register an observer to the cache, called when an observer is created
@@ -334,6 +304,11 @@ impl World {
 
         for &event_key in &descriptor.event_keys {
             let cache = observers.get_observers_mut(event_key);
+            if observer_state.priority != 0 {
+                cache
+                    .priorities
+                    .insert(observer_entity, observer_state.priority);
+            }
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache
@@ -385,6 +360,7 @@ impl World {
 
         for &event_key in &descriptor.event_keys {
             let cache = observers.get_observers_mut(event_key);
+            cache.priorities.remove(&entity);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.global_observers.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -624,6 +600,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn observer_order_priority() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let entity = world.spawn_empty().id();
+
+        world.add_observer(|_: On<EntityEventA>, mut res: ResMut<Order>| res.observed("a"));
+        world.add_observer(
+            Observer::new(|_: On<EntityEventA>, mut res: ResMut<Order>| res.observed("b"))
+                .with_priority(10),
+        );
+        world.add_observer(
+            Observer::new(|_: On<EntityEventA>, mut res: ResMut<Order>| res.observed("c"))
+                .with_priority(1),
+        );
+        world.add_observer(
+            Observer::new(|_: On<EntityEventA>, mut res: ResMut<Order>| res.observed("d"))
+                .with_priority(-5),
+        );
+        world.spawn(
+            Observer::new(|_: On<EntityEventA>, mut res: ResMut<Order>| res.observed("e"))
+                .with_entity(entity)
+                .with_priority(5),
+        );
+        world
+            .entity_mut(entity)
+            .observe(|_: On<EntityEventA>, mut res: ResMut<Order>| res.observed("f"));
+
+        world.trigger(EntityEventA(entity));
+        assert_eq!(
+            vec!["b", "e", "c", "a", "f", "d"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_order_priority_components() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.add_observer(|_: On<Add>, mut res: ResMut<Order>| res.observed("any"));
+        world.add_observer(|_: On<Add, A>, mut res: ResMut<Order>| res.observed("a"));
+        world.add_observer(
+            Observer::new(|_: On<Add, B>, mut res: ResMut<Order>| res.observed("b"))
+                .with_priority(1),
+        );
+
+        world.spawn((A, B));
+        assert_eq!(vec!["b", "any", "a"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_stop_observers() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.add_observer(|_: On<EventPropagating>, mut res: ResMut<Order>| {
+            res.observed("global");
+        });
+        let parent = world
+            .spawn_empty()
+            .observe(|_: On<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("parent");
+            })
+            .id();
+        let child = world.spawn(ChildOf(parent)).id();
+        world.spawn(
+            Observer::new(|mut event: On<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("child");
+                event.stop_observers();
+                assert!(event.observers_stopped());
+            })
+            .with_entity(child)
+            .with_priority(1),
+        );
+
+        world.trigger(EventPropagating(child));
+        assert_eq!(vec!["child"], world.resource::<Order>().0);
+
+        // Stopping only applies to a single trigger.
+        world.resource_mut::<Order>().0.clear();
+        world.trigger(EventPropagating(parent));
+        assert_eq!(vec!["global", "parent"], world.resource::<Order>().0);
+    }
+
     #[test]
     fn observer_trigger_ref() {
         let mut world = World::new();
@@ -76,13 +76,14 @@ pub(super) unsafe fn observer_system_runner<E: Event, B: Bundle, S: ObserverSyst
     // See this thread for more details: <https://github.com/bevyengine/bevy/pull/20731#discussion_r2311907935>
     let trigger: &mut E::Trigger<'_> = unsafe { trigger_ptr.deref_mut() };
 
-    let on: On<E, B> = On::new(
+    let on = On::<E, B>::new(
         // SAFETY: Caller ensures `ptr` is castable to `&mut E`
         unsafe { event_ptr.deref_mut() },
         observer,
         trigger,
         trigger_context,
-    );
+    )
+    .with_observers(world.observers());
 
     // SAFETY:
     // - observer was triggered so must have an `Observer` component.
@@ -4,6 +4,7 @@ use crate::{
     bundle::Bundle,
     change_detection::MaybeLocation,
     event::{Event, EventKey, PropagateEntityTrigger},
+    observer::Observers,
     prelude::*,
     traversal::Traversal,
 };
@@ -43,6 +44,8 @@ pub struct On<'w, 't, E: Event, B: Bundle = ()> {
     trigger: &'w mut E::Trigger<'t>,
     // SAFETY WARNING: never expose this 'w lifetime
     trigger_context: &'w TriggerContext,
+    // SAFETY WARNING: never expose this 'w lifetime
+    observers: Option<&'w Observers>,
     _marker: PhantomData<B>,
 }
 
@@ -59,6 +62,7 @@ impl<'w, 't, E: Event, B: Bundle> On<'w, 't, E, B> {
             observer,
             trigger,
             trigger_context,
+            observers: None,
             _marker: PhantomData,
         }
     }
@@ -123,6 +127,30 @@ impl<'w, 't, E: Event, B: Bundle> On<'w, 't, E, B> {
     pub fn caller(&self) -> MaybeLocation {
         self.trigger_context.caller
     }
+
+    /// Prevents the observers that would run after this one from running for this trigger of the event.
+    ///
+    /// Observers run in order of descending [`Observer::priority`], so this lets a high priority observer
+    /// handle an event exclusively. For propagating [`EntityEvent`]s, this also skips the observers of the entities
+    /// the event propagates to.
+    ///
+    /// This has no effect on an [`On`] created with [`On::new`] rather than by running an observer.
+    pub fn stop_observers(&mut self) {
+        if let Some(observers) = self.observers {
+            observers.stop_trigger();
+        }
+    }
+
+    /// Returns `true` if an observer called [`On::stop_observers`] for this trigger of the event.
+    pub fn observers_stopped(&self) -> bool {
+        self.observers.is_some_and(Observers::is_trigger_stopped)
+    }
+
+    /// Lets [`On::stop_observers`] stop the trigger running this observer.
+    pub(crate) fn with_observers(mut self, observers: &'w Observers) -> Self {
+        self.observers = Some(observers);
+        self
+    }
 }
 
 impl<
@@ -0,0 +1,794 @@
+use crate::{
+    bundle::Bundle,
+    component::{Component, ComponentCloneBehavior, Immutable, Mutable, StorageType},
+    entity::{Entity, EntityIndexMap, EntityIndexSet, EntityMapper},
+    lifecycle::{ComponentHook, HookContext},
+    relationship::RelationshipHookMode,
+    system::{EntityCommand, EntityCommands},
+    world::{DeferredWorld, EntityMut, EntityWorldMut, World},
+};
+use alloc::{format, vec::Vec};
+use bevy_utils::prelude::DebugName;
+use core::marker::PhantomData;
+use log::warn;
+
+/// A many-to-many relationship between entities, optionally carrying data on each edge.
+///
+/// Unlike a [`Relationship`](super::Relationship), which points to at most one target, a [`ManyRelationship`] lets
+/// a "source" entity relate to any number of "target" entities. The source stores its edges in a [`RelatedTo<R>`]
+/// component, which maps each target to a [`Data`](ManyRelationship::Data) payload. Each target stores the sources
+/// that relate to it in a [`RelatedFrom<R>`] component, which is kept in sync by component hooks, just like a
+/// [`RelationshipTarget`](super::RelationshipTarget).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::relationship::{ManyRelationship, RelatedFrom, RelatedTo};
+///
+/// /// A many-to-many relationship without data.
+/// struct Likes;
+///
+/// impl ManyRelationship for Likes {
+///     type Data = ();
+/// }
+///
+/// /// A relationship carrying the inventory slot the item is stored in.
+/// struct InInventory;
+///
+/// impl ManyRelationship for InInventory {
+///     type Data = usize;
+/// }
+///
+/// let mut world = World::new();
+/// let apple = world.spawn_empty().id();
+/// let pear = world.spawn_empty().id();
+/// let alice = world.spawn(RelatedTo::<Likes>::from_targets([apple, pear])).id();
+/// let bob = world.spawn_empty().id();
+/// world.entity_mut(bob).relate::<Likes>(apple, ());
+///
+/// let likers: Vec<_> = world.get::<RelatedFrom<Likes>>(apple).unwrap().iter().collect();
+/// assert_eq!(likers, [alice, bob]);
+///
+/// let bag = world.spawn_empty().id();
+/// world.entity_mut(apple).relate::<InInventory>(bag, 3);
+/// assert_eq!(world.get::<RelatedTo<InInventory>>(apple).unwrap().get(bag), Some(&3));
+/// ```
+pub trait ManyRelationship: Send + Sync + 'static {
+    /// The data stored on each edge of the relationship. Use `()` for relationships without data.
+    type Data: Clone + Send + Sync + 'static;
+
+    /// If this is true, despawning a target entity also despawns every source that relates to it, and cloning a
+    /// target (when [linked cloning is enabled](crate::entity::EntityClonerBuilder::linked_cloning)) also clones its
+    /// sources.
+    ///
+    /// See [`RelationshipTarget::LINKED_SPAWN`](super::RelationshipTarget::LINKED_SPAWN).
+    const LINKED_SPAWN: bool = false;
+
+    /// If this is true, an entity is allowed to relate to itself.
+    const ALLOW_SELF_REFERENTIAL: bool = false;
+}
+
+/// The "source" side of a [`ManyRelationship`], mapping each target entity to the data stored on the edge.
+///
+/// This component is immutable, so that [`RelatedFrom<R>`] cannot fall out of sync. Use [`EntityWorldMut::relate`]
+/// and [`EntityWorldMut::unrelate`] (or their [`EntityCommands`] equivalents) to change individual edges: they
+/// re-insert the map in place and only touch the [`RelatedFrom<R>`] of the changed edge.
+pub struct RelatedTo<R: ManyRelationship> {
+    edges: EntityIndexMap<R::Data>,
+}
+
+impl<R: ManyRelationship> RelatedTo<R> {
+    /// Creates a [`RelatedTo`] without any edges.
+    pub fn new() -> Self {
+        Self {
+            edges: EntityIndexMap::new(),
+        }
+    }
+
+    /// Creates a [`RelatedTo`] relating to each of the `targets` with the default edge data.
+    pub fn from_targets(targets: impl IntoIterator<Item = Entity>) -> Self
+    where
+        R::Data: Default,
+    {
+        targets
+            .into_iter()
+            .map(|target| (target, R::Data::default()))
+            .collect()
+    }
+
+    /// Adds an edge to `target` with the given `data`, replacing any existing edge to `target`.
+    pub fn with(mut self, target: Entity, data: R::Data) -> Self {
+        self.edges.insert(target, data);
+        self
+    }
+
+    /// Returns the data stored on the edge to `target`, if there is one.
+    pub fn get(&self, target: Entity) -> Option<&R::Data> {
+        self.edges.get(&target)
+    }
+
+    /// Returns `true` if there is an edge to `target`.
+    pub fn contains(&self, target: Entity) -> bool {
+        self.edges.contains_key(&target)
+    }
+
+    /// Iterates the target entities, in the order their edges were added.
+    pub fn targets(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.edges.keys().copied()
+    }
+
+    /// Iterates the target entities along with the data stored on each edge.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Entity, &R::Data)> + '_ {
+        self.edges.iter().map(|(target, data)| (*target, data))
+    }
+
+    /// Returns the number of edges.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns `true` if there are no edges.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// The `on_insert` component hook that adds this entity to the [`RelatedFrom`] of each of its targets.
+    fn on_insert(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            caller,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        // Unlike one-to-many relationships, the hooks also run for linked spawns: a cloned source may relate to
+        // targets outside of the cloned hierarchy, which would otherwise never learn about the clone.
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let targets: Vec<Entity> = world.get::<Self>(entity).unwrap().targets().collect();
+        for target in targets {
+            let target_exists = world.get_entity(target).is_ok();
+            if let Some(reason) = invalid_edge::<R>(entity, target, target_exists) {
+                warn!(
+                    "{}The {} edge to {target:?} on entity {entity:?} {reason}. The invalid edge has been removed.",
+                    caller.map(|location| format!("{location}: ")).unwrap_or_default(),
+                    DebugName::type_name::<Self>(),
+                );
+                world
+                    .commands()
+                    .queue_silenced(unrelate::<R>(target).with_entity(entity));
+                continue;
+            }
+
+            // Deferring is necessary for batch mode
+            world
+                .commands()
+                .entity(target)
+                .entry::<RelatedFrom<R>>()
+                .and_modify(move |mut related_from| {
+                    related_from.sources.insert(entity);
+                })
+                .or_insert_with(move || RelatedFrom {
+                    sources: EntityIndexSet::from_iter([entity]),
+                    marker: PhantomData,
+                });
+        }
+    }
+
+    /// The `on_discard` component hook that removes this entity from the [`RelatedFrom`] of each of its targets.
+    fn on_discard(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let targets: Vec<Entity> = world.get::<Self>(entity).unwrap().targets().collect();
+        for target in targets {
+            let Some(mut related_from) = world
+                .get_entity_mut(target)
+                .ok()
+                .and_then(EntityMut::into_mut::<RelatedFrom<R>>)
+            else {
+                continue;
+            };
+            related_from.sources.shift_remove(&entity);
+            if related_from.sources.is_empty() {
+                let command = |mut entity: EntityWorldMut| {
+                    // An identical edge may have been inserted on top before this command runs, so emptiness
+                    // has to be checked again.
+                    if entity
+                        .get::<RelatedFrom<R>>()
+                        .is_some_and(RelatedFrom::is_empty)
+                    {
+                        entity.remove::<RelatedFrom<R>>();
+                    }
+                };
+                world.commands().queue_silenced(command.with_entity(target));
+            }
+        }
+    }
+}
+
+impl<R: ManyRelationship> Default for RelatedTo<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: ManyRelationship> Clone for RelatedTo<R> {
+    fn clone(&self) -> Self {
+        Self {
+            edges: self.edges.clone(),
+        }
+    }
+}
+
+impl<R: ManyRelationship> core::fmt::Debug for RelatedTo<R>
+where
+    R::Data: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.edges.iter()).finish()
+    }
+}
+
+impl<R: ManyRelationship> FromIterator<(Entity, R::Data)> for RelatedTo<R> {
+    fn from_iter<I: IntoIterator<Item = (Entity, R::Data)>>(iter: I) -> Self {
+        Self {
+            edges: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<R: ManyRelationship> Component for RelatedTo<R> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Immutable;
+
+    fn on_insert() -> Option<ComponentHook> {
+        Some(Self::on_insert)
+    }
+
+    fn on_discard() -> Option<ComponentHook> {
+        Some(Self::on_discard)
+    }
+
+    fn clone_behavior() -> ComponentCloneBehavior {
+        ComponentCloneBehavior::clone::<Self>()
+    }
+
+    fn map_entities<E: EntityMapper>(this: &mut Self, mapper: &mut E) {
+        this.edges = this
+            .edges
+            .drain(..)
+            .map(|(target, data)| (mapper.get_mapped(target), data))
+            .collect();
+    }
+}
+
+/// The "target" side of a [`ManyRelationship`], containing every source entity that relates to this entity.
+///
+/// This is maintained automatically by the hooks of [`RelatedTo<R>`] and cannot be changed directly. Removing it
+/// removes the edges to this entity from every source.
+pub struct RelatedFrom<R: ManyRelationship> {
+    sources: EntityIndexSet,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: ManyRelationship> RelatedFrom<R> {
+    /// Returns `true` if `source` relates to this entity.
+    pub fn contains(&self, source: Entity) -> bool {
+        self.sources.contains(&source)
+    }
+
+    /// Iterates the source entities, in the order they started relating to this entity.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.sources.iter().copied()
+    }
+
+    /// Returns the number of source entities.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns `true` if no entities relate to this entity.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// The `on_discard` component hook that removes the edges to this entity from every source.
+    fn on_discard(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        match relationship_hook_mode {
+            RelationshipHookMode::Run => {}
+            RelationshipHookMode::Skip | RelationshipHookMode::RunIfNotLinked => return,
+        }
+        let (entities, mut commands) = world.entities_and_commands();
+        let related_from = entities.get(entity).unwrap().get::<Self>().unwrap();
+        for source in related_from.iter() {
+            commands.queue_silenced(unrelate::<R>(entity).with_entity(source));
+        }
+    }
+
+    /// The `on_despawn` component hook that despawns the sources of a [linked](ManyRelationship::LINKED_SPAWN)
+    /// relationship.
+    fn on_despawn(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let (entities, mut commands) = world.entities_and_commands();
+        let related_from = entities.get(entity).unwrap().get::<Self>().unwrap();
+        for source in related_from.iter() {
+            commands.entity(source).try_despawn();
+        }
+    }
+}
+
+impl<R: ManyRelationship> core::fmt::Debug for RelatedFrom<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.sources.iter()).finish()
+    }
+}
+
+impl<R: ManyRelationship> Component for RelatedFrom<R> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Mutable;
+
+    fn on_discard() -> Option<ComponentHook> {
+        Some(Self::on_discard)
+    }
+
+    fn on_despawn() -> Option<ComponentHook> {
+        R::LINKED_SPAWN.then_some(Self::on_despawn as ComponentHook)
+    }
+
+    fn clone_behavior() -> ComponentCloneBehavior {
+        // The cloned `RelatedFrom` is populated by the hooks of the sources relating to the clone, so the original
+        // sources are never copied over.
+        ComponentCloneBehavior::Custom(|source, context| {
+            let Some(component) = source.read::<Self>() else {
+                return;
+            };
+            if context.linked_cloning() && R::LINKED_SPAWN {
+                for source in component.iter() {
+                    context.queue_entity_clone(source);
+                }
+            } else if context.moving() {
+                let target = context.target();
+                let original = context.source();
+                for source in component.iter() {
+                    context.queue_deferred(move |world: &mut World, _mapper| {
+                        retarget::<R>(world, source, original, target);
+                    });
+                }
+            }
+        })
+    }
+
+    fn map_entities<E: EntityMapper>(this: &mut Self, mapper: &mut E) {
+        this.sources = this
+            .sources
+            .drain(..)
+            .map(|source| mapper.get_mapped(source))
+            .collect();
+    }
+}
+
+/// Moves the edge from `source` to `original` over to `target`, keeping its data.
+fn retarget<R: ManyRelationship>(
+    world: &mut World,
+    source: Entity,
+    original: Entity,
+    target: Entity,
+) {
+    let Ok(mut source) = world.get_entity_mut(source) else {
+        return;
+    };
+    let Some(related_to) = source.get::<RelatedTo<R>>() else {
+        return;
+    };
+    let related_to: RelatedTo<R> = related_to
+        .iter()
+        .map(|(edge, data)| {
+            let edge = if edge == original { target } else { edge };
+            (edge, data.clone())
+        })
+        .collect();
+    source.insert(related_to);
+}
+
+/// Returns why an `R` edge from `source` to `target` is invalid, if it is.
+fn invalid_edge<R: ManyRelationship>(
+    source: Entity,
+    target: Entity,
+    target_exists: bool,
+) -> Option<&'static str> {
+    if !R::ALLOW_SELF_REFERENTIAL && target == source {
+        Some("points to itself")
+    } else if !target_exists {
+        Some("relates to an entity that does not exist")
+    } else {
+        None
+    }
+}
+
+/// An [`EntityCommand`] that removes the `R` edge from the entity to `target`.
+fn unrelate<R: ManyRelationship>(target: Entity) -> impl EntityCommand {
+    move |mut entity: EntityWorldMut| {
+        entity.unrelate::<R>(target);
+    }
+}
+
+/// Changes the [`RelatedTo<R>`] of `entity` in place, running its `Discard` and `Insert` hooks and observers.
+///
+/// The relationship hooks are skipped, so the caller is responsible for updating the [`RelatedFrom<R>`] of the
+/// changed edge.
+fn modify_related_to<R: ManyRelationship>(
+    entity: &mut EntityWorldMut,
+    f: impl FnOnce(&mut RelatedTo<R>),
+) {
+    let source = entity.id();
+    entity.world_scope(|world| {
+        DeferredWorld::from(&mut *world)
+            .modify_component_with_relationship_hook_mode::<RelatedTo<R>, _>(
+                source,
+                RelationshipHookMode::Skip,
+                f,
+            )
+            .expect("entity access must be valid");
+        world.flush();
+    });
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Relates this entity to `target` with the many-to-many relationship `R`, storing `data` on the edge.
+    ///
+    /// If this entity already relates to `target`, the data on the edge is replaced.
+    ///
+    /// If this entity already has a [`RelatedTo<R>`], the edge is added to it in place: the `Discard` and `Insert`
+    /// hooks and observers of [`RelatedTo<R>`] run again, but only the [`RelatedFrom<R>`] of `target` is updated.
+    pub fn relate<R: ManyRelationship>(&mut self, target: Entity, data: R::Data) -> &mut Self {
+        let source = self.id();
+        let Some(related_to) = self.get::<RelatedTo<R>>() else {
+            return self.insert(RelatedTo::<R>::new().with(target, data));
+        };
+        let is_new_edge = !related_to.contains(target);
+        if is_new_edge {
+            let target_exists = self.world().get_entity(target).is_ok();
+            if let Some(reason) = invalid_edge::<R>(source, target, target_exists) {
+                warn!(
+                    "The {} edge to {target:?} on entity {source:?} {reason}. The invalid edge has not been added.",
+                    DebugName::type_name::<RelatedTo<R>>(),
+                );
+                return self;
+            }
+        }
+
+        modify_related_to::<R>(self, |related_to| {
+            related_to.edges.insert(target, data);
+        });
+        if is_new_edge {
+            self.world_scope(|world| {
+                let mut target = world.entity_mut(target);
+                match target.get_mut::<RelatedFrom<R>>() {
+                    Some(mut related_from) => {
+                        related_from.sources.insert(source);
+                    }
+                    None => {
+                        target.insert(RelatedFrom::<R> {
+                            sources: EntityIndexSet::from_iter([source]),
+                            marker: PhantomData,
+                        });
+                    }
+                }
+            });
+        }
+        self
+    }
+
+    /// Removes the many-to-many relationship `R` between this entity and `target`.
+    ///
+    /// The [`RelatedTo<R>`] component is removed when its last edge is removed. Otherwise the edge is removed in
+    /// place, and only the [`RelatedFrom<R>`] of `target` is updated.
+    pub fn unrelate<R: ManyRelationship>(&mut self, target: Entity) -> &mut Self {
+        let source = self.id();
+        let Some(related_to) = self.get::<RelatedTo<R>>() else {
+            return self;
+        };
+        if !related_to.contains(target) {
+            return self;
+        }
+        if related_to.len() == 1 {
+            return self.remove::<RelatedTo<R>>();
+        }
+
+        modify_related_to::<R>(self, |related_to| {
+            related_to.edges.shift_remove(&target);
+        });
+        self.world_scope(|world| {
+            let Ok(mut target) = world.get_entity_mut(target) else {
+                return;
+            };
+            let Some(mut related_from) = target.get_mut::<RelatedFrom<R>>() else {
+                return;
+            };
+            related_from.sources.shift_remove(&source);
+            if related_from.is_empty() {
+                target.remove::<RelatedFrom<R>>();
+            }
+        });
+        self
+    }
+
+    /// Removes every edge of the many-to-many relationship `R` from this entity to its targets.
+    pub fn unrelate_all<R: ManyRelationship>(&mut self) -> &mut Self {
+        self.remove::<RelatedTo<R>>()
+    }
+
+    /// Removes every edge of the many-to-many relationship `R` from other entities to this entity.
+    pub fn detach_all_relating<R: ManyRelationship>(&mut self) -> &mut Self {
+        self.remove::<RelatedFrom<R>>()
+    }
+
+    /// Spawns an entity that relates to this entity with the many-to-many relationship `R`, storing `data` on the
+    /// edge.
+    pub fn with_relating<R: ManyRelationship>(
+        &mut self,
+        bundle: impl Bundle,
+        data: R::Data,
+    ) -> &mut Self {
+        let target = self.id();
+        self.world_scope(|world| {
+            world.spawn((bundle, RelatedTo::<R>::new().with(target, data)));
+        });
+        self
+    }
+
+    /// Despawns every entity that relates to this entity with the many-to-many relationship `R`.
+    /// This entity will not be despawned.
+    pub fn despawn_relating<R: ManyRelationship>(&mut self) -> &mut Self {
+        if let Some(related_from) = self.get::<RelatedFrom<R>>() {
+            let sources = related_from.iter().collect::<Vec<_>>();
+            self.world_scope(|world| {
+                for source in sources {
+                    if let Ok(source) = world.get_entity_mut(source) {
+                        source.despawn();
+                    }
+                }
+            });
+        }
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Relates this entity to `target` with the many-to-many relationship `R`, storing `data` on the edge.
+    ///
+    /// If this entity already relates to `target`, the data on the edge is replaced.
+    pub fn relate<R: ManyRelationship>(&mut self, target: Entity, data: R::Data) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.relate::<R>(target, data);
+        })
+    }
+
+    /// Removes the many-to-many relationship `R` between this entity and `target`.
+    pub fn unrelate<R: ManyRelationship>(&mut self, target: Entity) -> &mut Self {
+        self.queue(unrelate::<R>(target))
+    }
+
+    /// Removes every edge of the many-to-many relationship `R` from this entity to its targets.
+    pub fn unrelate_all<R: ManyRelationship>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.unrelate_all::<R>();
+        })
+    }
+
+    /// Removes every edge of the many-to-many relationship `R` from other entities to this entity.
+    pub fn detach_all_relating<R: ManyRelationship>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.detach_all_relating::<R>();
+        })
+    }
+
+    /// Spawns an entity that relates to this entity with the many-to-many relationship `R`, storing `data` on the
+    /// edge.
+    pub fn with_relating<R: ManyRelationship>(
+        &mut self,
+        bundle: impl Bundle,
+        data: R::Data,
+    ) -> &mut Self {
+        let target = self.id();
+        self.commands
+            .spawn((bundle, RelatedTo::<R>::new().with(target, data)));
+        self
+    }
+
+    /// Despawns every entity that relates to this entity with the many-to-many relationship `R`.
+    /// This entity will not be despawned.
+    pub fn despawn_relating<R: ManyRelationship>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.despawn_relating::<R>();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ManyRelationship, RelatedFrom, RelatedTo};
+    use crate::{
+        entity::Entity, lifecycle::Insert, observer::On, resource::Resource, system::ResMut,
+        world::World,
+    };
+    use alloc::vec::Vec;
+
+    struct Likes;
+
+    impl ManyRelationship for Likes {
+        type Data = ();
+    }
+
+    struct InSlot;
+
+    impl ManyRelationship for InSlot {
+        type Data = u32;
+        const LINKED_SPAWN: bool = true;
+    }
+
+    fn sources<R: ManyRelationship>(world: &World, target: Entity) -> Vec<Entity> {
+        world
+            .get::<RelatedFrom<R>>(target)
+            .map(|related_from| related_from.iter().collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn many_to_many_hooks() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let x = world.spawn(RelatedTo::<Likes>::from_targets([a, b])).id();
+        let y = world.spawn_empty().id();
+        world.entity_mut(y).relate::<Likes>(a, ());
+
+        assert_eq!(sources::<Likes>(&world, a), [x, y]);
+        assert_eq!(sources::<Likes>(&world, b), [x]);
+
+        world.entity_mut(x).unrelate::<Likes>(a);
+        assert_eq!(sources::<Likes>(&world, a), [y]);
+        assert_eq!(sources::<Likes>(&world, b), [x]);
+
+        // Despawning a target removes the edges from its sources.
+        world.despawn(b);
+        assert!(!world.entity(x).contains::<RelatedTo<Likes>>());
+
+        // Despawning a source removes it from its targets.
+        world.despawn(y);
+        assert!(!world.entity(a).contains::<RelatedFrom<Likes>>());
+
+        // Edges to self or to missing entities are removed.
+        let z = world.spawn_empty().id();
+        world.entity_mut(z).relate::<Likes>(z, ());
+        assert!(!world.entity(z).contains::<RelatedTo<Likes>>());
+        world.entity_mut(z).relate::<Likes>(b, ());
+        assert!(!world.entity(z).contains::<RelatedTo<Likes>>());
+    }
+
+    #[test]
+    fn many_to_many_edges_are_changed_in_place() {
+        #[derive(Resource, Default)]
+        struct Inserts(usize);
+
+        let mut world = World::new();
+        world.init_resource::<Inserts>();
+        world.add_observer(
+            |_: On<Insert, RelatedTo<Likes>>, mut inserts: ResMut<Inserts>| {
+                inserts.0 += 1;
+            },
+        );
+        let targets: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+        let x = world.spawn_empty().id();
+        let y = world.spawn_empty().id();
+        for &target in &targets {
+            world.entity_mut(x).relate::<Likes>(target, ());
+        }
+        world.entity_mut(y).relate::<Likes>(targets[0], ());
+        assert_eq!(world.resource::<Inserts>().0, 5);
+        assert_eq!(sources::<Likes>(&world, targets[0]), [x, y]);
+        for &target in &targets[1..] {
+            assert_eq!(sources::<Likes>(&world, target), [x]);
+        }
+
+        // Changing an edge runs the hooks and observers again without touching the other edges.
+        world.entity_mut(x).unrelate::<Likes>(targets[1]);
+        assert_eq!(world.resource::<Inserts>().0, 6);
+        assert_eq!(sources::<Likes>(&world, targets[0]), [x, y]);
+        assert!(!world.entity(targets[1]).contains::<RelatedFrom<Likes>>());
+        let remaining: Vec<Entity> = world
+            .get::<RelatedTo<Likes>>(x)
+            .unwrap()
+            .targets()
+            .collect();
+        assert_eq!(remaining, [targets[0], targets[2], targets[3]]);
+
+        // Invalid edges are never added to an existing `RelatedTo`.
+        world.entity_mut(x).relate::<Likes>(x, ());
+        assert!(!world.get::<RelatedTo<Likes>>(x).unwrap().contains(x));
+    }
+
+    #[test]
+    fn many_to_many_with_data() {
+        let mut world = World::new();
+        let bag = world.spawn_empty().id();
+        let chest = world.spawn_empty().id();
+        let apple = world.spawn_empty().id();
+        world
+            .entity_mut(apple)
+            .relate::<InSlot>(bag, 3)
+            .relate::<InSlot>(chest, 7);
+        assert_eq!(sources::<InSlot>(&world, bag), [apple]);
+
+        let related_to = world.get::<RelatedTo<InSlot>>(apple).unwrap();
+        assert_eq!(related_to.get(bag), Some(&3));
+        assert_eq!(related_to.get(chest), Some(&7));
+
+        // Relating again replaces the data on the edge.
+        world.entity_mut(apple).relate::<InSlot>(bag, 5);
+        assert_eq!(
+            world.get::<RelatedTo<InSlot>>(apple).unwrap().get(bag),
+            Some(&5)
+        );
+        assert_eq!(sources::<InSlot>(&world, bag), [apple]);
+
+        // Linked spawn despawns the sources along with the target.
+        world.despawn(bag);
+        assert!(world.get_entity(apple).is_err());
+        assert!(!world.entity(chest).contains::<RelatedFrom<InSlot>>());
+    }
+
+    #[test]
+    fn many_to_many_cloning() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let x = world.spawn(RelatedTo::<Likes>::from_targets([a, b])).id();
+
+        let x_clone = world.entity_mut(x).clone_and_spawn();
+        assert_eq!(sources::<Likes>(&world, a), [x, x_clone]);
+        assert_eq!(sources::<Likes>(&world, b), [x, x_clone]);
+
+        // Cloning a target does not steal the sources of the original.
+        let a_clone = world.entity_mut(a).clone_and_spawn();
+        assert!(!world.entity(a_clone).contains::<RelatedFrom<Likes>>());
+
+        // Linked cloning clones the sources, relating them to the cloned target and the original other targets.
+        let bag = world.spawn_empty().id();
+        let chest = world.spawn_empty().id();
+        let apple = world.spawn_empty().id();
+        world
+            .entity_mut(apple)
+            .relate::<InSlot>(bag, 3)
+            .relate::<InSlot>(chest, 7);
+        let bag_clone = world
+            .entity_mut(bag)
+            .clone_and_spawn_with_opt_out(|builder| {
+                builder.linked_cloning(true);
+            });
+        let [apple_clone] = sources::<InSlot>(&world, bag_clone)[..] else {
+            panic!("expected exactly one cloned source");
+        };
+        assert_ne!(apple_clone, apple);
+        let related_to = world.get::<RelatedTo<InSlot>>(apple_clone).unwrap();
+        assert_eq!(related_to.get(bag_clone), Some(&3));
+        assert_eq!(related_to.get(chest), Some(&7));
+        assert_eq!(sources::<InSlot>(&world, bag), [apple]);
+        assert_eq!(sources::<InSlot>(&world, chest), [apple, apple_clone]);
+    }
+}
@@ -11,7 +11,7 @@ use crate::{
             DiGraphToposortError, GraphNodeId,
         },
         AmbiguousSystemConflictsWarning, ConflictingSystems, NodeId, ScheduleGraph, SystemKey,
-        SystemSetKey, SystemTypeSetAmbiguityError,
+        SystemSetKey, SystemTypeSetAmbiguityError, TieBreakOrderWarning,
     },
     world::World,
 };
@@ -72,6 +72,19 @@ pub enum ScheduleBuildWarning {
     /// [`LogLevel::Error`]: crate::schedule::LogLevel::Error
     #[error(transparent)]
     Ambiguity(#[from] AmbiguousSystemConflictsWarning),
+    /// Systems with conflicting access are only ordered by the tie-break of
+    /// [`ScheduleBuildSettings::deterministic_order`].
+    ///
+    /// This warning is **disabled** by default, but can be enabled by setting
+    /// [`ScheduleBuildSettings::tie_break_detection`] to [`LogLevel::Warn`]
+    /// or upgraded to a [`ScheduleBuildError`] by setting it to [`LogLevel::Error`].
+    ///
+    /// [`ScheduleBuildSettings::deterministic_order`]: crate::schedule::ScheduleBuildSettings::deterministic_order
+    /// [`ScheduleBuildSettings::tie_break_detection`]: crate::schedule::ScheduleBuildSettings::tie_break_detection
+    /// [`LogLevel::Warn`]: crate::schedule::LogLevel::Warn
+    /// [`LogLevel::Error`]: crate::schedule::LogLevel::Error
+    #[error(transparent)]
+    TieBreak(#[from] TieBreakOrderWarning),
 }
 
 impl ScheduleBuildError {
@@ -259,6 +272,24 @@ impl ScheduleBuildError {
         message
     }
 
+    fn tie_break_to_string(pairs: &[(SystemKey, SystemKey)], graph: &ScheduleGraph) -> String {
+        let mut message = format!(
+            "{} pairs of systems with conflicting data access are only ordered by the order they were added to the \
+            schedule. Consider adding `before` or `after` relationships between these:\n",
+            pairs.len(),
+        );
+        for (first, second) in pairs {
+            writeln!(
+                message,
+                " -- {} runs before {}",
+                graph.get_node_name(&NodeId::System(*first)),
+                graph.get_node_name(&NodeId::System(*second)),
+            )
+            .unwrap();
+        }
+        message
+    }
+
     fn uninitialized_to_string() -> String {
         String::from("tried to run a schedule before all of its systems have been initialized")
     }
@@ -275,6 +306,9 @@ impl ScheduleBuildWarning {
             ScheduleBuildWarning::Ambiguity(AmbiguousSystemConflictsWarning(ambiguities)) => {
                 ScheduleBuildError::ambiguity_to_string(ambiguities, graph, world.components())
             }
+            ScheduleBuildWarning::TieBreak(TieBreakOrderWarning(pairs)) => {
+                ScheduleBuildError::tie_break_to_string(pairs, graph)
+            }
         }
     }
 }
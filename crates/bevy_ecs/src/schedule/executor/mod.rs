@@ -19,8 +19,8 @@ use crate::{
     prelude::{IntoSystemSet, SystemSet},
     query::FilteredAccessSet,
     schedule::{
-        ConditionWithAccess, InternedSystemSet, SystemKey, SystemSetKey, SystemTypeSet,
-        SystemWithAccess,
+        ConditionWithAccess, InternedSystemSet, SystemKey, SystemSetKey, SystemTimingSample,
+        SystemTypeSet, SystemWithAccess,
     },
     system::{RunSystemError, System, SystemIn, SystemStateFlags},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
@@ -104,6 +104,11 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Whether executors should record the run time of each system into `system_timings`.
+    pub(super) record_timings: bool,
+    /// Indexed by system node id.
+    /// Run time measurements recorded since they were last merged into [`SystemTimings`](super::SystemTimings).
+    pub(super) system_timings: Vec<SystemTimingSample>,
 }
 
 impl SystemSchedule {
@@ -119,6 +124,8 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            record_timings: false,
+            system_timings: Vec::new(),
         }
     }
 
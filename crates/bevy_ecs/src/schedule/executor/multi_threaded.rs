@@ -1,9 +1,10 @@
 use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::cell::SyncUnsafeCell;
 use bevy_platform::sync::Arc;
+use bevy_platform::time::Instant;
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use concurrent_queue::ConcurrentQueue;
-use core::{any::Any, panic::AssertUnwindSafe};
+use core::{any::Any, panic::AssertUnwindSafe, time::Duration};
 use fixedbitset::FixedBitSet;
 #[cfg(feature = "std")]
 use std::eprintln;
@@ -22,7 +23,8 @@ use crate::{
     },
     prelude::Resource,
     schedule::{
-        is_apply_deferred, ConditionWithAccess, SystemExecutor, SystemSchedule, SystemWithAccess,
+        is_apply_deferred, ConditionWithAccess, SystemExecutor, SystemSchedule, SystemTimingSample,
+        SystemWithAccess,
     },
     system::{BoxedSystem, RunSystemError, ScheduleSystem},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
@@ -38,6 +40,7 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<SystemWithAccess>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    record_timings: bool,
 }
 
 struct Conditions<'a> {
@@ -63,6 +66,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
             world_cell: world.as_unsafe_world_cell(),
+            record_timings: schedule.record_timings,
         }
     }
 }
@@ -88,6 +92,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// How long the system took to run, if timings are being recorded.
+    elapsed: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -134,6 +140,10 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// Whether the run time of each system is being recorded into `system_timings`.
+    record_timings: bool,
+    /// Run time measurements of each system during the current run.
+    system_timings: Vec<SystemTimingSample>,
 }
 
 /// References to data required by the executor.
@@ -253,6 +263,13 @@ impl SystemExecutor for MultiThreadedExecutor {
             .num_dependencies_remaining
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
+        state.record_timings = schedule.record_timings;
+        if state.record_timings {
+            state.system_timings.clear();
+            state
+                .system_timings
+                .resize(schedule.systems.len(), SystemTimingSample::default());
+        }
 
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -308,6 +325,16 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        if state.record_timings {
+            for (total, sample) in schedule
+                .system_timings
+                .iter_mut()
+                .zip(&state.system_timings)
+            {
+                total.add(sample);
+            }
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -332,12 +359,16 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
+        elapsed: Option<Duration>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                elapsed,
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             #[cfg(feature = "std")]
@@ -419,6 +450,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            record_timings: false,
+            system_timings: Vec::new(),
         }
     }
 
@@ -662,6 +695,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.record_timings.then(Instant::now);
             let res = handle_errors(
                 |system| {
                     // SAFETY:
@@ -679,7 +713,8 @@ impl ExecutorState {
                 context.error_handler,
                 "System panicked",
             );
-            context.system_completed(system_index, res, system);
+            let elapsed = start.map(|start| start.elapsed());
+            context.system_completed(system_index, res, system, elapsed);
         };
 
         if system_meta.is_send {
@@ -706,13 +741,15 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context.environment.record_timings.then(Instant::now);
                 let res = apply_deferred(
                     &unapplied_systems,
                     context.environment.systems,
                     world,
                     context.error_handler,
                 );
-                context.system_completed(system_index, res, system);
+                let elapsed = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, elapsed);
             };
 
             context.scope.spawn_on_scope(task);
@@ -721,13 +758,15 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context.environment.record_timings.then(Instant::now);
                 let res = handle_errors(
                     |system| __rust_begin_short_backtrace::run(system, world),
                     system,
                     context.error_handler,
                     "Exclusive system panicked",
                 );
-                context.system_completed(system_index, res, system);
+                let elapsed = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, elapsed);
             };
 
             context.scope.spawn_on_scope(task);
@@ -738,7 +777,14 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            elapsed,
+        } = result;
+
+        if let Some(elapsed) = elapsed {
+            self.system_timings[system_index].record_run(elapsed);
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -758,6 +804,9 @@ impl ExecutorState {
     }
 
     fn skip_system_and_signal_dependents(&mut self, system_index: usize) {
+        if self.record_timings {
+            self.system_timings[system_index].record_skip();
+        }
         self.completed_systems.insert(system_index);
         self.signal_dependents(system_index);
     }
@@ -3,6 +3,7 @@ use core::panic::AssertUnwindSafe;
 #[cfg(feature = "std")]
 use std::backtrace::Backtrace;
 
+use bevy_platform::time::Instant;
 use fixedbitset::FixedBitSet;
 
 #[cfg(feature = "trace")]
@@ -131,11 +132,19 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems.insert(system_index);
 
             if !should_run {
+                if schedule.record_timings {
+                    schedule.system_timings[system_index].record_skip();
+                }
                 continue;
             }
 
+            let start = schedule.record_timings.then(Instant::now);
+
             if is_apply_deferred(&**system) {
                 self.apply_deferred(schedule, world, error_handler);
+                if let Some(start) = start {
+                    schedule.system_timings[system_index].record_run(start.elapsed());
+                }
                 continue;
             }
 
@@ -164,6 +173,10 @@ impl SystemExecutor for SingleThreadedExecutor {
                 (f)(system);
             }
 
+            if let Some(start) = start {
+                schedule.system_timings[system_index].record_run(start.elapsed());
+            }
+
             self.unapplied_systems.insert(system_index);
         }
 
@@ -10,9 +10,12 @@ mod pass;
 mod schedule;
 mod set;
 mod stepping;
+mod timing;
 
 pub use self::graph::GraphInfo;
-pub use self::{condition::*, config::*, error::*, executor::*, node::*, schedule::*, set::*};
+pub use self::{
+    condition::*, config::*, error::*, executor::*, node::*, schedule::*, set::*, timing::*,
+};
 pub use pass::{FlattenedDependencies, ScheduleBuildPass};
 
 /// An implementation of a graph data structure.
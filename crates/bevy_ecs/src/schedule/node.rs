@@ -576,6 +576,40 @@ impl Systems {
         }
     }
 
+    /// Returns every pair of systems that have no ordering dependency between
+    /// them but would observe each other's changes, so their order affects the
+    /// result of running the schedule.
+    ///
+    /// Unlike [`get_conflicting_systems`](Self::get_conflicting_systems), this
+    /// includes accepted and ignored ambiguities, and conflicts between the
+    /// conditions of one system and the other system.
+    pub fn get_unordered_conflicts(
+        &self,
+        flat_dependency_analysis: &DagAnalysis<SystemKey>,
+    ) -> Vec<(SystemKey, SystemKey)> {
+        let conflicts = |a: SystemKey, b: SystemKey| {
+            let system_b = &self[b];
+            !self[a].access.is_compatible(&system_b.access)
+                || self
+                    .conditions
+                    .get(a)
+                    .into_iter()
+                    .flatten()
+                    .any(|condition| !condition.access.is_compatible(&system_b.access))
+        };
+        flat_dependency_analysis
+            .disconnected()
+            .iter()
+            .copied()
+            .filter(|&(a, b)| {
+                self[a].is_exclusive()
+                    || self[b].is_exclusive()
+                    || conflicts(a, b)
+                    || conflicts(b, a)
+            })
+            .collect()
+    }
+
     /// Calculates the list of systems that conflict with each other based on
     /// their access patterns.
     ///
@@ -700,6 +734,14 @@ impl Deref for ConflictingSystems {
 #[error("Systems with conflicting access have indeterminate run order: {:?}", .0.0)]
 pub struct AmbiguousSystemConflictsWarning(pub ConflictingSystems);
 
+/// Warning returned when [`deterministic_order`](crate::schedule::ScheduleBuildSettings::deterministic_order) had to
+/// order systems with conflicting access that have no ordering dependency between them.
+///
+/// Each pair is in the order the systems run in.
+#[derive(Error, Debug)]
+#[error("Systems with conflicting access are only ordered by the order they were added in: {:?}", .0)]
+pub struct TieBreakOrderWarning(pub Vec<(SystemKey, SystemKey)>);
+
 /// Container for system sets in a schedule.
 #[derive(Default)]
 pub struct SystemSets {
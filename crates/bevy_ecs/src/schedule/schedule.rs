@@ -4,7 +4,7 @@
 )]
 use alloc::{
     boxed::Box,
-    collections::BTreeSet,
+    collections::{BTreeSet, BinaryHeap},
     format,
     string::{String, ToString},
     vec,
@@ -18,6 +18,7 @@ use bevy_platform::{
 use bevy_utils::{default, TypeIdMap};
 use core::{
     any::{Any, TypeId},
+    cmp::Reverse,
     fmt::{Debug, Write},
 };
 use fixedbitset::FixedBitSet;
@@ -572,6 +573,16 @@ impl Schedule {
 
         let error_handler = world.fallback_error_handler();
 
+        self.executable.record_timings = world.contains_resource::<SystemTimings>();
+        if self.executable.record_timings {
+            let sys_count = self.executable.systems.len();
+            self.executable
+                .system_timings
+                .resize(sys_count, SystemTimingSample::default());
+        } else {
+            self.executable.system_timings.clear();
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor
             .run(&mut self.executable, world, None, error_handler);
@@ -590,6 +601,12 @@ impl Schedule {
                 error_handler,
             );
         }
+
+        if self.executable.record_timings
+            && let Some(mut timings) = world.get_resource_mut::<SystemTimings>()
+        {
+            timings.record(self.label, &mut self.executable);
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -1260,6 +1277,41 @@ impl ScheduleGraph {
             }
         }
 
+        // Order the remaining conflicting systems by their position in a topological sort that prefers the
+        // systems added first. These edges agree with that order, so they can't introduce a cycle.
+        if self.settings.deterministic_order {
+            let unordered = self
+                .systems
+                .get_unordered_conflicts(&flat_dependency_analysis);
+            if !unordered.is_empty() {
+                let position = self.insertion_ordered_positions(&flat_dependency);
+                let ordered: Vec<_> = unordered
+                    .into_iter()
+                    .map(|(a, b)| {
+                        if position[&a] < position[&b] {
+                            (a, b)
+                        } else {
+                            (b, a)
+                        }
+                    })
+                    .collect();
+                let graph = flat_dependency.graph_mut();
+                for &(a, b) in &ordered {
+                    graph.add_edge(a, b);
+                }
+                flat_dependency
+                    .ensure_toposorted()
+                    .map_err(ScheduleBuildError::FlatDependencySort)?;
+
+                let warning = ScheduleBuildWarning::TieBreak(TieBreakOrderWarning(ordered));
+                match self.settings.tie_break_detection {
+                    LogLevel::Error => return Err(warning.into()),
+                    LogLevel::Warn => warnings.push(warning),
+                    LogLevel::Ignore => {}
+                }
+            }
+        }
+
         // build the schedule
         Ok((
             self.build_schedule_inner(flat_dependency, hierarchy_analysis),
@@ -1270,6 +1322,42 @@ impl ScheduleGraph {
         ))
     }
 
+    /// Topologically sorts the systems in `flat_dependency`, picking the system that was added first whenever
+    /// several systems could come next. Returns the position of each system in that order.
+    fn insertion_ordered_positions(
+        &self,
+        flat_dependency: &Dag<SystemKey>,
+    ) -> HashMap<SystemKey, usize> {
+        let rank: HashMap<SystemKey, usize> = self
+            .systems
+            .iter()
+            .enumerate()
+            .map(|(i, (key, ..))| (key, i))
+            .collect();
+        let graph = flat_dependency.graph();
+        let mut in_degree: HashMap<SystemKey, usize> = graph
+            .nodes()
+            .map(|key| (key, graph.neighbors_directed(key, Incoming).count()))
+            .collect();
+        let mut ready: BinaryHeap<Reverse<(usize, SystemKey)>> = in_degree
+            .iter()
+            .filter(|&(_, &degree)| degree == 0)
+            .map(|(&key, _)| Reverse((rank[&key], key)))
+            .collect();
+        let mut position = HashMap::with_capacity(in_degree.len());
+        while let Some(Reverse((_, key))) = ready.pop() {
+            position.insert(key, position.len());
+            for next in graph.neighbors_directed(key, Outgoing) {
+                let degree = in_degree.get_mut(&next).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(Reverse((rank[&next], next)));
+                }
+            }
+        }
+        position
+    }
+
     fn build_schedule_inner(
         &self,
         flat_dependency: Dag<SystemKey>,
@@ -1362,6 +1450,8 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            record_timings: false,
+            system_timings: Vec::new(),
         }
     }
 
@@ -1611,6 +1701,29 @@ pub struct ScheduleBuildSettings {
     ///
     /// Defaults to `true`.
     pub report_sets: bool,
+    /// If set to true, systems whose order affects the result of running the schedule, but have no ordering
+    /// dependency between them, are run in a stable order, so every run of the schedule mutates the world in the
+    /// same order. Systems that don't conflict can still run in parallel.
+    ///
+    /// Such systems run in the order they were added to the schedule in, as far as their ordering dependencies
+    /// allow. This applies to accepted and ignored ambiguities too. Use [`tie_break_detection`](Self::tie_break_detection)
+    /// to be told about the systems ordered this way.
+    ///
+    /// This is useful for lockstep networking and for reproducing bugs from replays.
+    ///
+    /// Defaults to `false`.
+    pub deterministic_order: bool,
+    /// Determines whether systems that [`deterministic_order`](Self::deterministic_order) orders by the order they
+    /// were added in are only logged or also result in a [`TieBreak`](ScheduleBuildWarning::TieBreak) warning or
+    /// error.
+    ///
+    /// Unlike [`ambiguity_detection`](Self::ambiguity_detection), this also reports accepted and ignored
+    /// ambiguities, since their order would change if the systems were added in a different order. Set this to
+    /// [`LogLevel::Error`] to require every conflicting system to be ordered explicitly.
+    ///
+    /// Has no effect unless [`deterministic_order`](Self::deterministic_order) is set. Defaults to
+    /// [`LogLevel::Ignore`].
+    pub tie_break_detection: LogLevel,
 }
 
 impl Default for ScheduleBuildSettings {
@@ -1629,6 +1742,8 @@ impl ScheduleBuildSettings {
             auto_insert_apply_deferred: true,
             use_shortnames: true,
             report_sets: true,
+            deterministic_order: false,
+            tie_break_detection: LogLevel::Ignore,
         }
     }
 }
@@ -1675,8 +1790,9 @@ mod tests {
         prelude::{ApplyDeferred, IntoSystemSet, Res, Resource},
         schedule::{
             passes::AutoInsertApplyDeferredPass, tests::ResMut, FlattenedDependencies,
-            IntoScheduleConfigs, Schedule, ScheduleBuildPass, ScheduleBuildSettings,
-            ScheduleCleanupPolicy, SystemSet,
+            IntoScheduleConfigs, LogLevel, Schedule, ScheduleBuildError, ScheduleBuildPass,
+            ScheduleBuildSettings, ScheduleBuildWarning, ScheduleCleanupPolicy, SystemSet,
+            TieBreakOrderWarning,
         },
         system::Commands,
         world::World,
@@ -2674,7 +2790,7 @@ mod tests {
                 _world: &mut World,
                 _graph: &mut super::ScheduleGraph,
                 _dependency_flattened: FlattenedDependencies<'_>,
-            ) -> core::result::Result<(), crate::schedule::ScheduleBuildError> {
+            ) -> core::result::Result<(), ScheduleBuildError> {
                 Ok(())
             }
             fn collapse_set(
@@ -2708,4 +2824,62 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn deterministic_order() {
+        #[derive(Resource, Default)]
+        struct Log(Vec<u32>);
+
+        fn push<const N: u32>(mut log: ResMut<Log>) {
+            log.0.push(N);
+        }
+
+        fn run(tie_break_detection: LogLevel) -> (Vec<u32>, Schedule) {
+            let mut world = World::new();
+            world.init_resource::<Log>();
+            let mut schedule = Schedule::default();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                deterministic_order: true,
+                tie_break_detection,
+                ..Default::default()
+            });
+            schedule.add_systems((push::<2>, push::<0>, push::<3>.before(push::<1>), push::<1>));
+            for _ in 0..3 {
+                schedule.run(&mut world);
+            }
+            (world.remove_resource::<Log>().unwrap().0, schedule)
+        }
+
+        // Systems without an ordering dependency run in the order they were added in.
+        let (log, schedule) = run(LogLevel::Ignore);
+        assert_eq!(log, [2, 0, 3, 1].repeat(3));
+        // The ambiguities are still reported.
+        assert_eq!(schedule.graph().conflicting_systems().0.len(), 5);
+
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.set_build_settings(ScheduleBuildSettings {
+            deterministic_order: true,
+            tie_break_detection: LogLevel::Error,
+            ..Default::default()
+        });
+        schedule.add_systems((push::<0>, push::<1>).ambiguous_with_all());
+        let Err(ScheduleBuildError::Elevated(ScheduleBuildWarning::TieBreak(
+            TieBreakOrderWarning(pairs),
+        ))) = schedule.initialize(&mut world)
+        else {
+            panic!("expected a tie-break error");
+        };
+        assert_eq!(pairs.len(), 1);
+
+        // Explicitly ordered systems don't rely on the tie-break.
+        let mut schedule = Schedule::default();
+        schedule.set_build_settings(ScheduleBuildSettings {
+            deterministic_order: true,
+            tie_break_detection: LogLevel::Error,
+            ..Default::default()
+        });
+        schedule.add_systems((push::<0>, push::<1>).chain());
+        schedule.initialize(&mut world).unwrap();
+    }
 }
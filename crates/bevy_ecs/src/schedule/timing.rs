@@ -0,0 +1,217 @@
+//! Per-system run time measurements collected by the schedule executors.
+
+use alloc::vec::Vec;
+use bevy_platform::collections::HashMap;
+use bevy_utils::prelude::DebugName;
+use core::{cmp::Reverse, time::Duration};
+
+use crate::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, SystemKey, SystemSchedule},
+};
+
+/// Run time measurements of every system, recorded by the built-in executors while this resource exists.
+///
+/// Recording is opt-in: inserting this resource makes every [`Schedule`](super::Schedule) measure how long each
+/// of its systems takes to run and how often they are skipped, and removing it stops the measurements. The
+/// measurements are merged into this resource after each schedule run.
+///
+/// Measurements are accumulated in [`SystemTimingStats::current_frame`] until [`SystemTimings::end_frame`] is
+/// called, which moves them to [`SystemTimingStats::last_frame`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::schedule::SystemTimings;
+///
+/// fn slow_system() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemTimings>();
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(slow_system);
+/// schedule.run(&mut world);
+///
+/// let mut timings = world.resource_mut::<SystemTimings>();
+/// timings.end_frame();
+/// let slowest = timings.slowest(1);
+/// assert_eq!(slowest[0].last_frame.run_count, 1);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct SystemTimings {
+    systems: HashMap<(InternedScheduleLabel, SystemKey), SystemTimingStats>,
+}
+
+impl SystemTimings {
+    /// Iterates the measurements of every system that has been seen by an executor.
+    pub fn iter(&self) -> impl Iterator<Item = &SystemTimingStats> {
+        self.systems.values()
+    }
+
+    /// Returns the `n` systems that took the longest to run during the last frame, slowest first.
+    pub fn slowest(&self, n: usize) -> Vec<&SystemTimingStats> {
+        let mut systems: Vec<_> = self.systems.values().collect();
+        systems.sort_by_key(|stats| Reverse(stats.last_frame.time));
+        systems.truncate(n);
+        systems
+    }
+
+    /// Ends the current frame, moving [`SystemTimingStats::current_frame`] into
+    /// [`SystemTimingStats::last_frame`] for every system.
+    pub fn end_frame(&mut self) {
+        for stats in self.systems.values_mut() {
+            stats.last_frame = core::mem::take(&mut stats.current_frame);
+        }
+    }
+
+    /// Removes every measurement.
+    pub fn clear(&mut self) {
+        self.systems.clear();
+    }
+
+    /// Merges the measurements recorded by the executor while running `schedule`, resetting them.
+    pub(super) fn record(&mut self, label: InternedScheduleLabel, schedule: &mut SystemSchedule) {
+        for ((key, system), sample) in schedule
+            .system_ids
+            .iter()
+            .zip(&schedule.systems)
+            .zip(&mut schedule.system_timings)
+        {
+            let sample = core::mem::take(sample);
+            if sample.run_count == 0 && sample.skipped_count == 0 {
+                continue;
+            }
+            let stats = self
+                .systems
+                .entry((label, *key))
+                .or_insert_with(|| SystemTimingStats {
+                    name: system.system.name(),
+                    schedule: label,
+                    total: SystemTimingSample::default(),
+                    current_frame: SystemTimingSample::default(),
+                    last_frame: SystemTimingSample::default(),
+                });
+            stats.total.add(&sample);
+            stats.current_frame.add(&sample);
+        }
+    }
+}
+
+/// The measurements of a single system in a [`SystemTimings`] resource.
+#[derive(Clone, Debug)]
+pub struct SystemTimingStats {
+    /// The name of the system.
+    pub name: DebugName,
+    /// The schedule the system belongs to.
+    pub schedule: InternedScheduleLabel,
+    /// Measurements accumulated since the system was first seen.
+    pub total: SystemTimingSample,
+    /// Measurements accumulated since the last call to [`SystemTimings::end_frame`].
+    pub current_frame: SystemTimingSample,
+    /// Measurements accumulated between the last two calls to [`SystemTimings::end_frame`].
+    pub last_frame: SystemTimingSample,
+}
+
+/// Run time measurements of a system over some period.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct SystemTimingSample {
+    /// The number of times the system ran.
+    pub run_count: u64,
+    /// The number of times the system was skipped by its run conditions, the run conditions of its sets, or
+    /// stepping.
+    pub skipped_count: u64,
+    /// The total time spent running the system, not including its run conditions or applying its deferred buffers.
+    ///
+    /// For [`ApplyDeferred`](crate::schedule::ApplyDeferred) sync points, this is the time spent applying the
+    /// deferred buffers of the systems before them.
+    pub time: Duration,
+}
+
+impl SystemTimingSample {
+    /// Returns the average time of a single run of the system, or [`Duration::ZERO`] if it never ran.
+    pub fn average_time(&self) -> Duration {
+        if self.run_count == 0 {
+            Duration::ZERO
+        } else {
+            self.time.div_f64(self.run_count as f64)
+        }
+    }
+
+    pub(super) fn record_run(&mut self, time: Duration) {
+        self.run_count += 1;
+        self.time += time;
+    }
+
+    pub(super) fn record_skip(&mut self) {
+        self.skipped_count += 1;
+    }
+
+    pub(super) fn add(&mut self, other: &Self) {
+        self.run_count += other.run_count;
+        self.skipped_count += other.skipped_count;
+        self.time += other.time;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SystemTimingSample, SystemTimings};
+    use crate::{
+        prelude::*,
+        schedule::{ScheduleLabel, SingleThreadedExecutor, SystemExecutor},
+        system::NonSendMarker,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
+    struct TestSchedule;
+
+    fn run_system() {}
+
+    fn skipped_system() {}
+
+    fn main_thread_system(_: NonSendMarker) {}
+
+    fn record_timings(executor: impl SystemExecutor + 'static) {
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.set_executor(executor);
+        schedule.add_systems((
+            run_system,
+            skipped_system.run_if(|| false),
+            (main_thread_system, ApplyDeferred).chain(),
+        ));
+
+        // Nothing is recorded without the resource.
+        schedule.run(&mut world);
+        world.init_resource::<SystemTimings>();
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let mut timings = world.resource_mut::<SystemTimings>();
+        timings.end_frame();
+        let mut counts: Vec<_> = timings
+            .iter()
+            .map(|stats| {
+                assert_eq!(stats.schedule, TestSchedule.intern());
+                assert_eq!(stats.total, stats.last_frame);
+                assert_eq!(stats.current_frame, SystemTimingSample::default());
+                (stats.last_frame.run_count, stats.last_frame.skipped_count)
+            })
+            .collect();
+        counts.sort();
+        assert_eq!(counts, [(0, 2), (2, 0), (2, 0), (2, 0)]);
+        assert_eq!(timings.slowest(1).len(), 1);
+    }
+
+    #[test]
+    fn single_threaded_timings() {
+        record_timings(SingleThreadedExecutor::new());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn multi_threaded_timings() {
+        record_timings(crate::schedule::MultiThreadedExecutor::new());
+    }
+}
@@ -0,0 +1,209 @@
+//! Provides the [`StableId`] [`Component`], used to refer to an [`Entity`] across sessions, saves and network peers.
+//!
+//! [`Entity`] ids are reused and are different every time a world is created, so they can't be stored in save files
+//! or sent to other processes without remapping them. A [`StableId`] is a 64-bit identifier chosen by the
+//! application that never changes. Every [`World`] keeps track of the entity that has each [`StableId`] in the
+//! [`StableIds`] resource, which is maintained by the hooks of the component.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! let mut world = World::new();
+//! let quest_giver = world.spawn(StableId(42)).id();
+//!
+//! // Later, for example after loading a save file that refers to `StableId(42)`.
+//! assert_eq!(world.entity_by_stable_id(StableId(42)), Some(quest_giver));
+//! ```
+
+use crate::{
+    archetype::ArchetypeEntity,
+    component::Component,
+    entity::Entity,
+    lifecycle::HookContext,
+    resource::Resource,
+    world::{DeferredWorld, FromWorld, World},
+};
+use alloc::vec::Vec;
+use bevy_platform::collections::HashMap;
+use log::warn;
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::ReflectComponent,
+    bevy_reflect::{std_traits::ReflectDefault, Reflect},
+};
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A persistent identifier for an [`Entity`], which stays the same across sessions, save files and network peers.
+///
+/// Unlike [`Entity`], the value is never reused or reassigned by the ECS. It is up to the application to choose
+/// unique values, for example with [`StableIds::allocate`], a content hash, or ids assigned by a server.
+/// If two entities have the same [`StableId`], [`StableIds`] only keeps track of the last one it was inserted on.
+///
+/// The component is immutable, so the [`StableIds`] index is always up to date. It is used by world serialization
+/// to resolve entities of a loaded world to the live entities with the same id.
+///
+/// See the [module docs](crate::stable_id) for more.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[component(immutable, on_insert = StableId::on_insert, on_discard = StableId::on_discard)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, Clone, Hash, PartialEq)
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Deserialize, Serialize)
+)]
+pub struct StableId(pub u64);
+
+impl StableId {
+    fn on_insert(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&id) = world.get::<StableId>(entity) else {
+            return;
+        };
+        match world.get_resource_mut::<StableIds>() {
+            Some(mut ids) => ids.insert(id, entity),
+            // The resource indexes every existing `StableId` when it is created, including this one.
+            None => world.commands().init_resource::<StableIds>(),
+        }
+    }
+
+    fn on_discard(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&id) = world.get::<StableId>(entity) else {
+            return;
+        };
+        if let Some(mut ids) = world.get_resource_mut::<StableIds>() {
+            ids.remove(id, entity);
+        }
+    }
+}
+
+/// A [`Resource`] mapping every [`StableId`] in the [`World`] to the [`Entity`] that has it.
+///
+/// This is created automatically when a [`StableId`] is first inserted, and is kept up to date by the hooks of
+/// [`StableId`]. Use [`World::entity_by_stable_id`] or `Res<StableIds>` to look up entities.
+#[derive(Resource, Debug)]
+pub struct StableIds {
+    entities: HashMap<StableId, Entity>,
+    next: u64,
+}
+
+impl StableIds {
+    /// Returns the live entity with the given `id`, if there is one.
+    pub fn get(&self, id: StableId) -> Option<Entity> {
+        self.entities.get(&id).copied()
+    }
+
+    /// Returns `true` if a live entity has the given `id`.
+    pub fn contains(&self, id: StableId) -> bool {
+        self.entities.contains_key(&id)
+    }
+
+    /// Returns the number of live entities with a [`StableId`].
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no live entity has a [`StableId`].
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Returns an iterator over every [`StableId`] and the entity that has it, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (StableId, Entity)> + '_ {
+        self.entities.iter().map(|(&id, &entity)| (id, entity))
+    }
+
+    /// Returns a new [`StableId`] that is greater than every id this world has seen so far.
+    ///
+    /// Ids loaded from a save file are taken into account as soon as they are inserted, so allocating after loading
+    /// never returns an id that is already in use.
+    pub fn allocate(&mut self) -> StableId {
+        let id = StableId(self.next);
+        self.next += 1;
+        id
+    }
+
+    fn insert(&mut self, id: StableId, entity: Entity) {
+        if let Some(previous) = self.entities.insert(id, entity)
+            && previous != entity
+        {
+            warn!("{entity} has the same {id:?} as {previous}. The id now refers to {entity}.");
+        }
+        self.next = self.next.max(id.0.saturating_add(1));
+    }
+
+    fn remove(&mut self, id: StableId, entity: Entity) {
+        if self.entities.get(&id) == Some(&entity) {
+            self.entities.remove(&id);
+        }
+    }
+}
+
+impl FromWorld for StableIds {
+    fn from_world(world: &mut World) -> Self {
+        let mut ids = StableIds {
+            entities: HashMap::default(),
+            next: 0,
+        };
+        let Some(component_id) = world.component_id::<StableId>() else {
+            return ids;
+        };
+        // This looks at archetypes directly so that disabled entities are indexed too.
+        let entities: Vec<Entity> = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect();
+        for entity in entities {
+            if let Some(&id) = world.get::<StableId>(entity) {
+                ids.insert(id, entity);
+            }
+        }
+        ids
+    }
+}
+
+impl World {
+    /// Returns the live entity with the given [`StableId`], if there is one.
+    pub fn entity_by_stable_id(&self, id: StableId) -> Option<Entity> {
+        self.get_resource::<StableIds>()?.get(id)
+    }
+
+    /// Returns a new [`StableId`] that is not used by any entity of this world.
+    ///
+    /// See [`StableIds::allocate`].
+    pub fn allocate_stable_id(&mut self) -> StableId {
+        self.get_resource_or_init::<StableIds>().allocate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StableId, StableIds};
+    use crate::world::World;
+
+    #[test]
+    fn stable_ids_track_entities() {
+        let mut world = World::new();
+        let a = world.spawn(StableId(3)).id();
+        assert_eq!(world.entity_by_stable_id(StableId(3)), Some(a));
+        assert_eq!(world.allocate_stable_id(), StableId(4));
+
+        let b = world.spawn(StableId(10)).id();
+        assert_eq!(world.entity_by_stable_id(StableId(10)), Some(b));
+        assert_eq!(world.allocate_stable_id(), StableId(11));
+
+        world.entity_mut(a).insert(StableId(5));
+        assert_eq!(world.entity_by_stable_id(StableId(3)), None);
+        assert_eq!(world.entity_by_stable_id(StableId(5)), Some(a));
+
+        world.despawn(b);
+        assert_eq!(world.entity_by_stable_id(StableId(10)), None);
+        assert_eq!(world.resource::<StableIds>().len(), 1);
+    }
+}
@@ -0,0 +1,345 @@
+//! Asynchronous access to a [`World`] from tasks running outside of the schedule.
+
+use crate::{
+    bundle::Bundle, change_detection::Mut, component::Component, entity::Entity,
+    resource::Resource, system::Command, world::World,
+};
+use alloc::boxed::Box;
+use bevy_platform::sync::{Arc, Mutex, PoisonError};
+use concurrent_queue::ConcurrentQueue;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+/// A request queued by an [`AsyncWorld`], run with exclusive access to the [`World`] at the next sync point.
+type AsyncWorldRequest = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A handle that lets asynchronous tasks access a [`World`].
+///
+/// Tasks spawned on the [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool) or the
+/// [`IoTaskPool`](bevy_tasks::IoTaskPool) cannot borrow the [`World`] directly. Instead, they queue requests through
+/// this handle, which are run by [`apply_async_world_requests`] at a well-defined point in the schedule. Each
+/// request returns a future that resolves once the request has been run, so multi-step flows can be written as
+/// linear code.
+///
+/// Handles are cheap to clone. Get one from the [`AsyncWorldRequests`] resource or with [`World::async_world`].
+///
+/// If the [`AsyncWorldRequests`] resource is removed or the [`World`] is dropped, every pending and future request
+/// fails with an [`AsyncWorldClosedError`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::{apply_async_world_requests, AsyncWorld};
+/// # use bevy_tasks::block_on;
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// async fn spawn_and_heal(world: AsyncWorld) -> Option<u32> {
+///     let entity = world.spawn(Health(10)).await.ok()?;
+///     // Wait for the next sync point before continuing.
+///     world.next_frame().await.ok()?;
+///     world.run(move |world: &mut World| world.get_mut::<Health>(entity).unwrap().0 += 5).await.ok()?;
+///     world.get::<Health>(entity).await.ok()?.map(|health| health.0)
+/// }
+///
+/// let mut world = World::new();
+/// let mut task = Box::pin(spawn_and_heal(world.async_world()));
+/// let health = loop {
+///     // In an app, the task would be polled by a task pool while the schedule runs.
+///     if let Some(health) = block_on(bevy_tasks::poll_once(&mut task)) {
+///         break health;
+///     }
+///     apply_async_world_requests(&mut world);
+/// };
+/// assert_eq!(health, Some(15));
+/// ```
+#[derive(Clone)]
+pub struct AsyncWorld {
+    queue: Arc<ConcurrentQueue<AsyncWorldRequest>>,
+}
+
+impl AsyncWorld {
+    /// Runs `f` with exclusive access to the [`World`] at the next sync point, returning its output.
+    ///
+    /// Like any future, the returned future does nothing until it is first polled: `f` is only queued then, so
+    /// dropping the future beforehand never runs it. Once queued, `f` runs even if the future is dropped.
+    pub fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World) -> R + Send + 'static,
+    ) -> impl Future<Output = Result<R, AsyncWorldClosedError>> + Send + 'static {
+        let queue = self.queue.clone();
+        async move {
+            let (sender, receiver) = oneshot();
+            queue
+                .push(Box::new(move |world: &mut World| sender.send(f(world))))
+                .map_err(|_| AsyncWorldClosedError)?;
+            receiver.await
+        }
+    }
+
+    /// Queues a [`Command`] to be applied at the next sync point, without waiting for it.
+    ///
+    /// Returns an [`AsyncWorldClosedError`] if the [`World`] can no longer be accessed.
+    pub fn queue(&self, command: impl Command) -> Result<(), AsyncWorldClosedError> {
+        self.queue
+            .push(Box::new(move |world: &mut World| {
+                world.commands().queue(command);
+            }))
+            .map_err(|_| AsyncWorldClosedError)
+    }
+
+    /// Resolves at the next sync point.
+    ///
+    /// This is useful to let the schedule run once between two steps of a task.
+    pub fn next_frame(
+        &self,
+    ) -> impl Future<Output = Result<(), AsyncWorldClosedError>> + Send + 'static {
+        self.run(|_| {})
+    }
+
+    /// Spawns an entity with the given `bundle` at the next sync point, returning its [`Entity`].
+    pub fn spawn<B: Bundle>(
+        &self,
+        bundle: B,
+    ) -> impl Future<Output = Result<Entity, AsyncWorldClosedError>> + Send + 'static {
+        self.run(move |world| world.spawn(bundle).id())
+    }
+
+    /// Returns a clone of the component `C` on `entity` at the next sync point, or [`None`] if the entity does not
+    /// exist or does not have the component.
+    pub fn get<C: Component + Clone>(
+        &self,
+        entity: Entity,
+    ) -> impl Future<Output = Result<Option<C>, AsyncWorldClosedError>> + Send + 'static {
+        self.run(move |world| world.get::<C>(entity).cloned())
+    }
+
+    /// Returns a clone of the resource `R` at the next sync point, or [`None`] if it does not exist.
+    pub fn get_resource<R: Resource + Clone>(
+        &self,
+    ) -> impl Future<Output = Result<Option<R>, AsyncWorldClosedError>> + Send + 'static {
+        self.run(|world| world.get_resource::<R>().cloned())
+    }
+
+    /// Runs `f` with mutable access to the resource `R` at the next sync point, returning its output, or [`None`]
+    /// if the resource does not exist.
+    pub fn resource_scope<R: Resource, T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World, Mut<R>) -> T + Send + 'static,
+    ) -> impl Future<Output = Result<Option<T>, AsyncWorldClosedError>> + Send + 'static {
+        self.run(|world| world.try_resource_scope(f))
+    }
+}
+
+/// The resource that owns the queue of requests made by [`AsyncWorld`] handles.
+///
+/// Requests are run by [`apply_async_world_requests`]. Dropping this resource closes the queue, failing every
+/// pending request.
+#[derive(Resource)]
+pub struct AsyncWorldRequests {
+    queue: Arc<ConcurrentQueue<AsyncWorldRequest>>,
+}
+
+impl AsyncWorldRequests {
+    /// Returns a new [`AsyncWorld`] handle that queues its requests here.
+    pub fn handle(&self) -> AsyncWorld {
+        AsyncWorld {
+            queue: self.queue.clone(),
+        }
+    }
+
+    /// Returns the number of requests waiting for the next sync point.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if no requests are waiting for the next sync point.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl Default for AsyncWorldRequests {
+    fn default() -> Self {
+        Self {
+            queue: Arc::new(ConcurrentQueue::unbounded()),
+        }
+    }
+}
+
+impl Drop for AsyncWorldRequests {
+    fn drop(&mut self) {
+        self.queue.close();
+        // Dropping the requests drops their senders, which wakes the tasks waiting on them.
+        while self.queue.pop().is_ok() {}
+    }
+}
+
+/// Runs the requests queued by [`AsyncWorld`] handles. This is the sync point at which tasks access the [`World`].
+///
+/// Only the requests queued before this is called are run; requests queued while it runs wait for the next call.
+/// The world is flushed after each request, so entities and commands are visible to the following requests.
+pub fn apply_async_world_requests(world: &mut World) {
+    let Some(requests) = world.get_resource::<AsyncWorldRequests>() else {
+        return;
+    };
+    let queue = requests.queue.clone();
+    for _ in 0..queue.len() {
+        let Ok(request) = queue.pop() else {
+            break;
+        };
+        request(world);
+        world.flush();
+    }
+}
+
+impl World {
+    /// Returns an [`AsyncWorld`] handle that lets asynchronous tasks access this world, initializing the
+    /// [`AsyncWorldRequests`] resource if needed.
+    ///
+    /// Requests made through the handle are run by [`apply_async_world_requests`].
+    pub fn async_world(&mut self) -> AsyncWorld {
+        self.get_resource_or_init::<AsyncWorldRequests>().handle()
+    }
+}
+
+/// The error returned by [`AsyncWorld`] requests when the [`World`] can no longer be accessed, because it was
+/// dropped or its [`AsyncWorldRequests`] resource was removed.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The world is no longer accessible from async tasks.")]
+pub struct AsyncWorldClosedError;
+
+/// Creates a single-use channel used to return the output of a request to the task that made it.
+fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let shared = Arc::new(Mutex::new(OneshotState {
+        value: None,
+        closed: false,
+        waker: None,
+    }));
+    (
+        OneshotSender {
+            shared: shared.clone(),
+        },
+        OneshotReceiver { shared },
+    )
+}
+
+struct OneshotState<T> {
+    value: Option<T>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+struct OneshotSender<T> {
+    shared: Arc<Mutex<OneshotState<T>>>,
+}
+
+impl<T> OneshotSender<T> {
+    fn send(self, value: T) {
+        self.shared
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .value = Some(value);
+    }
+}
+
+impl<T> Drop for OneshotSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap_or_else(PoisonError::into_inner);
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct OneshotReceiver<T> {
+    shared: Arc<Mutex<OneshotState<T>>>,
+}
+
+impl<T> Future for OneshotReceiver<T> {
+    type Output = Result<T, AsyncWorldClosedError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(value) = state.value.take() {
+            Poll::Ready(Ok(value))
+        } else if state.closed {
+            Poll::Ready(Err(AsyncWorldClosedError))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_async_world_requests, AsyncWorldClosedError, AsyncWorldRequests};
+    use crate::{component::Component, resource::Resource, world::World};
+    use alloc::boxed::Box;
+    use bevy_tasks::{block_on, poll_once};
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct A(u32);
+
+    #[derive(Resource, Clone, PartialEq, Debug)]
+    struct R(u32);
+
+    #[test]
+    fn requests_run_at_sync_point() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+        let mut spawn = Box::pin(async_world.spawn(A(1)));
+        assert!(block_on(poll_once(&mut spawn)).is_none());
+
+        apply_async_world_requests(&mut world);
+        let entity = block_on(poll_once(&mut spawn)).unwrap().unwrap();
+        assert_eq!(world.get::<A>(entity), Some(&A(1)));
+
+        async_world
+            .queue(move |world: &mut World| {
+                world.insert_resource(R(2));
+            })
+            .unwrap();
+        let mut resource = Box::pin(async_world.get_resource::<R>());
+        let mut component = Box::pin(async_world.get::<A>(entity));
+        assert!(block_on(poll_once(&mut resource)).is_none());
+        assert!(block_on(poll_once(&mut component)).is_none());
+        apply_async_world_requests(&mut world);
+        assert_eq!(block_on(resource.as_mut()), Ok(Some(R(2))));
+        assert_eq!(block_on(component.as_mut()), Ok(Some(A(1))));
+    }
+
+    #[test]
+    fn requests_are_queued_on_first_poll() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+        drop(async_world.spawn(A(1)));
+        assert!(world.resource::<AsyncWorldRequests>().is_empty());
+
+        let mut spawn = Box::pin(async_world.spawn(A(2)));
+        assert!(block_on(poll_once(&mut spawn)).is_none());
+        assert_eq!(world.resource::<AsyncWorldRequests>().len(), 1);
+        apply_async_world_requests(&mut world);
+        let entity = block_on(poll_once(&mut spawn)).unwrap().unwrap();
+        assert_eq!(world.get::<A>(entity), Some(&A(2)));
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn requests_fail_when_world_is_dropped() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+        let pending = async_world.next_frame();
+        world.remove_resource::<AsyncWorldRequests>();
+        assert_eq!(block_on(pending), Err(AsyncWorldClosedError));
+        assert_eq!(
+            block_on(async_world.spawn(A(1))).map(|_| ()),
+            Err(AsyncWorldClosedError)
+        );
+        assert!(async_world.queue(|_: &mut World| {}).is_err());
+    }
+}
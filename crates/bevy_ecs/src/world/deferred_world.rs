@@ -786,17 +786,18 @@ impl<'w> DeferredWorld<'w> {
         caller: MaybeLocation,
     ) {
         // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
-        let (mut world, observers) = unsafe {
+        let (mut world, all_observers, observers) = unsafe {
             let world = self.as_unsafe_world_cell();
-            let observers = world.observers();
-            let Some(observers) = observers.try_get_observers(event_key) else {
+            let all_observers = world.observers();
+            let Some(observers) = all_observers.try_get_observers(event_key) else {
                 return;
             };
-            // SAFETY: The only outstanding reference to world is `observers`
-            (world.into_deferred(), observers)
+            // SAFETY: The only outstanding references to world are `all_observers` and `observers`
+            (world.into_deferred(), all_observers, observers)
         };
         let context = TriggerContext { event_key, caller };
 
+        let outer_stopped = all_observers.begin_trigger();
         // SAFETY:
         // - `observers` comes from `world`, and corresponds to the `event_key`, as it was looked up above
         // - trigger_context contains the correct event_key for `event`, as enforced by the call to `trigger_raw`
@@ -804,6 +805,7 @@ impl<'w> DeferredWorld<'w> {
         unsafe {
             trigger.trigger(world.reborrow(), observers, &context, event);
         }
+        all_observers.end_trigger(outer_stopped);
     }
 
     /// Sends a global [`Event`] without any targets.
@@ -1,11 +1,13 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod async_world;
 pub(crate) mod command_queue;
 mod deferred_world;
 mod entity_access;
 mod entity_fetch;
 mod filtered_resource;
 mod identifier;
+mod snapshot;
 mod spawn_batch;
 
 pub mod error;
@@ -17,6 +19,7 @@ pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
     world::command_queue::CommandQueue,
 };
+pub use async_world::*;
 pub use bevy_ecs_macros::FromWorld;
 pub use deferred_world::DeferredWorld;
 pub use entity_access::{
@@ -27,6 +30,7 @@ pub use entity_access::{
 pub use entity_fetch::{EntityFetcher, WorldEntityFetch};
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+pub use snapshot::*;
 pub use spawn_batch::*;
 
 use crate::{
@@ -0,0 +1,718 @@
+//! Fast snapshots of a filtered part of a [`World`], used to rewind the simulation for rollback networking.
+
+use crate::{
+    change_detection::MaybeLocation,
+    component::{Component, ComponentMutability},
+    entity::{Entity, EntityGeneration, EntityHashSet, EntityIndex},
+    resource::Resource,
+    world::World,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_platform::collections::HashSet;
+use core::{fmt, ops::Range};
+use thiserror::Error;
+
+/// Selects the [`Component`] and [`Resource`] types captured by a [`WorldSnapshot`].
+///
+/// Only types that are [`Clone`] can be captured. Capturing and restoring them is done with typed code,
+/// without going through reflection.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::SnapshotFilter;
+/// #[derive(Component, Clone)]
+/// struct Position(i32);
+///
+/// #[derive(Resource, Clone)]
+/// struct Score(u32);
+///
+/// let filter = SnapshotFilter::new()
+///     .allow_component::<Position>()
+///     .allow_resource::<Score>();
+/// ```
+#[derive(Clone, Default)]
+pub struct SnapshotFilter {
+    captures: Vec<fn(&World) -> Box<dyn SnapshotData>>,
+}
+
+impl SnapshotFilter {
+    /// Creates a filter that does not capture anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Captures the component `T` on every entity that has it.
+    ///
+    /// Entities with at least one captured component are rollback entities: restoring a snapshot
+    /// spawns and despawns them to match the snapshot.
+    pub fn allow_component<T: Component + Clone>(mut self) -> Self {
+        self.captures.push(ComponentSnapshot::<T>::capture);
+        self
+    }
+
+    /// Captures the resource `R`, including whether it exists.
+    pub fn allow_resource<R: Resource + Clone>(mut self) -> Self {
+        self.captures.push(ResourceSnapshot::<R>::capture);
+        self
+    }
+}
+
+impl fmt::Debug for SnapshotFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotFilter")
+            .field("types", &self.captures.len())
+            .finish()
+    }
+}
+
+/// A copy of the components and resources selected by a [`SnapshotFilter`], taken with [`World::snapshot`]
+/// and applied back with [`World::restore_snapshot`].
+///
+/// Besides the captured values, the snapshot records which rollback entities (entities with at least one captured
+/// component) were spawned and the state of the entity allocator. Restoring a snapshot keeps [`Entity`] ids stable:
+/// rollback entities are respawned with the same id, and entities spawned after restoring get the same ids they
+/// got after the snapshot was taken. Re-running a deterministic simulation from a restored snapshot therefore
+/// produces the same entities and values.
+///
+/// This is meant to be taken every tick, so it stores plain typed copies of the captured values. Components stored
+/// in tables are copied one table column at a time, and restoring writes a column back in one pass when its table
+/// still holds the same entities in the same order. Other components, and tables whose entities changed since the
+/// snapshot, are captured or restored one entity at a time.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::SnapshotFilter;
+/// #[derive(Component, Clone, PartialEq, Debug)]
+/// struct Position(i32);
+///
+/// let mut world = World::new();
+/// let player = world.spawn(Position(0)).id();
+/// let filter = SnapshotFilter::new().allow_component::<Position>();
+/// let snapshot = world.snapshot(&filter);
+///
+/// // Mispredicted simulation.
+/// world.get_mut::<Position>(player).unwrap().0 = 10;
+/// world.despawn(player);
+/// let bullet = world.spawn(Position(5)).id();
+///
+/// world.restore_snapshot(&snapshot).unwrap();
+/// assert_eq!(world.get::<Position>(player), Some(&Position(0)));
+/// assert!(world.get_entity(bullet).is_err());
+/// // Spawning again gives out the same id as before the restore.
+/// assert_eq!(world.spawn(Position(5)).id(), bullet);
+/// ```
+///
+/// # Limitations
+///
+/// - Components that are not captured are lost when a rollback entity despawned after the snapshot is respawned,
+///   and they are left as they are on rollback entities that are still spawned.
+///   For relationships, capture the relationship component (like [`ChildOf`](crate::hierarchy::ChildOf)):
+///   restoring it inserts it again, and its hooks rebuild the relationship target.
+/// - Only rollback entities are spawned or despawned by a restore. Entities that existed when the snapshot was taken
+///   and got captured components since only have them removed.
+///   If an entity without captured components was spawned at the index of a rollback entity, the restore fails with
+///   [`RestoreSnapshotError::EntityIndexInUse`].
+/// - Entities allocated but not spawned when restoring (for example with a [`RemoteAllocator`](crate::entity::RemoteAllocator))
+///   may be given out again.
+pub struct WorldSnapshot {
+    /// The rollback entities that were spawned, sorted.
+    entities: Vec<Entity>,
+    shared_free: Vec<Entity>,
+    local_free: Vec<Entity>,
+    next_entity_index: u32,
+    data: Vec<Box<dyn SnapshotData>>,
+}
+
+impl WorldSnapshot {
+    /// Returns the rollback entities that were spawned when the snapshot was taken, sorted.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+}
+
+impl fmt::Debug for WorldSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorldSnapshot")
+            .field("entities", &self.entities)
+            .field("types", &self.data.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error returned by [`World::restore_snapshot`].
+///
+/// The world is not modified when this is returned.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreSnapshotError {
+    /// A rollback entity must be respawned, but its index is used by an entity that the restore does not despawn,
+    /// because it is not a rollback entity.
+    #[error("Cannot respawn {entity}: its index is used by {occupied_by}, which is not a rollback entity.")]
+    EntityIndexInUse {
+        /// The rollback entity that could not be respawned.
+        entity: Entity,
+        /// The entity currently using the index.
+        occupied_by: Entity,
+    },
+}
+
+/// The values of one captured type.
+trait SnapshotData: Send + Sync + 'static {
+    /// The entities that had the component when the snapshot was taken. Empty for resources.
+    fn entities(&self) -> &[Entity];
+
+    /// Adds the entities that currently have the component to `out`.
+    fn current_entities(&self, world: &World, out: &mut EntityHashSet);
+
+    /// Writes the captured values back into `world`. Every entity in [`Self::entities`] must be spawned.
+    fn restore(&self, world: &mut World);
+}
+
+/// The captured values of a component, stored as parallel columns.
+///
+/// Components stored in tables are captured one table column at a time. When restoring, a table that still holds
+/// the same entities in the same order, which is the common case between two ticks, has its column overwritten in
+/// one pass instead of looking up each entity.
+struct ComponentSnapshot<T> {
+    entities: Vec<Entity>,
+    values: Vec<T>,
+    /// The ranges of `entities` and `values` captured from each table. Empty for sparse set components.
+    tables: Vec<Range<usize>>,
+}
+
+impl<T: Component + Clone> ComponentSnapshot<T> {
+    fn capture(world: &World) -> Box<dyn SnapshotData> {
+        let mut entities = Vec::new();
+        let mut values = Vec::new();
+        let mut tables = Vec::new();
+        if let Some(mut query) = world.try_query::<(Entity, &T)>() {
+            if let Ok(iter) = query.contiguous_iter(world) {
+                for (table_entities, table_values) in iter {
+                    let start = entities.len();
+                    entities.extend_from_slice(table_entities);
+                    values.extend_from_slice(table_values);
+                    tables.push(start..entities.len());
+                }
+            } else {
+                let iter = query.iter(world);
+                entities.reserve(iter.len());
+                values.reserve(iter.len());
+                for (entity, value) in iter {
+                    entities.push(entity);
+                    values.push(value.clone());
+                }
+            }
+        }
+        Box::new(Self {
+            entities,
+            values,
+            tables,
+        })
+    }
+
+    /// Writes back the values captured from one table if the table still holds the same entities in the same order.
+    ///
+    /// Returns `false` without writing anything otherwise. `T` must be mutable, since this doesn't run hooks.
+    fn restore_table(&self, world: &mut World, range: Range<usize>) -> bool {
+        let entities = &self.entities[range.clone()];
+        let Some(component_id) = world.component_id::<T>() else {
+            return false;
+        };
+        let Ok(location) = world.entities.get_spawned(entities[0]) else {
+            return false;
+        };
+        let change_tick = world.change_tick();
+        let Some(table) = world.storages.tables.get(location.table_id) else {
+            return false;
+        };
+        if table.entities() != entities {
+            return false;
+        }
+        // SAFETY: The table has a column for `component_id`, which is the id of `T`.
+        let Some(data) = (unsafe { table.get_data_slice_for::<T>(component_id) }) else {
+            return false;
+        };
+        let Some(changed_ticks) = table.get_changed_ticks_slice_for(component_id) else {
+            return false;
+        };
+        for ((value, changed_tick), captured) in
+            data.iter().zip(changed_ticks).zip(&self.values[range])
+        {
+            // SAFETY: `world` is borrowed mutably, so nothing else accesses the table.
+            unsafe {
+                *value.get() = captured.clone();
+                *changed_tick.get() = change_tick;
+            }
+        }
+        table
+            .get_changed_by_slice_for(component_id)
+            .zip(MaybeLocation::caller())
+            .map(|(changed_by, caller)| {
+                for changed_by in changed_by.into_iter().flatten() {
+                    // SAFETY: `world` is borrowed mutably, so nothing else accesses the table.
+                    unsafe { *changed_by.get() = caller };
+                }
+            });
+        true
+    }
+}
+
+impl<T: Component + Clone> SnapshotData for ComponentSnapshot<T> {
+    fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    fn current_entities(&self, world: &World, out: &mut EntityHashSet) {
+        if let Some(mut query) = world.try_query::<(Entity, &T)>() {
+            out.extend(query.iter(world).map(|(entity, _)| entity));
+        }
+    }
+
+    fn restore(&self, world: &mut World) {
+        let captured: EntityHashSet = self.entities.iter().copied().collect();
+        let mut current = EntityHashSet::default();
+        self.current_entities(world, &mut current);
+
+        for &entity in current.iter().filter(|entity| !captured.contains(*entity)) {
+            world.entity_mut(entity).remove::<T>();
+        }
+
+        // Sparse set components are always restored entity by entity.
+        let sparse = self.tables.is_empty().then_some(0..self.entities.len());
+        let remaining: Vec<Range<usize>> = self
+            .tables
+            .iter()
+            .filter(|range| {
+                !(T::Mutability::MUTABLE && self.restore_table(world, (*range).clone()))
+            })
+            .cloned()
+            .chain(sparse)
+            .collect();
+
+        let mut missing = Vec::new();
+        for index in remaining.into_iter().flatten() {
+            let (entity, value) = (self.entities[index], &self.values[index]);
+            if !current.contains(&entity) {
+                missing.push((entity, value.clone()));
+            } else if T::Mutability::MUTABLE {
+                let mut entity = world.entity_mut(entity);
+                // SAFETY: We just checked that `T` is mutable.
+                if let Some(mut component) = unsafe { entity.get_mut_assume_mutable::<T>() } {
+                    *component = value.clone();
+                }
+            } else {
+                // Immutable components are replaced, which runs their hooks and observers.
+                world.entity_mut(entity).insert(value.clone());
+            }
+        }
+        world.insert_batch(missing);
+    }
+}
+
+/// The captured value of a resource.
+struct ResourceSnapshot<R> {
+    value: Option<R>,
+}
+
+impl<R: Resource + Clone> ResourceSnapshot<R> {
+    fn capture(world: &World) -> Box<dyn SnapshotData> {
+        Box::new(Self {
+            value: world.get_resource::<R>().cloned(),
+        })
+    }
+}
+
+impl<R: Resource + Clone> SnapshotData for ResourceSnapshot<R> {
+    fn entities(&self) -> &[Entity] {
+        &[]
+    }
+
+    fn current_entities(&self, _world: &World, _out: &mut EntityHashSet) {}
+
+    fn restore(&self, world: &mut World) {
+        match &self.value {
+            Some(value) => world.insert_resource(value.clone()),
+            None => {
+                world.remove_resource::<R>();
+            }
+        }
+    }
+}
+
+impl World {
+    /// Captures the components and resources selected by `filter`, along with the spawned rollback entities and
+    /// the state of the entity allocator.
+    ///
+    /// See [`WorldSnapshot`] for details.
+    pub fn snapshot(&self, filter: &SnapshotFilter) -> WorldSnapshot {
+        let data: Vec<_> = filter
+            .captures
+            .iter()
+            .map(|capture| capture(self))
+            .collect();
+        let mut entities: Vec<Entity> = data
+            .iter()
+            .flat_map(|data| data.entities())
+            .copied()
+            .collect();
+        entities.sort_unstable();
+        entities.dedup();
+
+        let (shared_free, local_free) = self.entity_allocator.inner.free_entities();
+        WorldSnapshot {
+            entities,
+            shared_free,
+            local_free,
+            next_entity_index: self.entity_allocator.inner.total_entity_indices(),
+            data,
+        }
+    }
+
+    /// Rewinds the world to `snapshot`.
+    ///
+    /// This despawns the rollback entities spawned after the snapshot was taken, respawns the ones despawned since,
+    /// restores the entity allocator so future entities get the same ids they got after the snapshot,
+    /// and writes back the captured components and resources.
+    ///
+    /// Returns an error without modifying the world if a rollback entity can't be respawned.
+    /// See [`WorldSnapshot`] for details.
+    pub fn restore_snapshot(
+        &mut self,
+        snapshot: &WorldSnapshot,
+    ) -> Result<(), RestoreSnapshotError> {
+        self.flush();
+
+        let mut current = EntityHashSet::default();
+        for data in &snapshot.data {
+            data.current_entities(self, &mut current);
+        }
+        let captured: EntityHashSet = snapshot.entities.iter().copied().collect();
+
+        // Entities with captured components are despawned if they were spawned after the snapshot, that is if their
+        // index was fresh, free, or used by a different rollback entity when the snapshot was taken.
+        // Others only have their captured components restored.
+        let taken: HashSet<EntityIndex> = snapshot
+            .entities
+            .iter()
+            .chain(&snapshot.shared_free)
+            .chain(&snapshot.local_free)
+            .map(|entity| entity.index())
+            .collect();
+        let mut despawn: Vec<Entity> = current
+            .into_iter()
+            .filter(|entity| {
+                !captured.contains(entity)
+                    && (entity.index_u32() >= snapshot.next_entity_index
+                        || taken.contains(&entity.index()))
+            })
+            .collect();
+        despawn.sort_unstable();
+
+        // Validate before modifying anything.
+        for &entity in &snapshot.entities {
+            if self.entities.contains_spawned(entity)
+                || !self.entities.is_index_spawned(entity.index())
+            {
+                continue;
+            }
+            let occupied_by = self.entities.resolve_from_index(entity.index());
+            if despawn.binary_search(&occupied_by).is_err() {
+                return Err(RestoreSnapshotError::EntityIndexInUse {
+                    entity,
+                    occupied_by,
+                });
+            }
+        }
+
+        for entity in despawn {
+            // This fails if the entity was despawned along with another one, like a child.
+            let _ = self.try_despawn(entity);
+        }
+        self.flush();
+
+        self.restore_entity_ids(snapshot);
+
+        for &entity in &snapshot.entities {
+            if !self.entities.contains_spawned(entity) {
+                self.entities
+                    .set_generation(entity.index(), entity.generation());
+                self.spawn_empty_at(entity)
+                    .expect("the index of a rollback entity was checked to be free");
+            }
+        }
+
+        for data in &snapshot.data {
+            data.restore(self);
+        }
+        self.flush();
+        Ok(())
+    }
+
+    /// Rewinds the entity allocator and the generations of free indices to `snapshot`,
+    /// after the rollback entities spawned since have been despawned.
+    fn restore_entity_ids(&mut self, snapshot: &WorldSnapshot) {
+        let respawned: HashSet<EntityIndex> = snapshot
+            .entities
+            .iter()
+            .map(|entity| entity.index())
+            .collect();
+        let is_free = |entities: &crate::entity::Entities, entity: &Entity| {
+            !entities.is_index_spawned(entity.index()) && !respawned.contains(&entity.index())
+        };
+        let shared_free: Vec<Entity> = snapshot
+            .shared_free
+            .iter()
+            .filter(|entity| is_free(&self.entities, entity))
+            .copied()
+            .collect();
+        let local_free: Vec<Entity> = snapshot
+            .local_free
+            .iter()
+            .filter(|entity| is_free(&self.entities, entity))
+            .copied()
+            .collect();
+
+        let mut known: HashSet<EntityIndex> = respawned;
+        known.extend(
+            shared_free
+                .iter()
+                .chain(&local_free)
+                .map(|entity| entity.index()),
+        );
+
+        // Indices freed since the snapshot that the snapshot doesn't account for, like those of despawned entities
+        // that are not rollback entities, stay free. They are reused last to keep the reuse order of the snapshot.
+        let (current_shared, current_local) = self.entity_allocator.inner.free_entities();
+        let mut shared: Vec<Entity> = current_shared
+            .into_iter()
+            .chain(current_local)
+            .filter(|entity| {
+                entity.index_u32() < snapshot.next_entity_index
+                    && !known.contains(&entity.index())
+                    && !self.entities.is_index_spawned(entity.index())
+            })
+            .collect();
+
+        // Indices given out fresh since the snapshot are given out again in the same order.
+        // If some of them are still spawned, the cursor can't be rewound, so the others are reused first instead.
+        let current_next = self.entity_allocator.inner.total_entity_indices();
+        let fresh_since: Vec<EntityIndex> = (snapshot.next_entity_index..current_next)
+            .filter_map(EntityIndex::from_raw_u32)
+            .collect();
+        let rewind = fresh_since
+            .iter()
+            .all(|&index| !self.entities.is_index_spawned(index));
+        let next_entity_index = if rewind {
+            snapshot.next_entity_index
+        } else {
+            current_next
+        };
+        let start = shared.len();
+        for &index in &fresh_since {
+            if !self.entities.is_index_spawned(index) {
+                self.entities.set_generation(index, EntityGeneration::FIRST);
+                if !rewind {
+                    shared.push(Entity::from_index(index));
+                }
+            }
+        }
+        // Free lists are used from the back.
+        shared[start..].reverse();
+
+        shared.extend(&shared_free);
+        for entity in shared_free.iter().chain(&local_free) {
+            self.entities
+                .set_generation(entity.index(), entity.generation());
+        }
+
+        self.entity_allocator
+            .inner
+            .restore(&shared, &local_free, next_entity_index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RestoreSnapshotError, SnapshotFilter};
+    use crate::{
+        component::Component, entity::Entity, prelude::*, resource::Resource, world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    struct Position(i32);
+
+    #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    #[component(immutable)]
+    struct Velocity(i32);
+
+    #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    struct Untracked;
+
+    #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    #[component(storage = "SparseSet")]
+    struct Sparse(i32);
+
+    #[derive(Resource, Clone, Copy, PartialEq, Debug, Default)]
+    struct Tick(u32);
+
+    fn filter() -> SnapshotFilter {
+        SnapshotFilter::new()
+            .allow_component::<Position>()
+            .allow_component::<Velocity>()
+            .allow_resource::<Tick>()
+    }
+
+    /// Moves entities, despawns those that go too far, and spawns new ones.
+    fn simulate(
+        mut commands: Commands,
+        mut tick: ResMut<Tick>,
+        mut query: Query<(Entity, &mut Position, &Velocity)>,
+    ) {
+        tick.0 += 1;
+        for (entity, mut position, velocity) in &mut query {
+            position.0 += velocity.0;
+            if position.0 > 12 {
+                commands.entity(entity).despawn();
+            }
+        }
+        if tick.0.is_multiple_of(2) {
+            commands.spawn((Position(0), Velocity(tick.0 as i32 % 3 + 1)));
+        }
+    }
+
+    fn state(world: &mut World) -> (u32, Vec<(Entity, Position, Velocity)>) {
+        let mut entities: Vec<_> = world
+            .query::<(Entity, &Position, &Velocity)>()
+            .iter(world)
+            .map(|(entity, position, velocity)| (entity, *position, *velocity))
+            .collect();
+        entities.sort_by_key(|(entity, ..)| *entity);
+        (world.resource::<Tick>().0, entities)
+    }
+
+    #[test]
+    fn restore_tables_in_bulk() {
+        let mut world = World::new();
+        let filter = SnapshotFilter::new()
+            .allow_component::<Position>()
+            .allow_component::<Sparse>();
+        let a = world.spawn(Position(1)).id();
+        let b = world.spawn((Position(2), Velocity(0))).id();
+        let c = world.spawn((Position(3), Sparse(3))).id();
+        let snapshot = world.snapshot(&filter);
+
+        for mut position in world.query::<&mut Position>().iter_mut(&mut world) {
+            position.0 *= 10;
+        }
+        world.get_mut::<Sparse>(c).unwrap().0 = 30;
+        // Despawning `a` makes the table of `a` and `c` fall back to restoring entity by entity, while the table `b`
+        // moved to still only holds `b` and is restored in bulk.
+        world.entity_mut(b).insert(Untracked);
+        world.despawn(a);
+        world.clear_trackers();
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(world.get::<Position>(b), Some(&Position(2)));
+        assert_eq!(world.get::<Position>(c), Some(&Position(3)));
+        assert_eq!(world.get::<Sparse>(c), Some(&Sparse(3)));
+        let mut changed = world.query_filtered::<Entity, Changed<Position>>();
+        assert_eq!(changed.iter(&world).count(), 3);
+    }
+
+    #[test]
+    fn restore_then_resimulate_is_deterministic() {
+        let mut world = World::new();
+        world.init_resource::<Tick>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(simulate);
+
+        for _ in 0..7 {
+            schedule.run(&mut world);
+        }
+        let snapshot = world.snapshot(&filter());
+        let at_snapshot = state(&mut world);
+
+        let mut predicted = Vec::new();
+        for _ in 0..15 {
+            schedule.run(&mut world);
+            predicted.push(state(&mut world));
+        }
+
+        // Spawn an entity that isn't part of the simulation, and a mispredicted rollback entity.
+        let untracked = world.spawn(Untracked).id();
+        let extra = world.spawn((Position(100), Velocity(0))).id();
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(state(&mut world), at_snapshot);
+        assert!(world.get_entity(extra).is_err());
+        assert!(world.get_entity(untracked).is_ok());
+
+        let mut resimulated = Vec::new();
+        for _ in 0..15 {
+            schedule.run(&mut world);
+            resimulated.push(state(&mut world));
+        }
+        assert_eq!(predicted, resimulated);
+    }
+
+    #[test]
+    fn restore_components_and_resources() {
+        let mut world = World::new();
+        let a = world.spawn((Position(1), Velocity(1))).id();
+        let b = world.spawn(Position(2)).id();
+        let untracked = world.spawn(Untracked).id();
+        let snapshot = world.snapshot(&filter());
+        assert_eq!(snapshot.entities().len(), 2);
+        assert!(snapshot.entities().contains(&a) && snapshot.entities().contains(&b));
+
+        world.insert_resource(Tick(3));
+        world.entity_mut(a).remove::<Velocity>().insert(Untracked);
+        world.entity_mut(b).insert(Velocity(5));
+        world.get_mut::<Position>(b).unwrap().0 = 10;
+        world.despawn(a);
+        world.entity_mut(untracked).insert(Position(4));
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert!(!world.contains_resource::<Tick>());
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(world.get::<Velocity>(a), Some(&Velocity(1)));
+        // Components that aren't captured are lost when respawning.
+        assert!(world.get::<Untracked>(a).is_none());
+        assert_eq!(world.get::<Position>(b), Some(&Position(2)));
+        assert!(world.get::<Velocity>(b).is_none());
+        // The entity existed when the snapshot was taken, so only its captured components are removed.
+        assert!(world.get::<Position>(untracked).is_none());
+        assert_eq!(world.query::<&Position>().iter(&world).len(), 2);
+    }
+
+    #[test]
+    fn restore_fails_if_index_is_in_use() {
+        let mut world = World::new();
+        let a = world.spawn(Position(1)).id();
+        let snapshot = world.snapshot(&filter());
+
+        let reused = world.despawn_no_free(a).unwrap();
+        world.spawn_empty_at(reused).unwrap();
+
+        assert_eq!(
+            world.restore_snapshot(&snapshot),
+            Err(RestoreSnapshotError::EntityIndexInUse {
+                entity: a,
+                occupied_by: reused,
+            })
+        );
+        assert!(world.get_entity(reused).is_ok());
+
+        world.despawn(reused);
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(
+            vec![a],
+            world
+                .query_filtered::<Entity, With<Position>>()
+                .iter(&world)
+                .collect::<Vec<_>>()
+        );
+    }
+}
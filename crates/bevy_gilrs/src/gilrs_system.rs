@@ -1,15 +1,18 @@
 use crate::{
     converter::{convert_axis, convert_button},
+    rumble::RunningRumbleEffects,
     Gilrs, GilrsGamepads,
 };
 use bevy_ecs::message::MessageWriter;
 use bevy_ecs::prelude::Commands;
-use bevy_ecs::system::ResMut;
+use bevy_ecs::system::{Res, ResMut};
 use bevy_input::gamepad::{
     GamepadConnection, GamepadConnectionEvent, RawGamepadAxisChangedEvent,
     RawGamepadButtonChangedEvent, RawGamepadEvent,
 };
+use bevy_input::gamepad_mapping::GamepadMappings;
 use gilrs::{ev::filter::axis_dpad_to_button, EventType, Filter};
+use tracing::error;
 
 pub fn gilrs_event_startup_system(
     mut commands: Commands,
@@ -111,3 +114,71 @@ pub fn gilrs_event_system(
         gilrs.inc();
     });
 }
+
+/// Restarts Gilrs when the SDL mappings of [`GamepadMappings`] change, since Gilrs only reads
+/// mappings when it is created.
+///
+/// The gamepads of the new instance are reported as connected again, keeping the entity of the
+/// gamepad with the same UUID.
+pub fn update_gilrs_mappings(
+    mut commands: Commands,
+    mappings: Res<GamepadMappings>,
+    mut gilrs: ResMut<Gilrs>,
+    mut gamepads: ResMut<GilrsGamepads>,
+    mut running_rumbles: ResMut<RunningRumbleEffects>,
+    mut events: MessageWriter<RawGamepadEvent>,
+    mut connection_events: MessageWriter<GamepadConnectionEvent>,
+) {
+    let sdl_mappings = mappings.sdl_mappings().collect::<Vec<_>>().join("\n");
+    if sdl_mappings == gilrs.sdl_mappings {
+        return;
+    }
+
+    let mut previous = Vec::new();
+    gilrs.with(|gilrs| {
+        for (id, gamepad) in gilrs.gamepads() {
+            if let Some(entity) = gamepads.get_entity(id) {
+                previous.push((gamepad.uuid(), entity));
+            }
+        }
+    });
+    match Gilrs::build(sdl_mappings) {
+        Ok(new_gilrs) => *gilrs = new_gilrs,
+        Err(err) => {
+            error!(
+                "Failed to restart Gilrs with the new gamepad mappings. {}",
+                err
+            );
+            return;
+        }
+    }
+    // Rumble effects and gamepad ids belong to the previous instance.
+    running_rumbles.clear();
+    *gamepads = GilrsGamepads::default();
+
+    gilrs.with(|gilrs| {
+        for (id, pad) in gilrs.gamepads() {
+            let entity = match previous.iter().position(|(uuid, _)| *uuid == pad.uuid()) {
+                Some(index) => previous.swap_remove(index).1,
+                None => commands.spawn_empty().id(),
+            };
+            gamepads.id_to_entity.insert(id, entity);
+            gamepads.entity_to_id.insert(entity, id);
+            let event = GamepadConnectionEvent::new(
+                entity,
+                GamepadConnection::Connected {
+                    name: pad.name().to_string(),
+                    vendor_id: pad.vendor_id(),
+                    product_id: pad.product_id(),
+                },
+            );
+            events.write(event.clone().into());
+            connection_events.write(event);
+        }
+    });
+    for (_, entity) in previous {
+        let event = GamepadConnectionEvent::new(entity, GamepadConnection::Disconnected);
+        events.write(event.clone().into());
+        connection_events.write(event);
+    }
+}
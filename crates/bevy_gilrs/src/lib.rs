@@ -23,10 +23,10 @@ use core::cell::RefCell;
 use bevy_app::{App, Plugin, PostUpdate, PreStartup, PreUpdate};
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::prelude::*;
-use bevy_input::InputSystems;
+use bevy_input::{gamepad_mapping::GamepadMappings, InputBackendSystems};
 use bevy_platform::collections::HashMap;
 use gilrs::GilrsBuilder;
-use gilrs_system::{gilrs_event_startup_system, gilrs_event_system};
+use gilrs_system::{gilrs_event_startup_system, gilrs_event_system, update_gilrs_mappings};
 use rumble::{play_gilrs_rumble, RunningRumbleEffects};
 use tracing::error;
 
@@ -46,9 +46,33 @@ thread_local! {
 pub(crate) struct Gilrs {
     #[cfg(not(target_arch = "wasm32"))]
     cell: SyncCell<gilrs::Gilrs>,
+    /// The SDL mappings of [`GamepadMappings`] this instance was built with.
+    sdl_mappings: String,
 }
 
 impl Gilrs {
+    /// Builds a Gilrs instance with the given newline separated SDL mappings.
+    #[expect(
+        clippy::result_large_err,
+        reason = "Gilrs is only built on startup and when mappings change, and the error is only logged."
+    )]
+    fn build(sdl_mappings: String) -> Result<Self, gilrs::Error> {
+        let gilrs = GilrsBuilder::new()
+            .add_mappings(&sdl_mappings)
+            .with_default_filters(false)
+            .set_update_state(false)
+            .build()?;
+        #[cfg(target_arch = "wasm32")]
+        GILRS.with(|g| {
+            g.replace(Some(gilrs));
+        });
+        Ok(Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            cell: SyncCell::new(gilrs),
+            sdl_mappings,
+        })
+    }
+
     #[inline]
     pub fn with(&mut self, f: impl FnOnce(&mut gilrs::Gilrs)) {
         #[cfg(target_arch = "wasm32")]
@@ -80,6 +104,10 @@ impl GilrsGamepads {
 }
 
 /// Plugin that provides gamepad handling to an [`App`].
+///
+/// SDL mappings in the [`GamepadMappings`] resource are added to Gilrs' mapping database.
+/// Gilrs only reads mappings when it starts, so it is restarted whenever they change, and the
+/// connected gamepads are reported as connected again.
 #[derive(Default)]
 pub struct GilrsPlugin;
 
@@ -89,25 +117,22 @@ pub struct RumbleSystems;
 
 impl Plugin for GilrsPlugin {
     fn build(&self, app: &mut App) {
-        match GilrsBuilder::new()
-            .with_default_filters(false)
-            .set_update_state(false)
-            .build()
-        {
+        match Gilrs::build(String::new()) {
             Ok(gilrs) => {
-                let g = Gilrs {
-                    #[cfg(not(target_arch = "wasm32"))]
-                    cell: SyncCell::new(gilrs),
-                };
-                #[cfg(target_arch = "wasm32")]
-                GILRS.with(|g| {
-                    g.replace(Some(gilrs));
-                });
-                app.insert_resource(g);
+                app.insert_resource(gilrs);
                 app.init_resource::<GilrsGamepads>();
                 app.init_resource::<RunningRumbleEffects>()
                     .add_systems(PreStartup, gilrs_event_startup_system)
-                    .add_systems(PreUpdate, gilrs_event_system.before(InputSystems))
+                    .add_systems(
+                        PreUpdate,
+                        (
+                            update_gilrs_mappings
+                                .run_if(resource_exists_and_changed::<GamepadMappings>),
+                            gilrs_event_system,
+                        )
+                            .chain()
+                            .in_set(InputBackendSystems),
+                    )
                     .add_systems(PostUpdate, play_gilrs_rumble.in_set(RumbleSystems));
             }
             Err(err) => error!("Failed to start Gilrs. {}", err),
@@ -118,6 +143,8 @@ impl Plugin for GilrsPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy_input::InputPlugin;
+    use bevy_time::{Real, Time};
 
     // Regression test for https://github.com/bevyengine/bevy/issues/17697
     #[test]
@@ -132,4 +159,22 @@ mod tests {
 
         handler.join().unwrap();
     }
+
+    #[test]
+    fn mappings_inserted_after_startup_restart_gilrs() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, GilrsPlugin))
+            .init_resource::<Time<Real>>();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<GamepadMappings>()
+            .add_mappings("03000000341200007856000000000000,Test Pad,a:b1,b:b0,");
+        app.update();
+        assert!(app
+            .world()
+            .resource::<Gilrs>()
+            .sdl_mappings
+            .contains("Test Pad"));
+    }
 }
@@ -14,7 +14,9 @@ use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
-use bevy_asset::{uuid_handle, Asset, AssetApp, Assets, Handle, RenderAssetUsages};
+use bevy_asset::{
+    memory::AssetMemorySize, uuid_handle, Asset, AssetApp, Assets, Handle, RenderAssetUsages,
+};
 use bevy_color::{Color, ColorToComponents, Gray, LinearRgba, Srgba, Xyza};
 use bevy_ecs::resource::Resource;
 use bevy_math::{AspectRatio, UVec2, UVec3, Vec2};
@@ -212,7 +214,8 @@ impl Plugin for ImagePlugin {
         #[cfg(feature = "hdr")]
         app.init_asset_loader::<crate::HdrTextureLoader>();
 
-        app.init_asset::<Image>();
+        app.init_asset::<Image>()
+            .init_asset_memory_tracking::<Image>();
         #[cfg(feature = "bevy_reflect")]
         app.register_asset_reflect::<Image>();
 
@@ -1093,6 +1096,15 @@ impl Default for Image {
     }
 }
 
+impl AssetMemorySize for Image {
+    /// Estimates the main world memory used by this image, which is dominated by its pixel data.
+    ///
+    /// Pixel data that has been extracted to the `RenderWorld` is not counted.
+    fn memory_size(&self) -> usize {
+        size_of::<Self>() + self.data.as_ref().map_or(0, Vec::len)
+    }
+}
+
 impl Image {
     /// Creates a new image from raw binary data and the corresponding metadata.
     ///
@@ -0,0 +1,53 @@
+use bevy_asset::processor::{AssetValidator, ValidationContext};
+use bevy_reflect::TypePath;
+
+use crate::Image;
+
+/// An [`AssetValidator`] that enforces size and format rules for [`Image`]s during asset processing.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_image::ImageValidator;
+/// # let mut app = App::new();
+/// app.register_asset_validator(ImageValidator {
+///     max_dimension: Some(4096),
+///     require_power_of_two: true,
+///     require_compressed: false,
+/// });
+/// ```
+#[derive(Clone, Debug, Default, TypePath)]
+pub struct ImageValidator {
+    /// The largest allowed width or height, in pixels.
+    pub max_dimension: Option<u32>,
+    /// Whether the width and height must be powers of two.
+    pub require_power_of_two: bool,
+    /// Whether the image must use a [block-compressed](wgpu_types::TextureFormat::is_compressed) format.
+    pub require_compressed: bool,
+}
+
+impl AssetValidator for ImageValidator {
+    type Asset = Image;
+
+    fn validate(&self, image: &Image, context: &mut ValidationContext) {
+        let (width, height) = (image.width(), image.height());
+        if let Some(max_dimension) = self.max_dimension
+            && (width > max_dimension || height > max_dimension)
+        {
+            context.error(format!(
+                "image is {width}x{height}, which exceeds the maximum dimension of {max_dimension}"
+            ));
+        }
+        if self.require_power_of_two && !(width.is_power_of_two() && height.is_power_of_two()) {
+            context.error(format!(
+                "image is {width}x{height}, but its dimensions must be powers of two"
+            ));
+        }
+        let format = image.texture_descriptor.format;
+        if self.require_compressed && !format.is_compressed() {
+            context.error(format!(
+                "image uses the uncompressed format {format:?}, but a compressed format is required"
+            ));
+        }
+    }
+}
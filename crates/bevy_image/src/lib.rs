@@ -34,6 +34,7 @@ mod exr_texture_loader;
 #[cfg(feature = "hdr")]
 mod hdr_texture_loader;
 mod image_loader;
+mod image_validator;
 #[cfg(feature = "ktx2")]
 mod ktx2;
 mod saver;
@@ -50,6 +51,7 @@ pub use exr_texture_loader::*;
 #[cfg(feature = "hdr")]
 pub use hdr_texture_loader::*;
 pub use image_loader::*;
+pub use image_validator::*;
 #[cfg(feature = "ktx2")]
 pub use ktx2::*;
 pub use saver::*;
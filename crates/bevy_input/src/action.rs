@@ -0,0 +1,852 @@
+//! Maps raw inputs to user-defined actions.
+//!
+//! Games rarely care about which key or button was pressed, they care about what the player wants to do:
+//! jump, move or open a menu. An action is a value of a user-defined type, usually a fieldless enum, that
+//! implements [`InputAction`]. An [`InputMap`] binds actions to [`InputBinding`]s, like keys, mouse buttons,
+//! gamepad buttons, gamepad axes and chords, and to [`AxisBinding`]s, which combine inputs into a [`Vec2`].
+//! Every update, [`InputActionPlugin`] evaluates each [`InputMap`] into the [`ActionState`] of the same entity.
+//!
+//! Each player is an entity with its own [`InputMap`], optionally restricted to a single gamepad with an
+//! [`ActionGamepad`]. Different contexts, like menus and gameplay, use different action types, and can be turned
+//! off with [`ActionState::set_enabled`].
+//!
+//! Bindings can be changed at runtime by mutating the [`InputMap`], and [`InputBinding::just_pressed`] finds
+//! the input the player pressed to rebind an action. With the `serialize` and `bevy_reflect` features, an
+//! [`InputMap`] can be saved, for example as a field of a settings resource.
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::{action::*, prelude::*, InputPlugin};
+//! #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+//! enum PlayerAction {
+//!     Jump,
+//!     Move,
+//! }
+//!
+//! fn spawn_player(mut commands: Commands) {
+//!     commands.spawn(
+//!         InputMap::default()
+//!             .with(PlayerAction::Jump, KeyCode::Space)
+//!             .with(PlayerAction::Jump, GamepadButton::South)
+//!             .with_axis(PlayerAction::Move, AxisBinding::WASD)
+//!             .with_axis(PlayerAction::Move, AxisBinding::LEFT_STICK),
+//!     );
+//! }
+//!
+//! fn move_player(players: Query<&ActionState<PlayerAction>>) {
+//!     for actions in &players {
+//!         if actions.just_pressed(PlayerAction::Jump) {
+//!             // Jump!
+//!         }
+//!         let direction = actions.axis_pair(PlayerAction::Move);
+//!         # let _ = direction;
+//!     }
+//! }
+//!
+//! App::new()
+//!     .add_plugins((InputPlugin, InputActionPlugin::<PlayerAction>::default()))
+//!     .add_systems(Startup, spawn_player)
+//!     .add_systems(Update, move_player);
+//! ```
+
+use crate::{
+    gamepad::{Gamepad, GamepadAxis, GamepadButton},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    ButtonInput, InputSystems,
+};
+use alloc::vec::Vec;
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_platform::collections::HashMap;
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    bevy_ecs::reflect::ReflectComponent,
+    bevy_reflect::{std_traits::ReflectDefault, Reflect},
+};
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A user-defined action, usually a fieldless enum.
+///
+/// This is implemented for every type with the required bounds.
+pub trait InputAction: Copy + Eq + Hash + Debug + Send + Sync + 'static {}
+
+impl<A: Copy + Eq + Hash + Debug + Send + Sync + 'static> InputAction for A {}
+
+/// Updates the [`ActionState<A>`] of every entity with an [`InputMap<A>`].
+///
+/// Add this plugin once for every action type.
+pub struct InputActionPlugin<A: InputAction>(PhantomData<A>);
+
+impl<A: InputAction> Default for InputActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: InputAction> Plugin for InputActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(PreUpdate, InputActionSystems.after(InputSystems))
+            .add_systems(
+                PreUpdate,
+                update_action_states::<A>.in_set(InputActionSystems),
+            );
+    }
+}
+
+/// Label for the systems that update [`ActionState`]s from their [`InputMap`]s.
+///
+/// This runs after [`InputSystems`] in [`PreUpdate`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct InputActionSystems;
+
+/// The direction of a [`GamepadAxis`] that is used as a button.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum AxisDirection {
+    /// The axis is pushed towards positive values.
+    Positive,
+    /// The axis is pushed towards negative values.
+    Negative,
+}
+
+/// An input that can be bound to an action in an [`InputMap`].
+///
+/// Gamepad inputs are read from the gamepad of the [`ActionGamepad`] of the entity, or from every gamepad if
+/// there is none.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputBinding {
+    /// A key of the keyboard.
+    Key(KeyCode),
+    /// A button of the mouse.
+    Mouse(MouseButton),
+    /// A button of a gamepad.
+    GamepadButton(GamepadButton),
+    /// A gamepad axis pushed in one direction, pressed past [`InputMap::axis_press_threshold`].
+    GamepadAxis(GamepadAxis, AxisDirection),
+    /// Several inputs that must all be pressed at the same time, like `Ctrl + S`.
+    Chord(Vec<InputBinding>),
+}
+
+impl InputBinding {
+    /// Returns the first input that was just pressed, ignoring chords.
+    ///
+    /// This is useful to rebind an action to the next input the player presses.
+    pub fn just_pressed<'a>(
+        keys: Option<&ButtonInput<KeyCode>>,
+        mouse_buttons: Option<&ButtonInput<MouseButton>>,
+        gamepads: impl IntoIterator<Item = &'a Gamepad>,
+    ) -> Option<InputBinding> {
+        if let Some(&key) = keys.and_then(|keys| keys.get_just_pressed().next()) {
+            return Some(InputBinding::Key(key));
+        }
+        if let Some(&button) = mouse_buttons.and_then(|buttons| buttons.get_just_pressed().next()) {
+            return Some(InputBinding::Mouse(button));
+        }
+        gamepads.into_iter().find_map(|gamepad| {
+            gamepad
+                .get_just_pressed()
+                .next()
+                .map(|&button| InputBinding::GamepadButton(button))
+        })
+    }
+
+    /// Returns whether this input is pressed, and its value between `0.0` and `1.0`.
+    fn evaluate(&self, sources: &InputSources, threshold: f32) -> (bool, f32) {
+        match self {
+            InputBinding::Key(key) => pressed_value(sources.keys.is_some_and(|k| k.pressed(*key))),
+            InputBinding::Mouse(button) => {
+                pressed_value(sources.mouse_buttons.is_some_and(|b| b.pressed(*button)))
+            }
+            InputBinding::GamepadButton(button) => {
+                sources
+                    .gamepads()
+                    .fold((false, 0.0), |(pressed, value), gamepad| {
+                        let button_pressed = gamepad.pressed(*button);
+                        let button_value =
+                            gamepad
+                                .get(*button)
+                                .unwrap_or(if button_pressed { 1.0 } else { 0.0 });
+                        (pressed || button_pressed, value.max(button_value))
+                    })
+            }
+            InputBinding::GamepadAxis(axis, direction) => {
+                let value = sources
+                    .gamepads()
+                    .map(|gamepad| {
+                        let value = gamepad.get(*axis).unwrap_or(0.0);
+                        match direction {
+                            AxisDirection::Positive => value.max(0.0),
+                            AxisDirection::Negative => (-value).max(0.0),
+                        }
+                    })
+                    .fold(0.0, f32::max);
+                (value > 0.0 && value >= threshold, value)
+            }
+            InputBinding::Chord(inputs) => {
+                if inputs.is_empty() {
+                    return (false, 0.0);
+                }
+                inputs.iter().fold((true, 1.0), |(pressed, value), input| {
+                    let (input_pressed, input_value) = input.evaluate(sources, threshold);
+                    (pressed && input_pressed, value.min(input_value))
+                })
+            }
+        }
+    }
+}
+
+fn pressed_value(pressed: bool) -> (bool, f32) {
+    (pressed, if pressed { 1.0 } else { 0.0 })
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        InputBinding::Key(key)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        InputBinding::Mouse(button)
+    }
+}
+
+impl From<GamepadButton> for InputBinding {
+    fn from(button: GamepadButton) -> Self {
+        InputBinding::GamepadButton(button)
+    }
+}
+
+/// Inputs combined into a two-dimensional value, bound to an action with [`InputMap::with_axis`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum AxisBinding {
+    /// Four inputs for each direction, like `WASD` or the arrow keys.
+    VirtualDPad {
+        /// The input for positive `y`.
+        up: InputBinding,
+        /// The input for negative `y`.
+        down: InputBinding,
+        /// The input for negative `x`.
+        left: InputBinding,
+        /// The input for positive `x`.
+        right: InputBinding,
+    },
+    /// Two gamepad axes, like a stick.
+    GamepadStick {
+        /// The axis for `x`.
+        x: GamepadAxis,
+        /// The axis for `y`.
+        y: GamepadAxis,
+    },
+}
+
+impl AxisBinding {
+    /// The `W`, `A`, `S` and `D` keys.
+    pub const WASD: Self =
+        Self::virtual_dpad(KeyCode::KeyW, KeyCode::KeyS, KeyCode::KeyA, KeyCode::KeyD);
+
+    /// The arrow keys.
+    pub const ARROW_KEYS: Self = Self::virtual_dpad(
+        KeyCode::ArrowUp,
+        KeyCode::ArrowDown,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowRight,
+    );
+
+    /// The left stick of a gamepad.
+    pub const LEFT_STICK: Self = AxisBinding::GamepadStick {
+        x: GamepadAxis::LeftStickX,
+        y: GamepadAxis::LeftStickY,
+    };
+
+    /// The right stick of a gamepad.
+    pub const RIGHT_STICK: Self = AxisBinding::GamepadStick {
+        x: GamepadAxis::RightStickX,
+        y: GamepadAxis::RightStickY,
+    };
+
+    /// The directional pad of a gamepad.
+    pub const DPAD: Self = AxisBinding::VirtualDPad {
+        up: InputBinding::GamepadButton(GamepadButton::DPadUp),
+        down: InputBinding::GamepadButton(GamepadButton::DPadDown),
+        left: InputBinding::GamepadButton(GamepadButton::DPadLeft),
+        right: InputBinding::GamepadButton(GamepadButton::DPadRight),
+    };
+
+    const fn virtual_dpad(up: KeyCode, down: KeyCode, left: KeyCode, right: KeyCode) -> Self {
+        AxisBinding::VirtualDPad {
+            up: InputBinding::Key(up),
+            down: InputBinding::Key(down),
+            left: InputBinding::Key(left),
+            right: InputBinding::Key(right),
+        }
+    }
+
+    fn evaluate(&self, sources: &InputSources, threshold: f32) -> Vec2 {
+        match self {
+            AxisBinding::VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            } => {
+                let value = |input: &InputBinding| input.evaluate(sources, threshold).1;
+                Vec2::new(value(right) - value(left), value(up) - value(down))
+            }
+            AxisBinding::GamepadStick { x, y } => sources
+                .gamepads()
+                .map(|gamepad| {
+                    Vec2::new(
+                        gamepad.get(*x).unwrap_or(0.0),
+                        gamepad.get(*y).unwrap_or(0.0),
+                    )
+                })
+                .fold(Vec2::ZERO, |a, b| {
+                    if b.length_squared() > a.length_squared() {
+                        b
+                    } else {
+                        a
+                    }
+                }),
+        }
+    }
+}
+
+/// The bindings of the actions of type `A`, evaluated into the [`ActionState<A>`] of the same entity.
+///
+/// An action can have any number of bindings. It is pressed if any of its [`InputBinding`]s is pressed, or if
+/// any of its [`AxisBinding`]s is outside of the [dead zone](Self::dead_zone).
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(ActionState<A>)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputMap<A: InputAction> {
+    bindings: Vec<(A, InputBinding)>,
+    axis_bindings: Vec<(A, AxisBinding)>,
+    /// The length below which the value of an [`AxisBinding::VirtualDPad`] is zero, between `0.0` and `1.0`.
+    ///
+    /// Values above the dead zone are rescaled to start at zero, so that small movements stay smooth.
+    /// [`AxisBinding::GamepadStick`]s already go through the dead zone of the
+    /// [`AxisSettings`](crate::gamepad::AxisSettings) of their gamepad, so this dead zone does not apply to them.
+    pub dead_zone: f32,
+    /// The value at which an [`InputBinding::GamepadAxis`] is pressed.
+    pub axis_press_threshold: f32,
+}
+
+impl<A: InputAction> Default for InputMap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            axis_bindings: Vec::new(),
+            dead_zone: 0.1,
+            axis_press_threshold: 0.5,
+        }
+    }
+}
+
+impl<A: InputAction> InputMap<A> {
+    /// Returns this map with `input` bound to `action`.
+    pub fn with(mut self, action: A, input: impl Into<InputBinding>) -> Self {
+        self.bind(action, input);
+        self
+    }
+
+    /// Returns this map with `axis` bound to `action`.
+    pub fn with_axis(mut self, action: A, axis: AxisBinding) -> Self {
+        self.bind_axis(action, axis);
+        self
+    }
+
+    /// Binds `input` to `action`, in addition to its other bindings.
+    pub fn bind(&mut self, action: A, input: impl Into<InputBinding>) -> &mut Self {
+        let input = input.into();
+        if !self
+            .bindings
+            .iter()
+            .any(|(a, i)| *a == action && *i == input)
+        {
+            self.bindings.push((action, input));
+        }
+        self
+    }
+
+    /// Binds `axis` to `action`, in addition to its other bindings.
+    pub fn bind_axis(&mut self, action: A, axis: AxisBinding) -> &mut Self {
+        if !self
+            .axis_bindings
+            .iter()
+            .any(|(a, i)| *a == action && *i == axis)
+        {
+            self.axis_bindings.push((action, axis));
+        }
+        self
+    }
+
+    /// Replaces every [`InputBinding`] of `action` with `input`.
+    ///
+    /// This is usually what a rebinding menu wants. [`AxisBinding`]s are kept.
+    pub fn rebind(&mut self, action: A, input: impl Into<InputBinding>) -> &mut Self {
+        self.bindings.retain(|(a, _)| *a != action);
+        self.bind(action, input)
+    }
+
+    /// Removes `input` from the bindings of `action`. Returns `true` if it was bound.
+    pub fn unbind(&mut self, action: A, input: &InputBinding) -> bool {
+        let len = self.bindings.len();
+        self.bindings.retain(|(a, i)| *a != action || i != input);
+        self.bindings.len() != len
+    }
+
+    /// Removes `axis` from the bindings of `action`. Returns `true` if it was bound.
+    pub fn unbind_axis(&mut self, action: A, axis: &AxisBinding) -> bool {
+        let len = self.axis_bindings.len();
+        self.axis_bindings
+            .retain(|(a, i)| *a != action || i != axis);
+        self.axis_bindings.len() != len
+    }
+
+    /// Removes every binding of `action`.
+    pub fn clear_action(&mut self, action: A) {
+        self.bindings.retain(|(a, _)| *a != action);
+        self.axis_bindings.retain(|(a, _)| *a != action);
+    }
+
+    /// Returns the [`InputBinding`]s of `action`.
+    pub fn bindings(&self, action: A) -> impl Iterator<Item = &InputBinding> {
+        self.bindings
+            .iter()
+            .filter(move |(a, _)| *a == action)
+            .map(|(_, input)| input)
+    }
+
+    /// Returns the [`AxisBinding`]s of `action`.
+    pub fn axis_bindings(&self, action: A) -> impl Iterator<Item = &AxisBinding> {
+        self.axis_bindings
+            .iter()
+            .filter(move |(a, _)| *a == action)
+            .map(|(_, axis)| axis)
+    }
+
+    /// Returns every bound action, once, in the order they were first bound.
+    pub fn actions(&self) -> impl Iterator<Item = A> + '_ {
+        let all = move || {
+            self.bindings
+                .iter()
+                .map(|(a, _)| *a)
+                .chain(self.axis_bindings.iter().map(|(a, _)| *a))
+        };
+        all()
+            .enumerate()
+            .filter(move |&(index, action)| !all().take(index).any(|a| a == action))
+            .map(|(_, action)| action)
+    }
+
+    /// Returns `true` if `action` has any binding.
+    fn is_bound(&self, action: A) -> bool {
+        self.bindings.iter().any(|(a, _)| *a == action)
+            || self.axis_bindings.iter().any(|(a, _)| *a == action)
+    }
+
+    /// Returns the combined value of the [`AxisBinding`]s of `action`, with a length of at most `1.0`.
+    fn evaluate_axis(&self, action: A, sources: &InputSources) -> Vec2 {
+        self.axis_bindings(action)
+            .map(|axis| {
+                let value = axis.evaluate(sources, self.axis_press_threshold);
+                match axis {
+                    AxisBinding::VirtualDPad { .. } => self.apply_dead_zone(value),
+                    AxisBinding::GamepadStick { .. } => value,
+                }
+            })
+            .sum::<Vec2>()
+            .clamp_length_max(1.0)
+    }
+
+    /// Applies the [dead zone](Self::dead_zone) to `axis`, and limits its length to `1.0`.
+    fn apply_dead_zone(&self, axis: Vec2) -> Vec2 {
+        let length = axis.length();
+        let dead_zone = self.dead_zone.clamp(0.0, 0.99);
+        if length <= dead_zone {
+            return Vec2::ZERO;
+        }
+        let scaled = ((length - dead_zone) / (1.0 - dead_zone)).min(1.0);
+        axis * (scaled / length)
+    }
+}
+
+/// Restricts the gamepad inputs of the [`InputMap`] of this entity to a single gamepad.
+///
+/// Use this to give each local player their own gamepad. Without it, every gamepad drives the [`InputMap`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, PartialEq, Clone)
+)]
+pub struct ActionGamepad(pub Entity);
+
+/// The state of an action in an [`ActionState`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct ActionData {
+    /// Whether the action is pressed.
+    pub pressed: bool,
+    /// Whether the action started being pressed this update.
+    pub just_pressed: bool,
+    /// Whether the action stopped being pressed this update.
+    pub just_released: bool,
+    /// How strongly the action is pressed, between `0.0` and `1.0`.
+    ///
+    /// This is the largest value of its [`InputBinding`]s, and the length of its axis.
+    pub value: f32,
+    /// The combined value of the [`AxisBinding`]s of the action, with a length of at most `1.0`.
+    pub axis: Vec2,
+}
+
+/// The current state of the actions of type `A`, updated from the [`InputMap<A>`] of the same entity.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, Clone)
+)]
+pub struct ActionState<A: InputAction> {
+    actions: HashMap<A, ActionData>,
+    enabled: bool,
+}
+
+impl<A: InputAction> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            actions: HashMap::default(),
+            enabled: true,
+        }
+    }
+}
+
+impl<A: InputAction> ActionState<A> {
+    /// Returns the state of `action`, if it is bound.
+    pub fn get(&self, action: A) -> Option<&ActionData> {
+        self.actions.get(&action)
+    }
+
+    /// Returns `true` if `action` is pressed.
+    pub fn pressed(&self, action: A) -> bool {
+        self.get(action).is_some_and(|data| data.pressed)
+    }
+
+    /// Returns `true` if `action` started being pressed this update.
+    pub fn just_pressed(&self, action: A) -> bool {
+        self.get(action).is_some_and(|data| data.just_pressed)
+    }
+
+    /// Returns `true` if `action` stopped being pressed this update.
+    pub fn just_released(&self, action: A) -> bool {
+        self.get(action).is_some_and(|data| data.just_released)
+    }
+
+    /// Returns how strongly `action` is pressed, between `0.0` and `1.0`.
+    pub fn value(&self, action: A) -> f32 {
+        self.get(action).map_or(0.0, |data| data.value)
+    }
+
+    /// Returns the combined value of the [`AxisBinding`]s of `action`.
+    pub fn axis_pair(&self, action: A) -> Vec2 {
+        self.get(action).map_or(Vec2::ZERO, |data| data.axis)
+    }
+
+    /// Returns `false` if the actions are ignored, see [`set_enabled`](Self::set_enabled).
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or disables every action of this state.
+    ///
+    /// While disabled, every action is released, for example to ignore gameplay actions while a menu is open.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn update(&mut self, action: A, pressed: bool, value: f32, axis: Vec2) {
+        self.actions
+            .entry(action)
+            .or_default()
+            .update(pressed, value, axis);
+    }
+}
+
+impl ActionData {
+    fn update(&mut self, pressed: bool, value: f32, axis: Vec2) {
+        *self = ActionData {
+            pressed,
+            just_pressed: pressed && !self.pressed,
+            just_released: !pressed && self.pressed,
+            value,
+            axis,
+        };
+    }
+}
+
+/// The raw inputs an [`InputMap`] is evaluated against.
+struct InputSources<'a> {
+    keys: Option<&'a ButtonInput<KeyCode>>,
+    mouse_buttons: Option<&'a ButtonInput<MouseButton>>,
+    gamepads: &'a [(Entity, &'a Gamepad)],
+    /// The gamepad of the [`ActionGamepad`], if there is one.
+    gamepad: Option<Entity>,
+}
+
+impl InputSources<'_> {
+    /// Iterates the gamepads whose inputs are read.
+    fn gamepads(&self) -> impl Iterator<Item = &Gamepad> {
+        self.gamepads
+            .iter()
+            .filter(|(entity, _)| self.gamepad.is_none_or(|gamepad| gamepad == *entity))
+            .map(|(_, gamepad)| *gamepad)
+    }
+}
+
+/// Updates every [`ActionState<A>`] from its [`InputMap<A>`].
+pub fn update_action_states<A: InputAction>(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut maps: Query<(&InputMap<A>, &mut ActionState<A>, Option<&ActionGamepad>)>,
+) {
+    let gamepads: Vec<(Entity, &Gamepad)> = gamepads.iter().collect();
+    for (map, mut state, action_gamepad) in &mut maps {
+        let sources = InputSources {
+            keys: keys.as_deref(),
+            mouse_buttons: mouse_buttons.as_deref(),
+            gamepads: &gamepads,
+            gamepad: action_gamepad.map(|gamepad| gamepad.0),
+        };
+        let state = state.as_mut();
+
+        // Actions that were unbound since the last update are released.
+        for (action, data) in &mut state.actions {
+            if !map.is_bound(*action) {
+                data.update(false, 0.0, Vec2::ZERO);
+            }
+        }
+
+        for action in map.actions() {
+            let (mut pressed, mut value) = map
+                .bindings(action)
+                .map(|input| input.evaluate(&sources, map.axis_press_threshold))
+                .fold((false, 0.0_f32), |(p, v), (input_p, input_v)| {
+                    (p || input_p, v.max(input_v))
+                });
+            let axis = map.evaluate_axis(action, &sources);
+            if axis != Vec2::ZERO {
+                pressed = true;
+                value = value.max(axis.length());
+            }
+
+            if state.enabled {
+                state.update(action, pressed, value.clamp(0.0, 1.0), axis);
+            } else {
+                state.update(action, false, 0.0, Vec2::ZERO);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gamepad::{
+            GamepadConnection, GamepadConnectionEvent, RawGamepadAxisChangedEvent, RawGamepadEvent,
+        },
+        keyboard::{Key, KeyboardInput},
+        ButtonState, InputPlugin,
+    };
+    use alloc::{string::ToString, vec};
+    use bevy_app::App;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Action {
+        Jump,
+        Save,
+        Move,
+    }
+
+    fn setup() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, InputActionPlugin::<Action>::default()));
+        let player = app
+            .world_mut()
+            .spawn(
+                InputMap::default()
+                    .with(Action::Jump, KeyCode::Space)
+                    .with(Action::Jump, GamepadButton::South)
+                    .with(
+                        Action::Save,
+                        InputBinding::Chord(vec![
+                            KeyCode::ControlLeft.into(),
+                            KeyCode::KeyS.into(),
+                        ]),
+                    )
+                    .with_axis(Action::Move, AxisBinding::WASD)
+                    .with_axis(Action::Move, AxisBinding::LEFT_STICK),
+            )
+            .id();
+        (app, player)
+    }
+
+    fn key(app: &mut App, key_code: KeyCode, state: ButtonState) {
+        app.world_mut().write_message(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(crate::keyboard::NativeKey::Unidentified),
+            state,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    fn state(app: &App, player: Entity) -> &ActionState<Action> {
+        app.world().get::<ActionState<Action>>(player).unwrap()
+    }
+
+    #[test]
+    fn buttons_and_chords() {
+        let (mut app, player) = setup();
+
+        key(&mut app, KeyCode::Space, ButtonState::Pressed);
+        key(&mut app, KeyCode::KeyS, ButtonState::Pressed);
+        app.update();
+        assert!(state(&app, player).just_pressed(Action::Jump));
+        assert_eq!(state(&app, player).value(Action::Jump), 1.0);
+        assert!(!state(&app, player).pressed(Action::Save));
+
+        key(&mut app, KeyCode::ControlLeft, ButtonState::Pressed);
+        app.update();
+        assert!(state(&app, player).pressed(Action::Jump));
+        assert!(!state(&app, player).just_pressed(Action::Jump));
+        assert!(state(&app, player).just_pressed(Action::Save));
+
+        key(&mut app, KeyCode::Space, ButtonState::Released);
+        app.update();
+        assert!(state(&app, player).just_released(Action::Jump));
+
+        // Rebinding takes effect on the next update.
+        app.world_mut()
+            .get_mut::<InputMap<Action>>(player)
+            .unwrap()
+            .rebind(Action::Jump, KeyCode::KeyJ);
+        key(&mut app, KeyCode::Space, ButtonState::Pressed);
+        app.update();
+        assert!(!state(&app, player).pressed(Action::Jump));
+        key(&mut app, KeyCode::KeyJ, ButtonState::Pressed);
+        app.update();
+        assert!(state(&app, player).just_pressed(Action::Jump));
+
+        app.world_mut()
+            .get_mut::<ActionState<Action>>(player)
+            .unwrap()
+            .set_enabled(false);
+        app.update();
+        assert!(state(&app, player).just_released(Action::Jump));
+    }
+
+    #[test]
+    fn axes_and_gamepads() {
+        let (mut app, player) = setup();
+
+        key(&mut app, KeyCode::KeyD, ButtonState::Pressed);
+        key(&mut app, KeyCode::KeyW, ButtonState::Pressed);
+        app.update();
+        let axis = state(&app, player).axis_pair(Action::Move);
+        assert!((axis.length() - 1.0).abs() < 1e-5);
+        assert!(axis.x > 0.0 && axis.x == axis.y);
+        assert!(state(&app, player).just_pressed(Action::Move));
+
+        key(&mut app, KeyCode::KeyD, ButtonState::Released);
+        key(&mut app, KeyCode::KeyW, ButtonState::Released);
+        let gamepad = app.world_mut().spawn_empty().id();
+        app.world_mut().write_message(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected {
+                name: "Test gamepad".to_string(),
+                vendor_id: None,
+                product_id: None,
+            },
+        ));
+        app.update();
+        assert!(state(&app, player).just_released(Action::Move));
+
+        // Within the dead zone of the gamepad settings.
+        app.world_mut()
+            .write_message(RawGamepadEvent::Axis(RawGamepadAxisChangedEvent::new(
+                gamepad,
+                GamepadAxis::LeftStickX,
+                0.04,
+            )));
+        app.update();
+        assert_eq!(state(&app, player).axis_pair(Action::Move), Vec2::ZERO);
+
+        // Only the dead zone of the gamepad settings is applied to sticks, not the one of the `InputMap`.
+        app.world_mut()
+            .write_message(RawGamepadEvent::Axis(RawGamepadAxisChangedEvent::new(
+                gamepad,
+                GamepadAxis::LeftStickX,
+                -0.525,
+            )));
+        app.update();
+        let axis = state(&app, player).axis_pair(Action::Move);
+        assert_eq!(axis, Vec2::new(-0.525, 0.0));
+
+        // Players restricted to another gamepad ignore this one.
+        let other = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .entity_mut(player)
+            .insert(ActionGamepad(other));
+        app.update();
+        assert_eq!(state(&app, player).axis_pair(Action::Move), Vec2::ZERO);
+    }
+}
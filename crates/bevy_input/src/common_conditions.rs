@@ -1,6 +1,10 @@
+#[cfg(feature = "input_buffer")]
+use crate::input_buffer::{InputBuffer, InputSequence};
 use crate::ButtonInput;
 use bevy_ecs::system::Res;
 use core::hash::Hash;
+#[cfg(feature = "input_buffer")]
+use core::time::Duration;
 
 /// Stateful run condition that can be toggled via an input press using [`ButtonInput::just_pressed`].
 ///
@@ -100,6 +104,49 @@ where
     move |inputs: Res<ButtonInput<T>>| inputs.just_released(input.clone())
 }
 
+/// Run condition that is active if the given input was pressed within the last `within`,
+/// according to [`InputBuffer::pressed_within`].
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, Update};
+/// # use bevy_ecs::prelude::IntoScheduleConfigs;
+/// # use bevy_input::{common_conditions::input_pressed_within, prelude::KeyCode};
+/// # use bevy_input::input_buffer::InputBufferPlugin;
+/// # use core::time::Duration;
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, InputBufferPlugin::<KeyCode>::default()))
+///         .add_systems(
+///             Update,
+///             dodge.run_if(input_pressed_within(KeyCode::ShiftLeft, Duration::from_millis(120))),
+///         )
+///         .run();
+/// }
+///
+/// # fn dodge() {}
+/// ```
+#[cfg(feature = "input_buffer")]
+pub fn input_pressed_within<T>(
+    input: T,
+    within: Duration,
+) -> impl FnMut(Res<InputBuffer<T>>) -> bool + Clone
+where
+    T: Clone + Eq + Send + Sync + 'static,
+{
+    move |buffer: Res<InputBuffer<T>>| buffer.pressed_within(input.clone(), within)
+}
+
+/// Run condition that is active on the frame the given [`InputSequence`] is completed.
+#[cfg(feature = "input_buffer")]
+pub fn input_sequence_completed<T>(
+    sequence: InputSequence<T>,
+) -> impl FnMut(Res<InputBuffer<T>>) -> bool + Clone
+where
+    T: Clone + Eq + Send + Sync + 'static,
+{
+    move |buffer: Res<InputBuffer<T>>| buffer.sequence_completed_within(&sequence, Duration::ZERO)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,7 +2,12 @@
 
 use core::{ops::RangeInclusive, time::Duration};
 
-use crate::{Axis, ButtonInput, ButtonState};
+use crate::{
+    gamepad_mapping::{
+        GamepadCalibration, GamepadCalibrations, GamepadDeviceId, GamepadLayout, GamepadMappings,
+    },
+    Axis, ButtonInput, ButtonState,
+};
 use alloc::string::String;
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::prelude::ReflectComponent;
@@ -12,7 +17,7 @@ use bevy_ecs::{
     entity::Entity,
     message::{Message, MessageReader, MessageWriter},
     name::Name,
-    system::{Commands, Query},
+    system::{Commands, Query, Res},
 };
 use bevy_math::ops;
 use bevy_math::Vec2;
@@ -399,6 +404,24 @@ impl Gamepad {
         self.product_id
     }
 
+    /// Returns the [`GamepadDeviceId`] used to look up this gamepad's mapping.
+    pub fn device_id(&self) -> GamepadDeviceId {
+        GamepadDeviceId {
+            vendor_id: self.vendor_id,
+            product_id: self.product_id,
+        }
+    }
+
+    /// Returns the face button layout guessed from the [vendor].
+    ///
+    /// Use [`GamepadMappings::layout`] to also take the layout of a loaded mapping into account.
+    ///
+    /// [vendor]: Self::vendor_id
+    pub fn layout(&self) -> GamepadLayout {
+        self.vendor_id
+            .map_or(GamepadLayout::Unknown, GamepadLayout::from_vendor_id)
+    }
+
     /// Returns the analog data of the provided [`GamepadAxis`] or [`GamepadButton`].
     ///
     /// This will be clamped between [[`Axis::MIN`],[`Axis::MAX`]].
@@ -1510,6 +1533,7 @@ impl ButtonAxisSettings {
 pub fn gamepad_connection_system(
     mut commands: Commands,
     mut connection_events: MessageReader<GamepadConnectionEvent>,
+    calibrations: Option<Res<GamepadCalibrations>>,
 ) {
     for connection_event in connection_events.read() {
         let id = connection_event.gamepad;
@@ -1523,14 +1547,18 @@ pub fn gamepad_connection_system(
                     warn!("Gamepad {id} removed before handling connection event.");
                     continue;
                 };
-                gamepad.insert((
-                    Name::new(name.clone()),
-                    Gamepad {
-                        vendor_id: *vendor_id,
-                        product_id: *product_id,
-                        ..Default::default()
-                    },
-                ));
+                let gamepad_component = Gamepad {
+                    vendor_id: *vendor_id,
+                    product_id: *product_id,
+                    ..Default::default()
+                };
+                if let Some(calibration) = calibrations
+                    .as_ref()
+                    .and_then(|calibrations| calibrations.get(gamepad_component.device_id()))
+                {
+                    gamepad.insert(calibration.clone());
+                }
+                gamepad.insert((Name::new(name.clone()), gamepad_component));
                 info!("Gamepad {id} connected.");
             }
             GamepadConnection::Disconnected => {
@@ -1585,106 +1613,165 @@ pub enum GamepadConnection {
 
 /// Consumes [`RawGamepadEvent`] events, filters them using their [`GamepadSettings`] and if successful,
 /// updates the [`Gamepad`] and sends [`GamepadAxisChangedEvent`], [`GamepadButtonStateChangedEvent`], [`GamepadButtonChangedEvent`] events.
+///
+/// Raw axis values are first calibrated using the gamepad's [`GamepadCalibration`], if any, then
+/// remapped using [`GamepadMappings`], if that resource exists. Mappings parsed from SDL mapping
+/// strings are left to the input backend.
 pub fn gamepad_event_processing_system(
-    mut gamepads: Query<(&mut Gamepad, &GamepadSettings)>,
+    mut gamepads: Query<(&mut Gamepad, &GamepadSettings, Option<&GamepadCalibration>)>,
     mut raw_events: MessageReader<RawGamepadEvent>,
     mut processed_events: MessageWriter<GamepadEvent>,
     mut processed_axis_events: MessageWriter<GamepadAxisChangedEvent>,
     mut processed_digital_events: MessageWriter<GamepadButtonStateChangedEvent>,
     mut processed_analog_events: MessageWriter<GamepadButtonChangedEvent>,
+    mappings: Option<Res<GamepadMappings>>,
 ) {
     // Clear digital buttons state
-    for (mut gamepad, _) in gamepads.iter_mut() {
+    for (mut gamepad, ..) in gamepads.iter_mut() {
         gamepad.bypass_change_detection().digital.clear();
     }
 
     for event in raw_events.read() {
-        match event {
+        let (gamepad, input, value) = match event {
             // Connections require inserting/removing components so they are done in a separate system
             RawGamepadEvent::Connection(send_event) => {
                 processed_events.write(GamepadEvent::from(send_event.clone()));
+                continue;
             }
             RawGamepadEvent::Axis(RawGamepadAxisChangedEvent {
                 gamepad,
                 axis,
                 value,
-            }) => {
-                let (gamepad, axis, value) = (*gamepad, *axis, *value);
-                let Ok((mut gamepad_axis, gamepad_settings)) = gamepads.get_mut(gamepad) else {
-                    continue;
-                };
-                let Some(filtered_value) = gamepad_settings
-                    .get_axis_settings(axis)
-                    .filter(value, gamepad_axis.get(axis))
-                else {
-                    continue;
-                };
-                gamepad_axis.analog.set(axis, filtered_value.raw);
-                let send_event =
-                    GamepadAxisChangedEvent::new(gamepad, axis, filtered_value.scaled.to_f32());
-                processed_axis_events.write(send_event);
-                processed_events.write(GamepadEvent::from(send_event));
-            }
+            }) => (*gamepad, GamepadInput::Axis(*axis), *value),
             RawGamepadEvent::Button(RawGamepadButtonChangedEvent {
                 gamepad,
                 button,
                 value,
-            }) => {
-                let (gamepad, button, value) = (*gamepad, *button, *value);
-                let Ok((mut gamepad_buttons, settings)) = gamepads.get_mut(gamepad) else {
-                    continue;
-                };
-                let Some(filtered_value) = settings
-                    .get_button_axis_settings(button)
-                    .filter(value, gamepad_buttons.get(button))
-                else {
-                    continue;
-                };
-                let button_settings = settings.get_button_settings(button);
-                gamepad_buttons.analog.set(button, filtered_value.raw);
-
-                if button_settings.is_released(filtered_value.raw) {
-                    // Check if button was previously pressed
-                    if gamepad_buttons.pressed(button) {
-                        processed_digital_events.write(GamepadButtonStateChangedEvent::new(
-                            gamepad,
-                            button,
-                            ButtonState::Released,
-                        ));
-                    }
-                    // We don't have to check if the button was previously pressed here
-                    // because that check is performed within Input<T>::release()
-                    gamepad_buttons.digital.release(button);
-                } else if button_settings.is_pressed(filtered_value.raw) {
-                    // Check if button was previously not pressed
-                    if !gamepad_buttons.pressed(button) {
-                        processed_digital_events.write(GamepadButtonStateChangedEvent::new(
-                            gamepad,
-                            button,
-                            ButtonState::Pressed,
-                        ));
+            }) => (*gamepad, GamepadInput::Button(*button), *value),
+        };
+        let Ok((mut gamepad_state, settings, calibration)) = gamepads.get_mut(gamepad) else {
+            continue;
+        };
+
+        let value = match (input, calibration) {
+            (GamepadInput::Axis(axis), Some(calibration)) => calibration.apply(axis, value),
+            _ => value,
+        };
+        let mapping = mappings
+            .as_ref()
+            .and_then(|mappings| mappings.for_gamepad(&gamepad_state))
+            .filter(|mapping| mapping.sdl_mapping.is_none() && mapping.reads(input));
+        // Inputs that aren't remapped are processed as they are.
+        let mapped = mapping
+            .into_iter()
+            .flat_map(|mapping| mapping.map(input, value))
+            .chain(mapping.is_none().then_some((input, value)));
+
+        for (input, value) in mapped {
+            match input {
+                GamepadInput::Axis(axis) => {
+                    let Some(send_event) =
+                        process_axis_value(gamepad, &mut gamepad_state, settings, axis, value)
+                    else {
+                        continue;
+                    };
+                    processed_axis_events.write(send_event);
+                    processed_events.write(GamepadEvent::from(send_event));
+                }
+                GamepadInput::Button(button) => {
+                    let Some((state_event, send_event)) =
+                        process_button_value(gamepad, &mut gamepad_state, settings, button, value)
+                    else {
+                        continue;
+                    };
+                    if let Some(state_event) = state_event {
+                        processed_digital_events.write(state_event);
                     }
-                    gamepad_buttons.digital.press(button);
-                };
-
-                let button_state = if gamepad_buttons.digital.pressed(button) {
-                    ButtonState::Pressed
-                } else {
-                    ButtonState::Released
-                };
-                let send_event = GamepadButtonChangedEvent::new(
-                    gamepad,
-                    button,
-                    button_state,
-                    filtered_value.scaled.to_f32(),
-                );
-                processed_analog_events.write(send_event);
-                processed_events.write(GamepadEvent::from(send_event));
+                    processed_analog_events.write(send_event);
+                    processed_events.write(GamepadEvent::from(send_event));
+                }
             }
         }
     }
 }
 
+/// Filters a new axis value and stores it on the [`Gamepad`].
+fn process_axis_value(
+    entity: Entity,
+    gamepad: &mut Gamepad,
+    settings: &GamepadSettings,
+    axis: GamepadAxis,
+    value: f32,
+) -> Option<GamepadAxisChangedEvent> {
+    let filtered_value = settings
+        .get_axis_settings(axis)
+        .filter(value, gamepad.get(axis))?;
+    gamepad.analog.set(axis, filtered_value.raw);
+    Some(GamepadAxisChangedEvent::new(
+        entity,
+        axis,
+        filtered_value.scaled.to_f32(),
+    ))
+}
+
+/// Filters a new button value, stores it on the [`Gamepad`] and updates the button's digital state.
+fn process_button_value(
+    entity: Entity,
+    gamepad: &mut Gamepad,
+    settings: &GamepadSettings,
+    button: GamepadButton,
+    value: f32,
+) -> Option<(
+    Option<GamepadButtonStateChangedEvent>,
+    GamepadButtonChangedEvent,
+)> {
+    let filtered_value = settings
+        .get_button_axis_settings(button)
+        .filter(value, gamepad.get(button))?;
+    let button_settings = settings.get_button_settings(button);
+    gamepad.analog.set(button, filtered_value.raw);
+
+    let mut state_event = None;
+    if button_settings.is_released(filtered_value.raw) {
+        // Check if button was previously pressed
+        if gamepad.pressed(button) {
+            state_event = Some(GamepadButtonStateChangedEvent::new(
+                entity,
+                button,
+                ButtonState::Released,
+            ));
+        }
+        // We don't have to check if the button was previously pressed here
+        // because that check is performed within Input<T>::release()
+        gamepad.digital.release(button);
+    } else if button_settings.is_pressed(filtered_value.raw) {
+        // Check if button was previously not pressed
+        if !gamepad.pressed(button) {
+            state_event = Some(GamepadButtonStateChangedEvent::new(
+                entity,
+                button,
+                ButtonState::Pressed,
+            ));
+        }
+        gamepad.digital.press(button);
+    };
+
+    let button_state = if gamepad.digital.pressed(button) {
+        ButtonState::Pressed
+    } else {
+        ButtonState::Released
+    };
+    Some((
+        state_event,
+        GamepadButtonChangedEvent::new(
+            entity,
+            button,
+            button_state,
+            filtered_value.scaled.to_f32(),
+        ),
+    ))
+}
+
 /// The intensity at which a gamepad's force-feedback motors may rumble.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(
@@ -0,0 +1,1184 @@
+//! Runtime gamepad remapping, per-device calibration and layout detection.
+//!
+//! Input backends such as `bevy_gilrs` only know how to map the controllers found in their
+//! built-in databases. Any other controller reports its inputs as [`GamepadButton::Other`] and
+//! [`GamepadAxis::Other`], or with a layout that doesn't match what is printed on the pad.
+//!
+//! [`GamepadMappings`] stores remappings that are applied by
+//! [`gamepad_event_processing_system`](crate::gamepad::gamepad_event_processing_system) before
+//! any [`GamepadSettings`](crate::gamepad::GamepadSettings) filtering takes place. Mappings can
+//! also be written in the [SDL game controller mapping format], so community databases such as
+//! [SDL_GameControllerDB] can be loaded with [`GamepadMappings::add_mappings`], whether the text
+//! comes from a file, an asset or a string embedded in the game. SDL mappings refer to the raw
+//! button and axis indices of the platform's gamepad API, which only the input backend knows
+//! about, so they are handed to the backend instead of being applied by Bevy.
+//!
+//! [`GamepadCalibration`] is a component storing the axis calibration of one gamepad entity.
+//! [`GamepadCalibrations`] remembers the calibration of each model of gamepad, and applies it to
+//! gamepads of that model when they connect. It can be serialized to persist calibration between
+//! runs.
+//!
+//! [`GamepadLayout`] describes the family of face button labels a gamepad uses, so that games
+//! can show the right button glyphs.
+//!
+//! [SDL game controller mapping format]: https://wiki.libsdl.org/SDL2/SDL_GameControllerAddMapping
+//! [SDL_GameControllerDB]: https://github.com/mdqinc/SDL_GameControllerDB
+
+use core::str::FromStr;
+
+use crate::gamepad::{Gamepad, GamepadAxis, GamepadButton, GamepadInput};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy_ecs::{
+    component::Component,
+    query::{Added, Changed, Or},
+    resource::Resource,
+    system::{Query, ResMut},
+};
+use bevy_platform::collections::HashMap;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use log::warn;
+use thiserror::Error;
+
+/// Identifies a model of gamepad by the USB ids it reports.
+///
+/// Two identical controllers share the same id.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadDeviceId {
+    /// The USB vendor ID as assigned by the USB-IF, if available.
+    pub vendor_id: Option<u16>,
+    /// The USB product ID as assigned by the vendor, if available.
+    pub product_id: Option<u16>,
+}
+
+impl GamepadDeviceId {
+    /// Creates a new [`GamepadDeviceId`] from known vendor and product ids.
+    pub const fn new(vendor_id: u16, product_id: u16) -> Self {
+        Self {
+            vendor_id: Some(vendor_id),
+            product_id: Some(product_id),
+        }
+    }
+}
+
+/// The family of face button labels used by a gamepad.
+///
+/// Bevy names face buttons by their position ([`GamepadButton::South`] and friends), which stays
+/// the same regardless of the label printed on the button. Use [`GamepadLayout::button_label`]
+/// to display the label the player expects.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadLayout {
+    /// Xbox style labels: A, B, X and Y with A at the bottom.
+    Xbox,
+    /// Sony style labels: Cross, Circle, Square and Triangle.
+    PlayStation,
+    /// Nintendo style labels: A, B, X and Y with B at the bottom.
+    Nintendo,
+    /// The layout could not be determined.
+    #[default]
+    Unknown,
+}
+
+impl GamepadLayout {
+    /// Guesses the layout from a USB vendor ID.
+    ///
+    /// Third-party controllers usually mimic one of these layouts but report their own vendor
+    /// ID, in which case this returns [`GamepadLayout::Unknown`]. A [`GamepadMapping`] can
+    /// provide the layout for those.
+    pub fn from_vendor_id(vendor_id: u16) -> Self {
+        match vendor_id {
+            0x045E => GamepadLayout::Xbox,
+            0x054C => GamepadLayout::PlayStation,
+            0x057E => GamepadLayout::Nintendo,
+            _ => GamepadLayout::Unknown,
+        }
+    }
+
+    /// Returns the label printed on `button` for this layout.
+    ///
+    /// Returns `None` for buttons without a well-known label, and for every button of
+    /// [`GamepadLayout::Unknown`].
+    pub fn button_label(self, button: GamepadButton) -> Option<&'static str> {
+        use GamepadButton::*;
+
+        use GamepadLayout::{Nintendo, PlayStation, Xbox};
+
+        let label = match (self, button) {
+            (GamepadLayout::Unknown, _) => return None,
+            (Xbox, South) | (Nintendo, East) => "A",
+            (Xbox, East) | (Nintendo, South) => "B",
+            (Xbox, West) | (Nintendo, North) => "X",
+            (Xbox, North) | (Nintendo, West) => "Y",
+            (PlayStation, South) => "Cross",
+            (PlayStation, East) => "Circle",
+            (PlayStation, West) => "Square",
+            (PlayStation, North) => "Triangle",
+            (Xbox, LeftTrigger) => "LB",
+            (Xbox, RightTrigger) => "RB",
+            (Xbox, LeftTrigger2) => "LT",
+            (Xbox, RightTrigger2) => "RT",
+            (Xbox, Select) => "View",
+            (Xbox, Start) => "Menu",
+            (PlayStation, LeftTrigger) => "L1",
+            (PlayStation, RightTrigger) => "R1",
+            (PlayStation, LeftTrigger2) => "L2",
+            (PlayStation, RightTrigger2) => "R2",
+            (PlayStation, Select) => "Share",
+            (PlayStation, Start) => "Options",
+            (Nintendo, LeftTrigger) => "L",
+            (Nintendo, RightTrigger) => "R",
+            (Nintendo, LeftTrigger2) => "ZL",
+            (Nintendo, RightTrigger2) => "ZR",
+            (Nintendo, Select) => "-",
+            (Nintendo, Start) => "+",
+            (_, LeftThumb) => "L3",
+            (_, RightThumb) => "R3",
+            _ => return None,
+        };
+        Some(label)
+    }
+
+    /// Parses the value of an SDL `face:` mapping field.
+    fn from_sdl_face(face: &str) -> Option<Self> {
+        match face {
+            "abxy" => Some(GamepadLayout::Xbox),
+            "bayx" => Some(GamepadLayout::Nintendo),
+            "sony" => Some(GamepadLayout::PlayStation),
+            _ => None,
+        }
+    }
+}
+
+/// The part of an axis range used by a [`GamepadBinding`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum AxisRange {
+    /// The whole `-1.0..=1.0` range.
+    #[default]
+    Full,
+    /// Only the `0.0..=1.0` half of the range.
+    Positive,
+    /// Only the `-1.0..=0.0` half of the range.
+    Negative,
+}
+
+/// The raw input read by a [`GamepadBinding`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadMappingSource {
+    /// A button as reported by the input backend.
+    ///
+    /// SDL `bN` bindings are parsed as [`GamepadButton::Other(N)`](GamepadButton::Other), but
+    /// `N` is the platform's raw button index, see [`GamepadMapping::sdl_mapping`].
+    Button(GamepadButton),
+    /// An axis as reported by the input backend.
+    ///
+    /// SDL `aN` bindings are parsed as [`GamepadAxis::Other(N)`](GamepadAxis::Other), but
+    /// `N` is the platform's raw axis index, see [`GamepadMapping::sdl_mapping`].
+    Axis {
+        /// The axis to read.
+        axis: GamepadAxis,
+        /// The part of the axis to read.
+        range: AxisRange,
+        /// Whether the value is negated before being used.
+        inverted: bool,
+    },
+    /// A hat switch bit, as used by SDL `hN.M` bindings.
+    ///
+    /// Bevy's input backends report hats as buttons, so hat bindings are kept when parsing but
+    /// never match any input.
+    Hat {
+        /// The index of the hat.
+        hat: u32,
+        /// The direction bit of the hat.
+        mask: u8,
+    },
+}
+
+impl GamepadMappingSource {
+    /// Returns the value of this source in response to `input` changing to `value`.
+    ///
+    /// Full-range axes produce values in `-1.0..=1.0`, everything else in `0.0..=1.0`.
+    fn read(&self, input: GamepadInput, value: f32) -> Option<f32> {
+        match (*self, input) {
+            (GamepadMappingSource::Button(button), GamepadInput::Button(input))
+                if button == input =>
+            {
+                Some(value)
+            }
+            (
+                GamepadMappingSource::Axis {
+                    axis,
+                    range,
+                    inverted,
+                },
+                GamepadInput::Axis(input),
+            ) if axis == input => {
+                let value = if inverted { -value } else { value };
+                Some(match range {
+                    AxisRange::Full => value,
+                    AxisRange::Positive => value.max(0.0),
+                    AxisRange::Negative => (-value).max(0.0),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if values read from this source span `-1.0..=1.0`.
+    fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            GamepadMappingSource::Axis {
+                range: AxisRange::Full,
+                ..
+            }
+        )
+    }
+}
+
+/// The input produced by a [`GamepadBinding`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadMappingTarget {
+    /// Drives a button with a value in `0.0..=1.0`.
+    Button(GamepadButton),
+    /// Drives an axis, or one half of it.
+    Axis {
+        /// The axis to drive.
+        axis: GamepadAxis,
+        /// The part of the axis that is driven.
+        range: AxisRange,
+    },
+}
+
+/// A single remapping from a raw input to a standard input.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadBinding {
+    /// The raw input that is read.
+    pub source: GamepadMappingSource,
+    /// The input that is produced.
+    pub target: GamepadMappingTarget,
+}
+
+impl GamepadBinding {
+    /// Maps `value` of the raw `input` to the target input, if this binding reads `input`.
+    pub fn map(&self, input: GamepadInput, value: f32) -> Option<(GamepadInput, f32)> {
+        let value = self.source.read(input, value)?;
+        // Signed sources cover both halves of a target that only uses one.
+        let unsigned = || {
+            if self.source.is_signed() {
+                (value + 1.0) / 2.0
+            } else {
+                value
+            }
+        };
+        Some(match self.target {
+            GamepadMappingTarget::Button(button) => (button.into(), unsigned()),
+            GamepadMappingTarget::Axis { axis, range } => {
+                let value = match range {
+                    AxisRange::Full => value,
+                    AxisRange::Positive => unsigned(),
+                    AxisRange::Negative => -unsigned(),
+                };
+                (axis.into(), value)
+            }
+        })
+    }
+}
+
+/// Errors that occur when parsing an SDL game controller mapping string.
+#[derive(Error, Debug, PartialEq)]
+pub enum GamepadMappingError {
+    /// The mapping doesn't start with a GUID and a name.
+    #[error("mapping is missing the GUID or name field")]
+    MissingFields,
+    /// The GUID isn't made of 32 hexadecimal digits.
+    #[error("invalid GUID {0}, expected 32 hexadecimal digits")]
+    InvalidGuid(String),
+    /// The GUID doesn't contain a USB vendor and product ID, so the mapping can't be matched to
+    /// a gamepad.
+    #[error("GUID {0} doesn't contain a vendor and product ID")]
+    UnsupportedGuid(String),
+    /// A field isn't a valid `target:source` binding.
+    #[error("invalid binding {0}")]
+    InvalidBinding(String),
+}
+
+/// A set of [`GamepadBinding`]s for one model of gamepad.
+///
+/// Usually parsed from an SDL mapping string with [`str::parse`], but can also be built by hand:
+///
+/// ```
+/// # use bevy_input::gamepad::GamepadButton;
+/// # use bevy_input::gamepad_mapping::{GamepadMapping, GamepadMappingSource, GamepadMappingTarget};
+/// // Swap the bottom and right face buttons of a third-party pad.
+/// let mapping = GamepadMapping::new(0x1234, 0x5678)
+///     .with_binding(
+///         GamepadMappingSource::Button(GamepadButton::South),
+///         GamepadMappingTarget::Button(GamepadButton::East),
+///     )
+///     .with_binding(
+///         GamepadMappingSource::Button(GamepadButton::East),
+///         GamepadMappingTarget::Button(GamepadButton::South),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadMapping {
+    /// The USB vendor ID of the gamepads this mapping applies to.
+    pub vendor_id: u16,
+    /// The USB product ID of the gamepads this mapping applies to.
+    pub product_id: u16,
+    /// A human-readable name for the gamepad.
+    pub name: String,
+    /// The SDL platform name this mapping was written for, if any.
+    pub platform: Option<String>,
+    /// The face button layout of the gamepad, if known.
+    ///
+    /// Overrides the layout guessed from the vendor ID in [`GamepadMappings::layout`].
+    pub layout: Option<GamepadLayout>,
+    /// The remapped inputs.
+    ///
+    /// Inputs that aren't read by any binding are passed through unchanged.
+    pub bindings: Vec<GamepadBinding>,
+    /// The SDL mapping string this mapping was parsed from, if any.
+    ///
+    /// The indices in SDL mappings are raw indices of the platform's gamepad API, which Bevy
+    /// never sees, so Bevy doesn't apply the bindings of these mappings itself. Input backends
+    /// that understand SDL mappings, such as `bevy_gilrs`, read them with
+    /// [`GamepadMappings::sdl_mappings`] instead.
+    pub sdl_mapping: Option<String>,
+}
+
+impl GamepadMapping {
+    /// Creates an empty mapping for the given vendor and product IDs.
+    pub fn new(vendor_id: u16, product_id: u16) -> Self {
+        Self {
+            vendor_id,
+            product_id,
+            name: String::new(),
+            platform: None,
+            layout: None,
+            bindings: Vec::new(),
+            sdl_mapping: None,
+        }
+    }
+
+    /// Adds a binding from `source` to `target`.
+    pub fn with_binding(
+        mut self,
+        source: GamepadMappingSource,
+        target: GamepadMappingTarget,
+    ) -> Self {
+        self.bindings.push(GamepadBinding { source, target });
+        self
+    }
+
+    /// Sets the face button layout of the gamepad.
+    pub fn with_layout(mut self, layout: GamepadLayout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Returns the [`GamepadDeviceId`] this mapping applies to.
+    pub fn device_id(&self) -> GamepadDeviceId {
+        GamepadDeviceId::new(self.vendor_id, self.product_id)
+    }
+
+    /// Returns `true` if any binding reads `input`.
+    pub fn reads(&self, input: GamepadInput) -> bool {
+        self.bindings
+            .iter()
+            .any(|binding| binding.source.read(input, 0.0).is_some())
+    }
+
+    /// Maps a change of the raw `input` to the standard inputs it drives.
+    ///
+    /// Returns nothing if no binding reads `input`; use [`GamepadMapping::reads`] to tell that
+    /// apart from an input that is deliberately left unbound.
+    pub fn map(
+        &self,
+        input: GamepadInput,
+        value: f32,
+    ) -> impl Iterator<Item = (GamepadInput, f32)> + '_ {
+        self.bindings
+            .iter()
+            .filter_map(move |binding| binding.map(input, value))
+    }
+
+    /// Returns `true` if this mapping applies to the platform the app was compiled for.
+    pub fn matches_platform(&self) -> bool {
+        match (&self.platform, current_sdl_platform()) {
+            (Some(platform), Some(current)) => platform == current,
+            _ => true,
+        }
+    }
+}
+
+impl FromStr for GamepadMapping {
+    type Err = GamepadMappingError;
+
+    /// Parses an SDL game controller mapping, such as
+    /// `030000005e0400008e02000014010000,Xbox 360 Controller,a:b0,b:b1,leftx:a0,platform:Linux,`.
+    ///
+    /// Unknown fields and targets are ignored, so mappings written for newer SDL versions can
+    /// still be loaded.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.trim().split(',');
+        let (Some(guid), Some(name)) = (fields.next(), fields.next()) else {
+            return Err(GamepadMappingError::MissingFields);
+        };
+        let guid = guid.trim();
+        let (vendor_id, product_id) = parse_sdl_guid(guid)?;
+
+        let mut mapping = GamepadMapping::new(vendor_id, product_id);
+        mapping.name = name.trim().to_string();
+        mapping.sdl_mapping = Some(s.trim().to_string());
+
+        for field in fields.map(str::trim).filter(|field| !field.is_empty()) {
+            let Some((key, value)) = field.split_once(':') else {
+                return Err(GamepadMappingError::InvalidBinding(field.to_string()));
+            };
+            match key {
+                "platform" => mapping.platform = Some(value.to_string()),
+                "face" => mapping.layout = GamepadLayout::from_sdl_face(value),
+                _ => {
+                    let Some(mut source) = parse_sdl_source(value) else {
+                        return Err(GamepadMappingError::InvalidBinding(field.to_string()));
+                    };
+                    let Some(mut target) = parse_sdl_target(key) else {
+                        continue;
+                    };
+                    // SDL's vertical axes point down, Bevy's point up.
+                    if let GamepadMappingTarget::Axis {
+                        axis: GamepadAxis::LeftStickY | GamepadAxis::RightStickY,
+                        range,
+                    } = &mut target
+                    {
+                        match (*range, &mut source) {
+                            (
+                                AxisRange::Full,
+                                GamepadMappingSource::Axis {
+                                    range: AxisRange::Full,
+                                    inverted,
+                                    ..
+                                },
+                            ) => *inverted = !*inverted,
+                            (AxisRange::Full | AxisRange::Positive, _) => {
+                                *range = AxisRange::Negative;
+                            }
+                            (AxisRange::Negative, _) => *range = AxisRange::Positive,
+                        }
+                    }
+                    mapping.bindings.push(GamepadBinding { source, target });
+                }
+            }
+        }
+
+        Ok(mapping)
+    }
+}
+
+/// Gamepad remappings, applied to raw gamepad input before it is processed.
+///
+/// Mappings are looked up by the [`GamepadDeviceId`] of each [`Gamepad`], so gamepads that don't
+/// report a vendor and product ID are never remapped.
+#[derive(Debug, Default, Clone, Resource)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, Resource, Clone)
+)]
+pub struct GamepadMappings {
+    mappings: HashMap<GamepadDeviceId, GamepadMapping>,
+}
+
+impl GamepadMappings {
+    /// Parses newline separated SDL mapping strings and adds them, returning how many were added.
+    ///
+    /// Blank lines, `#` comments, mappings for other platforms and mappings whose GUID doesn't
+    /// contain a vendor and product ID are skipped. Invalid mappings are skipped with a warning.
+    /// Later mappings replace earlier ones for the same device.
+    pub fn add_mappings(&mut self, mappings: &str) -> usize {
+        let mut added = 0;
+        for line in mappings.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.parse::<GamepadMapping>() {
+                Ok(mapping) if mapping.matches_platform() => {
+                    self.insert(mapping);
+                    added += 1;
+                }
+                Ok(_) | Err(GamepadMappingError::UnsupportedGuid(_)) => {}
+                Err(err) => warn!("Skipping gamepad mapping: {err}"),
+            }
+        }
+        added
+    }
+
+    /// Reads a file of SDL mapping strings, such as `gamecontrollerdb.txt`, and adds its
+    /// mappings with [`GamepadMappings::add_mappings`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read.
+    #[cfg(feature = "std")]
+    pub fn load_file(&mut self, path: impl AsRef<std::path::Path>) -> std::io::Result<usize> {
+        let mappings = std::fs::read_to_string(path)?;
+        Ok(self.add_mappings(&mappings))
+    }
+
+    /// Adds a mapping, returning the mapping it replaced for the same device, if any.
+    pub fn insert(&mut self, mapping: GamepadMapping) -> Option<GamepadMapping> {
+        self.mappings.insert(mapping.device_id(), mapping)
+    }
+
+    /// Removes the mapping for a device.
+    pub fn remove(&mut self, device: GamepadDeviceId) -> Option<GamepadMapping> {
+        self.mappings.remove(&device)
+    }
+
+    /// Returns the mapping for a device.
+    pub fn get(&self, device: GamepadDeviceId) -> Option<&GamepadMapping> {
+        self.mappings.get(&device)
+    }
+
+    /// Returns the mapping that applies to `gamepad`.
+    pub fn for_gamepad(&self, gamepad: &Gamepad) -> Option<&GamepadMapping> {
+        self.get(gamepad.device_id())
+    }
+
+    /// Returns the face button layout of `gamepad`.
+    ///
+    /// Prefers the layout of the gamepad's mapping, falling back to [`Gamepad::layout`].
+    pub fn layout(&self, gamepad: &Gamepad) -> GamepadLayout {
+        self.for_gamepad(gamepad)
+            .and_then(|mapping| mapping.layout)
+            .unwrap_or_else(|| gamepad.layout())
+    }
+
+    /// Returns an iterator over all mappings.
+    pub fn iter(&self) -> impl Iterator<Item = &GamepadMapping> {
+        self.mappings.values()
+    }
+
+    /// Returns an iterator over the SDL mapping strings of all mappings parsed from one.
+    ///
+    /// Input backends pass these to their SDL mapping database.
+    pub fn sdl_mappings(&self) -> impl Iterator<Item = &str> {
+        self.iter()
+            .filter_map(|mapping| mapping.sdl_mapping.as_deref())
+    }
+
+    /// Returns the number of mappings.
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    /// Returns `true` if there are no mappings.
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+}
+
+/// Calibration for a single axis, mapping the raw range a device reports onto `-1.0..=1.0`.
+///
+/// The default calibration leaves values unchanged.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AxisCalibration {
+    /// The raw value reported at the lowest position, mapped to `-1.0`.
+    pub min: f32,
+    /// The raw value reported at rest, mapped to `0.0`.
+    pub center: f32,
+    /// The raw value reported at the highest position, mapped to `1.0`.
+    pub max: f32,
+}
+
+impl Default for AxisCalibration {
+    fn default() -> Self {
+        Self {
+            min: -1.0,
+            center: 0.0,
+            max: 1.0,
+        }
+    }
+}
+
+impl AxisCalibration {
+    /// Creates a calibration for a device that never reaches any raw value yet.
+    ///
+    /// Use [`AxisCalibration::record`] to widen the range while the player moves the axis to
+    /// its extremes.
+    pub fn uncalibrated(center: f32) -> Self {
+        Self {
+            min: center,
+            center,
+            max: center,
+        }
+    }
+
+    /// Maps a raw value onto `-1.0..=1.0`.
+    pub fn apply(&self, value: f32) -> f32 {
+        if value >= self.center {
+            let span = self.max - self.center;
+            if span <= f32::EPSILON {
+                0.0
+            } else {
+                ((value - self.center) / span).min(1.0)
+            }
+        } else {
+            let span = self.center - self.min;
+            if span <= f32::EPSILON {
+                0.0
+            } else {
+                -((self.center - value) / span).min(1.0)
+            }
+        }
+    }
+
+    /// Widens the calibrated range to include a raw value.
+    pub fn record(&mut self, value: f32) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+}
+
+/// Axis calibration of a gamepad, applied to raw axis values before any remapping.
+///
+/// Insert this component on a [`Gamepad`] entity to calibrate it. Calibration is stored per
+/// entity, so two connected gamepads of the same model can be calibrated separately. Changes are
+/// also written to [`GamepadCalibrations`], so the calibration is restored when a gamepad of the
+/// same model connects again.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Component, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadCalibration {
+    /// The calibration of each axis. Axes without an entry are left unchanged.
+    pub axes: HashMap<GamepadAxis, AxisCalibration>,
+}
+
+impl GamepadCalibration {
+    /// Applies the calibration of `axis` to a raw value.
+    pub fn apply(&self, axis: GamepadAxis, value: f32) -> f32 {
+        self.axes
+            .get(&axis)
+            .map_or(value, |calibration| calibration.apply(value))
+    }
+
+    /// Returns the calibration of `axis`, inserting the default calibration if there is none.
+    pub fn axis_mut(&mut self, axis: GamepadAxis) -> &mut AxisCalibration {
+        self.axes.entry(axis).or_default()
+    }
+}
+
+/// The last [`GamepadCalibration`] of each model of gamepad.
+///
+/// When a gamepad connects, its calibration is inserted as a [`GamepadCalibration`] component,
+/// and changes to that component are written back here by [`store_gamepad_calibrations`].
+/// Gamepads that don't report a vendor and product ID are never stored.
+#[derive(Debug, Default, Clone, PartialEq, Resource)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Resource, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadCalibrations {
+    calibrations: HashMap<GamepadDeviceId, GamepadCalibration>,
+}
+
+impl GamepadCalibrations {
+    /// Stores the calibration of a device, returning the calibration it replaced, if any.
+    pub fn insert(
+        &mut self,
+        device: GamepadDeviceId,
+        calibration: GamepadCalibration,
+    ) -> Option<GamepadCalibration> {
+        self.calibrations.insert(device, calibration)
+    }
+
+    /// Removes the calibration of a device.
+    pub fn remove(&mut self, device: GamepadDeviceId) -> Option<GamepadCalibration> {
+        self.calibrations.remove(&device)
+    }
+
+    /// Returns the calibration of a device.
+    pub fn get(&self, device: GamepadDeviceId) -> Option<&GamepadCalibration> {
+        self.calibrations.get(&device)
+    }
+
+    /// Returns an iterator over all devices and their calibration.
+    pub fn iter(&self) -> impl Iterator<Item = (GamepadDeviceId, &GamepadCalibration)> {
+        self.calibrations
+            .iter()
+            .map(|(device, calibration)| (*device, calibration))
+    }
+}
+
+/// Writes the [`GamepadCalibration`] of newly connected or recalibrated gamepads to
+/// [`GamepadCalibrations`].
+pub fn store_gamepad_calibrations(
+    mut calibrations: ResMut<GamepadCalibrations>,
+    gamepads: Query<
+        (&Gamepad, &GamepadCalibration),
+        Or<(Changed<GamepadCalibration>, Added<Gamepad>)>,
+    >,
+) {
+    for (gamepad, calibration) in &gamepads {
+        let device = gamepad.device_id();
+        if device.vendor_id.is_some() && device.product_id.is_some() {
+            calibrations.insert(device, calibration.clone());
+        }
+    }
+}
+
+/// Returns the SDL name of the platform the app was compiled for.
+fn current_sdl_platform() -> Option<&'static str> {
+    if cfg!(target_os = "windows") {
+        Some("Windows")
+    } else if cfg!(target_os = "macos") {
+        Some("Mac OS X")
+    } else if cfg!(target_os = "linux") {
+        Some("Linux")
+    } else if cfg!(target_os = "android") {
+        Some("Android")
+    } else if cfg!(target_os = "ios") {
+        Some("iOS")
+    } else {
+        None
+    }
+}
+
+/// Extracts the USB vendor and product IDs from an SDL joystick GUID.
+fn parse_sdl_guid(guid: &str) -> Result<(u16, u16), GamepadMappingError> {
+    let invalid = || GamepadMappingError::InvalidGuid(guid.to_string());
+    if guid.len() != 32 || !guid.is_ascii() {
+        return Err(invalid());
+    }
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&guid[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+
+    // Bytes 0-3 hold the bus type and a name CRC, followed by little-endian vendor and product
+    // IDs, each padded with two zero bytes. GUIDs that don't follow this layout (e.g. XInput
+    // devices on Windows) don't identify a specific device.
+    let vendor_id = u16::from_le_bytes([bytes[4], bytes[5]]);
+    let product_id = u16::from_le_bytes([bytes[8], bytes[9]]);
+    if vendor_id == 0 || bytes[6..8] != [0, 0] || bytes[10..12] != [0, 0] {
+        return Err(GamepadMappingError::UnsupportedGuid(guid.to_string()));
+    }
+    Ok((vendor_id, product_id))
+}
+
+/// Parses the source of an SDL binding, such as `b0`, `+a2`, `a3~` or `h0.4`.
+fn parse_sdl_source(value: &str) -> Option<GamepadMappingSource> {
+    let (range, value) = if let Some(value) = value.strip_prefix('+') {
+        (AxisRange::Positive, value)
+    } else if let Some(value) = value.strip_prefix('-') {
+        (AxisRange::Negative, value)
+    } else {
+        (AxisRange::Full, value)
+    };
+    let (inverted, value) = match value.strip_suffix('~') {
+        Some(value) => (true, value),
+        None => (false, value),
+    };
+
+    match value.split_at_checked(1)? {
+        ("b", index) if range == AxisRange::Full && !inverted => Some(
+            GamepadMappingSource::Button(GamepadButton::Other(index.parse().ok()?)),
+        ),
+        ("a", index) => Some(GamepadMappingSource::Axis {
+            axis: GamepadAxis::Other(index.parse().ok()?),
+            range,
+            inverted,
+        }),
+        ("h", hat) if range == AxisRange::Full && !inverted => {
+            let (hat, mask) = hat.split_once('.')?;
+            Some(GamepadMappingSource::Hat {
+                hat: hat.parse().ok()?,
+                mask: mask.parse().ok()?,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parses the target of an SDL binding, such as `a`, `leftx` or `+lefty`, in SDL's axis
+/// directions.
+///
+/// Returns `None` for targets Bevy has no equivalent for.
+fn parse_sdl_target(key: &str) -> Option<GamepadMappingTarget> {
+    let (range, key) = if let Some(key) = key.strip_prefix('+') {
+        (AxisRange::Positive, key)
+    } else if let Some(key) = key.strip_prefix('-') {
+        (AxisRange::Negative, key)
+    } else {
+        (AxisRange::Full, key)
+    };
+    let button = match key {
+        "a" => GamepadButton::South,
+        "b" => GamepadButton::East,
+        "x" => GamepadButton::West,
+        "y" => GamepadButton::North,
+        "back" => GamepadButton::Select,
+        "start" => GamepadButton::Start,
+        "guide" => GamepadButton::Mode,
+        "leftstick" => GamepadButton::LeftThumb,
+        "rightstick" => GamepadButton::RightThumb,
+        "leftshoulder" => GamepadButton::LeftTrigger,
+        "rightshoulder" => GamepadButton::RightTrigger,
+        "lefttrigger" => GamepadButton::LeftTrigger2,
+        "righttrigger" => GamepadButton::RightTrigger2,
+        "dpup" => GamepadButton::DPadUp,
+        "dpdown" => GamepadButton::DPadDown,
+        "dpleft" => GamepadButton::DPadLeft,
+        "dpright" => GamepadButton::DPadRight,
+        _ => {
+            let axis = match key {
+                "leftx" => GamepadAxis::LeftStickX,
+                "lefty" => GamepadAxis::LeftStickY,
+                "rightx" => GamepadAxis::RightStickX,
+                "righty" => GamepadAxis::RightStickY,
+                _ => return None,
+            };
+            return Some(GamepadMappingTarget::Axis { axis, range });
+        }
+    };
+    Some(GamepadMappingTarget::Button(button))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gamepad::{
+        gamepad_connection_system, gamepad_event_processing_system, GamepadAxisChangedEvent,
+        GamepadButtonChangedEvent, GamepadButtonStateChangedEvent, GamepadConnection,
+        GamepadConnectionEvent, GamepadEvent, RawGamepadAxisChangedEvent,
+        RawGamepadButtonChangedEvent, RawGamepadEvent,
+    };
+    use crate::InputPlugin;
+    use bevy_app::{App, PreUpdate};
+    use bevy_ecs::{message::Messages, schedule::IntoScheduleConfigs};
+
+    const XBOX_360: &str = "030000005e0400008e02000014010000,Xbox 360 Controller,a:b0,b:b1,x:b2,y:b3,leftx:a0,lefty:a1,lefttrigger:a2,-rightx:b9,paddle1:b11,face:abxy,platform:Linux,";
+
+    #[test]
+    fn parse_sdl_mapping() {
+        let mapping: GamepadMapping = XBOX_360.parse().unwrap();
+        assert_eq!(mapping.device_id(), GamepadDeviceId::new(0x045E, 0x028E));
+        assert_eq!(mapping.name, "Xbox 360 Controller");
+        assert_eq!(mapping.platform.as_deref(), Some("Linux"));
+        assert_eq!(mapping.layout, Some(GamepadLayout::Xbox));
+        assert_eq!(mapping.sdl_mapping.as_deref(), Some(XBOX_360));
+        // `paddle1` has no Bevy equivalent and is skipped.
+        assert_eq!(mapping.bindings.len(), 8);
+
+        let map = |input: GamepadInput, value| mapping.map(input, value).collect::<Vec<_>>();
+        assert_eq!(
+            map(GamepadButton::Other(1).into(), 1.0),
+            [(GamepadButton::East.into(), 1.0)]
+        );
+        // SDL's vertical axes point down.
+        assert_eq!(
+            map(GamepadAxis::Other(1).into(), 0.5),
+            [(GamepadAxis::LeftStickY.into(), -0.5)]
+        );
+        // Full range axes drive analog buttons over their whole range.
+        assert_eq!(
+            map(GamepadAxis::Other(2).into(), 0.0),
+            [(GamepadButton::LeftTrigger2.into(), 0.5)]
+        );
+        assert_eq!(
+            map(GamepadButton::Other(9).into(), 1.0),
+            [(GamepadAxis::RightStickX.into(), -1.0)]
+        );
+        assert!(!mapping.reads(GamepadButton::South.into()));
+
+        assert_eq!(
+            "78696e70757401000000000000000000,XInput Controller,a:b0,".parse::<GamepadMapping>(),
+            Err(GamepadMappingError::UnsupportedGuid(
+                "78696e70757401000000000000000000".into()
+            ))
+        );
+        assert_eq!(
+            "030000005e0400008e02000014010000,Xbox 360 Controller,a:z0,".parse::<GamepadMapping>(),
+            Err(GamepadMappingError::InvalidBinding("a:z0".into()))
+        );
+
+        let mut mappings = GamepadMappings::default();
+        let added = mappings.add_mappings(&alloc::format!(
+            "# Comment\n\n{XBOX_360}\n03000000, broken\n"
+        ));
+        assert_eq!(added, usize::from(mapping.matches_platform()));
+        assert_eq!(mappings.sdl_mappings().count(), added);
+    }
+
+    #[test]
+    fn layout_and_calibration() {
+        assert_eq!(
+            GamepadLayout::from_vendor_id(0x054C),
+            GamepadLayout::PlayStation
+        );
+        assert_eq!(
+            GamepadLayout::Nintendo.button_label(GamepadButton::East),
+            Some("A")
+        );
+        assert_eq!(
+            GamepadLayout::Xbox.button_label(GamepadButton::East),
+            Some("B")
+        );
+        assert_eq!(
+            GamepadLayout::Unknown.button_label(GamepadButton::East),
+            None
+        );
+
+        let mut calibration = AxisCalibration::uncalibrated(0.1);
+        assert_eq!(calibration.apply(0.5), 0.0);
+        calibration.record(-0.5);
+        calibration.record(0.6);
+        assert_eq!(calibration.apply(0.1), 0.0);
+        assert_eq!(calibration.apply(0.6), 1.0);
+        assert_eq!(calibration.apply(0.9), 1.0);
+        assert_eq!(calibration.apply(-0.2), -0.5);
+    }
+
+    #[test]
+    fn remaps_and_calibrates_raw_events() {
+        let mut app = App::new();
+        app.add_systems(
+            PreUpdate,
+            (
+                gamepad_connection_system,
+                gamepad_event_processing_system.after(gamepad_connection_system),
+            ),
+        )
+        .add_message::<GamepadEvent>()
+        .add_message::<GamepadConnectionEvent>()
+        .add_message::<GamepadButtonChangedEvent>()
+        .add_message::<GamepadButtonStateChangedEvent>()
+        .add_message::<GamepadAxisChangedEvent>()
+        .add_message::<RawGamepadEvent>();
+
+        let mut mappings = GamepadMappings::default();
+        mappings.insert(
+            GamepadMapping::new(0x1234, 0x5678)
+                .with_binding(
+                    GamepadMappingSource::Button(GamepadButton::South),
+                    GamepadMappingTarget::Button(GamepadButton::East),
+                )
+                .with_binding(
+                    GamepadMappingSource::Button(GamepadButton::Other(7)),
+                    GamepadMappingTarget::Button(GamepadButton::South),
+                ),
+        );
+        // SDL mappings are left to the input backend.
+        mappings.insert(
+            "030000005e0400008e02000014010000,Xbox 360 Controller,a:b1,"
+                .parse()
+                .unwrap(),
+        );
+        app.insert_resource(mappings);
+
+        let mut calibration = GamepadCalibration::default();
+        *calibration.axis_mut(GamepadAxis::LeftStickX) = AxisCalibration {
+            min: -0.5,
+            center: 0.0,
+            max: 0.5,
+        };
+        // Calibration is per entity, so only the first of two identical pads is calibrated.
+        let calibrated = app.world_mut().spawn(calibration).id();
+        let uncalibrated = app.world_mut().spawn_empty().id();
+        let xbox = app.world_mut().spawn_empty().id();
+        let mut connections = app
+            .world_mut()
+            .resource_mut::<Messages<GamepadConnectionEvent>>();
+        for (entity, vendor_id, product_id) in [
+            (calibrated, 0x1234, 0x5678),
+            (uncalibrated, 0x1234, 0x5678),
+            (xbox, 0x045E, 0x028E),
+        ] {
+            connections.write(GamepadConnectionEvent::new(
+                entity,
+                GamepadConnection::Connected {
+                    name: "Pad".into(),
+                    vendor_id: Some(vendor_id),
+                    product_id: Some(product_id),
+                },
+            ));
+        }
+        app.update();
+
+        let mut raw_events = app.world_mut().resource_mut::<Messages<RawGamepadEvent>>();
+        for button in [GamepadButton::South, GamepadButton::Other(7)] {
+            raw_events.write(RawGamepadButtonChangedEvent::new(calibrated, button, 1.0).into());
+        }
+        for entity in [calibrated, uncalibrated] {
+            raw_events.write(
+                RawGamepadAxisChangedEvent::new(entity, GamepadAxis::LeftStickX, 0.25).into(),
+            );
+        }
+        raw_events.write(RawGamepadButtonChangedEvent::new(xbox, GamepadButton::South, 1.0).into());
+        app.update();
+
+        let gamepad = app.world().get::<Gamepad>(calibrated).unwrap();
+        assert!(gamepad.pressed(GamepadButton::East));
+        assert!(gamepad.pressed(GamepadButton::South));
+        assert!(!gamepad.pressed(GamepadButton::Other(7)));
+        assert_eq!(gamepad.get(GamepadAxis::LeftStickX), Some(0.5));
+        let gamepad = app.world().get::<Gamepad>(uncalibrated).unwrap();
+        assert_eq!(gamepad.get(GamepadAxis::LeftStickX), Some(0.25));
+        let gamepad = app.world().get::<Gamepad>(xbox).unwrap();
+        assert!(gamepad.pressed(GamepadButton::South));
+        assert!(!gamepad.pressed(GamepadButton::East));
+    }
+
+    #[test]
+    fn calibration_is_restored_on_reconnect() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin);
+        let connect = |app: &mut App, entity, connection| {
+            app.world_mut()
+                .write_message(GamepadConnectionEvent::new(entity, connection));
+            app.update();
+        };
+        let connected = || GamepadConnection::Connected {
+            name: "Pad".into(),
+            vendor_id: Some(0x1234),
+            product_id: Some(0x5678),
+        };
+
+        let pad = app.world_mut().spawn_empty().id();
+        connect(&mut app, pad, connected());
+        let mut calibration = GamepadCalibration::default();
+        *calibration.axis_mut(GamepadAxis::LeftStickX) = AxisCalibration {
+            min: -0.5,
+            center: 0.0,
+            max: 0.5,
+        };
+        app.world_mut().entity_mut(pad).insert(calibration.clone());
+        app.update();
+        let device = GamepadDeviceId::new(0x1234, 0x5678);
+        assert_eq!(
+            app.world().resource::<GamepadCalibrations>().get(device),
+            Some(&calibration)
+        );
+
+        // The same model connecting as a new entity, like after restarting the game, gets the
+        // stored calibration.
+        connect(&mut app, pad, GamepadConnection::Disconnected);
+        let reconnected = app.world_mut().spawn_empty().id();
+        connect(&mut app, reconnected, connected());
+        assert_eq!(
+            app.world().get::<GamepadCalibration>(reconnected),
+            Some(&calibration)
+        );
+        app.world_mut()
+            .write_message(RawGamepadEvent::from(RawGamepadAxisChangedEvent::new(
+                reconnected,
+                GamepadAxis::LeftStickX,
+                0.25,
+            )));
+        app.update();
+        let gamepad = app.world().get::<Gamepad>(reconnected).unwrap();
+        assert_eq!(gamepad.get(GamepadAxis::LeftStickX), Some(0.5));
+    }
+}
@@ -0,0 +1,540 @@
+//! Buffered button presses, for lenient input timing and combo detection.
+//!
+//! [`ButtonInput::just_pressed`] only reports presses from the current frame. An [`InputBuffer`]
+//! remembers every press for a configurable window of [`Time<Real>`], so a jump pressed shortly
+//! before landing can still be honored, and sequences of presses can be matched against an
+//! [`InputSequence`].
+//!
+//! Add an [`InputBufferPlugin`] for each input type to buffer, then query the buffer from systems
+//! or with the run conditions in [`common_conditions`](crate::common_conditions):
+//!
+//! ```
+//! # use bevy_app::{App, Update};
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::{common_conditions::input_sequence_completed, prelude::KeyCode};
+//! # use bevy_input::input_buffer::{InputBuffer, InputBufferPlugin, InputSequence};
+//! # use core::time::Duration;
+//! fn jump(mut buffer: ResMut<InputBuffer<KeyCode>>) {
+//!     // Jump even if the key was pressed a few frames before landing.
+//!     if buffer.consume(KeyCode::Space, Duration::from_millis(120)) {
+//!         // ...
+//!     }
+//! }
+//!
+//! fn hadouken() {}
+//!
+//! let hadouken_input = InputSequence::new()
+//!     .then(KeyCode::ArrowDown)
+//!     .then(KeyCode::ArrowRight)
+//!     .within(Duration::from_millis(200))
+//!     .then(KeyCode::KeyP)
+//!     .within(Duration::from_millis(150));
+//!
+//! App::new()
+//!     .add_plugins(InputBufferPlugin::<KeyCode>::default())
+//!     .add_systems(Update, (jump, hadouken.run_if(input_sequence_completed(hadouken_input))));
+//! ```
+//!
+//! Presses can also be recorded by hand with [`InputBuffer::press`], for example to buffer
+//! directions computed from a stick as numpad notation.
+
+use crate::{ButtonInput, InputSystems};
+use alloc::{collections::VecDeque, vec::Vec};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use core::{hash::Hash, marker::PhantomData, time::Duration};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+/// Records presses of `T` from [`ButtonInput<T>`] into an [`InputBuffer<T>`].
+///
+/// This requires the [`Time<Real>`] resource, which is added by the `TimePlugin`. Insert an
+/// [`InputBuffer<T>`] before adding the plugin to change its window.
+pub struct InputBufferPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for InputBufferPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Plugin for InputBufferPlugin<T>
+where
+    T: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ButtonInput<T>>()
+            .init_resource::<InputBuffer<T>>()
+            .configure_sets(PreUpdate, InputBufferSystems.after(InputSystems))
+            .add_systems(PreUpdate, buffer_inputs::<T>.in_set(InputBufferSystems));
+    }
+}
+
+/// Label for the systems that record presses into [`InputBuffer`]s.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct InputBufferSystems;
+
+/// Advances the [`InputBuffer<T>`] and records the presses of this frame.
+pub fn buffer_inputs<T>(
+    time: Res<Time<Real>>,
+    inputs: Res<ButtonInput<T>>,
+    mut buffer: ResMut<InputBuffer<T>>,
+) where
+    T: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    buffer.update(time.elapsed());
+    for input in inputs.get_just_pressed() {
+        buffer.press(input.clone());
+    }
+}
+
+/// A press recorded in an [`InputBuffer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct BufferedPress<T> {
+    /// The input that was pressed.
+    pub input: T,
+    /// The elapsed [`Time<Real>`] at which it was pressed.
+    pub time: Duration,
+}
+
+/// The presses of `T` made within the last [`window`](InputBuffer::window), oldest first.
+///
+/// Presses are timestamped with the elapsed [`Time<Real>`], so buffering is not affected by
+/// pausing or scaling virtual time.
+#[derive(Debug, Clone, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct InputBuffer<T: Send + Sync + 'static> {
+    window: Duration,
+    now: Duration,
+    presses: VecDeque<BufferedPress<T>>,
+}
+
+impl<T: Send + Sync + 'static> Default for InputBuffer<T> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_WINDOW)
+    }
+}
+
+impl<T: Send + Sync + 'static> InputBuffer<T> {
+    /// The default [`window`](InputBuffer::window).
+    pub const DEFAULT_WINDOW: Duration = Duration::from_secs(1);
+
+    /// Creates an empty buffer that remembers presses for `window`.
+    ///
+    /// The window must be at least as long as the longest query made on the buffer, including
+    /// the full length of every [`InputSequence`] matched against it.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            now: Duration::ZERO,
+            presses: VecDeque::new(),
+        }
+    }
+
+    /// Returns how long presses are remembered.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Sets how long presses are remembered.
+    pub fn set_window(&mut self, window: Duration) {
+        self.window = window;
+    }
+
+    /// Returns the time the buffer was last [updated](InputBuffer::update) to.
+    pub fn now(&self) -> Duration {
+        self.now
+    }
+
+    /// Advances the buffer to `now` and forgets presses older than the window.
+    pub fn update(&mut self, now: Duration) {
+        self.now = now;
+        while self
+            .presses
+            .front()
+            .is_some_and(|press| now.saturating_sub(press.time) > self.window)
+        {
+            self.presses.pop_front();
+        }
+    }
+
+    /// Records a press of `input` at the current time.
+    pub fn press(&mut self, input: T) {
+        self.presses.push_back(BufferedPress {
+            input,
+            time: self.now,
+        });
+    }
+
+    /// Returns an iterator over the buffered presses, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &BufferedPress<T>> {
+        self.presses.iter()
+    }
+
+    /// Forgets every buffered press.
+    pub fn clear(&mut self) {
+        self.presses.clear();
+    }
+}
+
+impl<T> InputBuffer<T>
+where
+    T: PartialEq + Send + Sync + 'static,
+{
+    /// Returns `true` if `input` was pressed within the last `within`.
+    pub fn pressed_within(&self, input: T, within: Duration) -> bool {
+        self.last_press(input)
+            .is_some_and(|time| self.now.saturating_sub(time) <= within)
+    }
+
+    /// Returns when `input` was last pressed, if it is still buffered.
+    pub fn last_press(&self, input: T) -> Option<Duration> {
+        self.presses
+            .iter()
+            .rfind(|press| press.input == input)
+            .map(|press| press.time)
+    }
+
+    /// Forgets the latest press of `input` if it was made within the last `within`, and returns
+    /// whether it was.
+    ///
+    /// Use this to act on a buffered press only once.
+    pub fn consume(&mut self, input: T, within: Duration) -> bool {
+        let Some(index) = self.presses.iter().rposition(|press| press.input == input) else {
+            return false;
+        };
+        if self.now.saturating_sub(self.presses[index].time) > within {
+            return false;
+        }
+        self.presses.remove(index);
+        true
+    }
+
+    /// Returns the most recent match of `sequence` in the buffer.
+    ///
+    /// Other presses may be interleaved with the steps of the sequence, as long as each step
+    /// follows the previous one within its [`max_delay`](SequenceStep::max_delay).
+    pub fn find_sequence(&self, sequence: &InputSequence<T>) -> Option<SequenceMatch> {
+        let (last_step, earlier_steps) = sequence.steps.split_last()?;
+        let mut bound = self.presses.len();
+
+        'ends: loop {
+            let end = self.find_step(last_step, bound, sequence.chord_tolerance)?;
+            let mut current = end;
+            let mut max_delay = last_step.max_delay;
+            for step in earlier_steps.iter().rev() {
+                // Picking the latest match of every step leaves the most time for the steps
+                // before it, so a failure here means no match ends with `end`.
+                match self.find_step(step, current.first, sequence.chord_tolerance) {
+                    Some(step_match) if current.time - step_match.time <= max_delay => {
+                        current = step_match;
+                        max_delay = step.max_delay;
+                    }
+                    _ => {
+                        bound = end.last;
+                        continue 'ends;
+                    }
+                }
+            }
+
+            return Some(SequenceMatch {
+                start: self.presses[current.first].time,
+                end: end.time,
+                last: end.last,
+            });
+        }
+    }
+
+    /// Returns `true` if `sequence` was completed within the last `within`.
+    ///
+    /// With a `within` of [`Duration::ZERO`], this only returns `true` on the frame the sequence
+    /// is completed.
+    pub fn sequence_completed_within(&self, sequence: &InputSequence<T>, within: Duration) -> bool {
+        self.find_sequence(sequence)
+            .is_some_and(|found| self.now.saturating_sub(found.end) <= within)
+    }
+
+    /// Forgets the most recent match of `sequence` and every press before it, if it was
+    /// completed within the last `within`, and returns whether it was.
+    ///
+    /// Use this to act on a buffered sequence only once.
+    pub fn consume_sequence(&mut self, sequence: &InputSequence<T>, within: Duration) -> bool {
+        match self.find_sequence(sequence) {
+            Some(found) if self.now.saturating_sub(found.end) <= within => {
+                self.presses.drain(..=found.last);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Finds the latest match of `step` among the presses before `bound`.
+    fn find_step(
+        &self,
+        step: &SequenceStep<T>,
+        mut bound: usize,
+        chord_tolerance: Duration,
+    ) -> Option<StepMatch> {
+        if step.inputs.is_empty() {
+            return None;
+        }
+        loop {
+            let mut first = usize::MAX;
+            let mut last = 0;
+            for input in &step.inputs {
+                let index = self
+                    .presses
+                    .range(..bound)
+                    .rposition(|press| press.input == *input)?;
+                first = first.min(index);
+                last = last.max(index);
+            }
+            let time = self.presses[last].time;
+            if time - self.presses[first].time <= chord_tolerance {
+                return Some(StepMatch { first, last, time });
+            }
+            // The earliest input has no later press, so no chord can include the latest one.
+            bound = last;
+        }
+    }
+}
+
+/// The presses matching one [`SequenceStep`], as indices into the buffer.
+#[derive(Clone, Copy)]
+struct StepMatch {
+    first: usize,
+    last: usize,
+    time: Duration,
+}
+
+/// A match of an [`InputSequence`] found by [`InputBuffer::find_sequence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceMatch {
+    /// When the first press of the sequence was made.
+    pub start: Duration,
+    /// When the sequence was completed.
+    pub end: Duration,
+    last: usize,
+}
+
+/// One step of an [`InputSequence`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceStep<T> {
+    /// The inputs to press. Multiple inputs must be pressed together, within the sequence's
+    /// [`chord_tolerance`](InputSequence::chord_tolerance).
+    pub inputs: Vec<T>,
+    /// The longest time allowed between completing the previous step and completing this one.
+    ///
+    /// Ignored for the first step.
+    pub max_delay: Duration,
+}
+
+/// A sequence of presses to detect, such as a fighting game combo.
+///
+/// ```
+/// # use bevy_input::input_buffer::InputSequence;
+/// # use core::time::Duration;
+/// #[derive(Clone, PartialEq)]
+/// enum Move {
+///     Down,
+///     DownForward,
+///     Forward,
+///     Punch,
+/// }
+///
+/// // ↓↘→+P, with a tighter window for the final press.
+/// let fireball = InputSequence::new()
+///     .then(Move::Down)
+///     .then(Move::DownForward)
+///     .then_chord([Move::Forward, Move::Punch])
+///     .within(Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputSequence<T> {
+    /// The steps of the sequence, in order.
+    pub steps: Vec<SequenceStep<T>>,
+    /// The longest time allowed between the presses of a single step.
+    pub chord_tolerance: Duration,
+}
+
+impl<T> Default for InputSequence<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> InputSequence<T> {
+    /// The default [`max_delay`](SequenceStep::max_delay) of each step.
+    pub const DEFAULT_STEP_DELAY: Duration = Duration::from_millis(250);
+
+    /// The default [`chord_tolerance`](InputSequence::chord_tolerance).
+    pub const DEFAULT_CHORD_TOLERANCE: Duration = Duration::from_millis(50);
+
+    /// Creates an empty sequence, which never matches.
+    pub const fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            chord_tolerance: Self::DEFAULT_CHORD_TOLERANCE,
+        }
+    }
+
+    /// Adds a step pressing `input`.
+    pub fn then(self, input: T) -> Self {
+        self.then_chord([input])
+    }
+
+    /// Adds a step pressing all of `inputs` together.
+    pub fn then_chord(mut self, inputs: impl IntoIterator<Item = T>) -> Self {
+        self.steps.push(SequenceStep {
+            inputs: inputs.into_iter().collect(),
+            max_delay: Self::DEFAULT_STEP_DELAY,
+        });
+        self
+    }
+
+    /// Sets the [`max_delay`](SequenceStep::max_delay) of the last added step.
+    pub fn within(mut self, max_delay: Duration) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.max_delay = max_delay;
+        }
+        self
+    }
+
+    /// Sets the [`chord_tolerance`](InputSequence::chord_tolerance).
+    pub fn with_chord_tolerance(mut self, chord_tolerance: Duration) -> Self {
+        self.chord_tolerance = chord_tolerance;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_conditions::{input_pressed_within, input_sequence_completed};
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Move {
+        Down,
+        DownForward,
+        Forward,
+        Punch,
+        Kick,
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn buffered(presses: &[(u64, Move)]) -> InputBuffer<Move> {
+        let mut buffer = InputBuffer::default();
+        for &(time, input) in presses {
+            buffer.update(ms(time));
+            buffer.press(input);
+        }
+        buffer
+    }
+
+    #[test]
+    fn buffered_presses() {
+        let mut buffer = buffered(&[(0, Move::Punch), (100, Move::Kick)]);
+        buffer.update(ms(150));
+        assert!(buffer.pressed_within(Move::Punch, ms(150)));
+        assert!(!buffer.pressed_within(Move::Punch, ms(120)));
+        assert!(!buffer.consume(Move::Punch, ms(120)));
+        assert!(buffer.consume(Move::Kick, ms(120)));
+        assert!(!buffer.consume(Move::Kick, ms(120)));
+
+        buffer.update(ms(1100));
+        assert_eq!(buffer.iter().count(), 0);
+    }
+
+    #[test]
+    fn sequences() {
+        let fireball = InputSequence::new()
+            .then(Move::Down)
+            .then(Move::DownForward)
+            .then_chord([Move::Forward, Move::Punch])
+            .within(ms(100));
+
+        // Interleaved presses are allowed, and the chord can be pressed in any order.
+        let buffer = buffered(&[
+            (0, Move::Down),
+            (50, Move::Kick),
+            (100, Move::DownForward),
+            (180, Move::Punch),
+            (200, Move::Forward),
+        ]);
+        assert_eq!(
+            buffer.find_sequence(&fireball),
+            Some(SequenceMatch {
+                start: ms(0),
+                end: ms(200),
+                last: 4,
+            })
+        );
+        assert!(buffer.sequence_completed_within(&fireball, Duration::ZERO));
+
+        // The final step is too slow.
+        let buffer = buffered(&[
+            (0, Move::Down),
+            (100, Move::DownForward),
+            (250, Move::Forward),
+            (250, Move::Punch),
+        ]);
+        assert_eq!(buffer.find_sequence(&fireball), None);
+
+        // The chord of the first attempt is too far apart, the second attempt matches.
+        let mut buffer = buffered(&[
+            (0, Move::Down),
+            (50, Move::DownForward),
+            (60, Move::Forward),
+            (140, Move::Punch),
+            (200, Move::Down),
+            (300, Move::DownForward),
+            (350, Move::Punch),
+            (360, Move::Forward),
+        ]);
+        let found = buffer.find_sequence(&fireball).unwrap();
+        assert_eq!((found.start, found.end), (ms(200), ms(360)));
+        assert!(buffer.consume_sequence(&fireball, Duration::ZERO));
+        assert_eq!(buffer.find_sequence(&fireball), None);
+    }
+
+    #[test]
+    fn buffers_button_input() {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .add_plugins(InputBufferPlugin::<Move>::default());
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(Duration::ZERO);
+
+        let combo = InputSequence::new().then(Move::Down).then(Move::Punch);
+        let mut update = |millis, input| {
+            let world = app.world_mut();
+            world
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(ms(millis));
+            let mut inputs = world.resource_mut::<ButtonInput<Move>>();
+            inputs.reset_all();
+            inputs.press(input);
+            app.update();
+            let world = app.world_mut();
+            (
+                world
+                    .run_system_once(input_pressed_within(Move::Down, ms(100)))
+                    .unwrap(),
+                world
+                    .run_system_once(input_sequence_completed(combo.clone()))
+                    .unwrap(),
+            )
+        };
+        assert_eq!(update(0, Move::Down), (true, false));
+        assert_eq!(update(80, Move::Punch), (true, true));
+        assert_eq!(update(80, Move::Kick), (false, false));
+    }
+}
@@ -17,6 +17,9 @@ extern crate std;
 
 extern crate alloc;
 
+#[cfg(feature = "action")]
+pub mod action;
+
 mod axis;
 mod button_input;
 /// Common run conditions
@@ -25,9 +28,15 @@ pub mod common_conditions;
 #[cfg(feature = "gamepad")]
 pub mod gamepad;
 
+#[cfg(feature = "gamepad")]
+pub mod gamepad_mapping;
+
 #[cfg(feature = "gestures")]
 pub mod gestures;
 
+#[cfg(feature = "input_buffer")]
+pub mod input_buffer;
+
 #[cfg(feature = "keyboard")]
 pub mod keyboard;
 
@@ -38,6 +47,9 @@ pub mod mouse;
 #[cfg(any(feature = "touch", feature = "mouse"))]
 pub mod touch;
 
+#[cfg(feature = "touch_gestures")]
+pub mod touch_gestures;
+
 pub use axis::*;
 pub use button_input::*;
 
@@ -93,6 +105,8 @@ use gamepad::{
     GamepadEvent, GamepadRumbleRequest, RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent,
     RawGamepadEvent,
 };
+#[cfg(feature = "gamepad")]
+use gamepad_mapping::{store_gamepad_calibrations, GamepadCalibrations, GamepadMappings};
 
 #[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -105,10 +119,18 @@ pub struct InputPlugin;
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub struct InputSystems;
 
+/// Label for systems of input backends, such as `bevy_gilrs`, that write raw input messages during [`PreUpdate`].
+///
+/// This runs before [`InputSystems`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct InputBackendSystems;
+
 impl Plugin for InputPlugin {
     #[expect(clippy::allow_attributes, reason = "this is only sometimes unused")]
     #[allow(unused, reason = "all features could be disabled")]
     fn build(&self, app: &mut App) {
+        app.configure_sets(PreUpdate, InputBackendSystems.before(InputSystems));
+
         #[cfg(feature = "keyboard")]
         app.add_message::<KeyboardInput>()
             .add_message::<KeyboardFocusLost>()
@@ -150,9 +172,12 @@ impl Plugin for InputPlugin {
             .add_message::<RawGamepadAxisChangedEvent>()
             .add_message::<RawGamepadButtonChangedEvent>()
             .add_message::<GamepadRumbleRequest>()
+            .init_resource::<GamepadMappings>()
+            .init_resource::<GamepadCalibrations>()
             .add_systems(
                 PreUpdate,
                 (
+                    store_gamepad_calibrations.before(gamepad_connection_system),
                     gamepad_connection_system,
                     gamepad_event_processing_system.after(gamepad_connection_system),
                 )
@@ -0,0 +1,1207 @@
+//! Gestures recognized from raw touch input, on every platform.
+//!
+//! [`TouchGesturePlugin`] reads [`TouchInput`] messages and writes a [`TouchGesture`] message for every tap,
+//! double tap, long press, swipe, pan, pinch and rotation it recognizes. Unlike the platform gestures of
+//! the `gestures` module, which most platforms never emit, these are available wherever touch input is.
+//!
+//! The thresholds of every recognizer, and how recognizers that compete for the same touches are resolved,
+//! are configured with the [`TouchGestureSettings`] resource:
+//!
+//! - A tap that is followed by a second one becomes a double tap. By default, the first tap is still sent
+//!   right away; with [`TapSettings::wait_for_double_tap`], taps are delayed until a double tap is no longer
+//!   possible, so a double tap never sends a tap.
+//! - A long press prevents the tap or swipe that would be recognized when the finger is lifted.
+//! - A one finger pan waits until a swipe is no longer possible: it starts once the finger has been pressed
+//!   for longer than [`SwipeSettings::max_duration`], or is sent when the finger is lifted without swiping.
+//! - Pans, pinches and rotations are continuous, with a [`GesturePhase`]. They run simultaneously, unless one
+//!   of them is [`exclusive`](PinchSettings::exclusive): an exclusive gesture only starts if no other
+//!   continuous gesture is active, and prevents the others from starting while it is active. A continuous
+//!   gesture ends when fewer fingers remain than it needs.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::touch_gestures::{TouchGesture, GesturePhase};
+//! fn zoom_camera(mut gestures: MessageReader<TouchGesture>) {
+//!     for gesture in gestures.read() {
+//!         match gesture {
+//!             TouchGesture::Pinch { scale, .. } => {
+//!                 // Zoom in when the fingers move apart.
+//!             }
+//!             TouchGesture::DoubleTap { position, .. } => {
+//!                 // Reset the zoom.
+//!             }
+//!             _ => {}
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{
+    touch::{TouchInput, TouchPhase},
+    InputSystems,
+};
+use alloc::vec::Vec;
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_math::{ops, Vec2};
+use bevy_time::{Real, Time};
+use core::{f32::consts::PI, time::Duration};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// Recognizes [`TouchGesture`]s from [`TouchInput`] messages, see the [module docs](self).
+///
+/// This requires the [`Time<Real>`] resource, which is added by the `TimePlugin`.
+#[derive(Default)]
+pub struct TouchGesturePlugin;
+
+impl Plugin for TouchGesturePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchGestureSettings>()
+            .add_message::<TouchInput>()
+            .add_message::<TouchGesture>()
+            .configure_sets(PreUpdate, TouchGestureSystems.after(InputSystems))
+            .add_systems(
+                PreUpdate,
+                recognize_touch_gestures.in_set(TouchGestureSystems),
+            );
+    }
+}
+
+/// Label for the system that recognizes [`TouchGesture`]s.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct TouchGestureSystems;
+
+/// The phase of a continuous [`TouchGesture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GesturePhase {
+    /// The gesture was recognized. The message includes the change since the fingers started moving.
+    Started,
+    /// The gesture changed since the last message.
+    Changed,
+    /// The fingers performing the gesture were lifted.
+    Ended,
+    /// A touch performing the gesture was canceled by the platform.
+    Canceled,
+}
+
+/// The main direction of a [`TouchGesture::Swipe`], on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum SwipeDirection {
+    /// Towards the left of the screen.
+    Left,
+    /// Towards the right of the screen.
+    Right,
+    /// Towards the top of the screen.
+    Up,
+    /// Towards the bottom of the screen.
+    Down,
+}
+
+impl SwipeDirection {
+    /// Returns the direction of the main axis of `delta`, in screen coordinates where `y` points down.
+    pub fn from_delta(delta: Vec2) -> Self {
+        if delta.x.abs() >= delta.y.abs() {
+            if delta.x < 0.0 {
+                Self::Left
+            } else {
+                Self::Right
+            }
+        } else if delta.y < 0.0 {
+            Self::Up
+        } else {
+            Self::Down
+        }
+    }
+}
+
+/// A gesture recognized by the [`TouchGesturePlugin`].
+///
+/// Positions are in the same logical pixels as [`TouchInput::position`], and `window` is the window of the first
+/// finger of the gesture.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone, Message)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum TouchGesture {
+    /// A single finger was pressed and lifted quickly, without moving.
+    Tap {
+        /// The window that was tapped.
+        window: Entity,
+        /// Where the finger was lifted.
+        position: Vec2,
+    },
+    /// Two taps in quick succession, close to each other.
+    DoubleTap {
+        /// The window that was tapped.
+        window: Entity,
+        /// Where the finger was lifted the second time.
+        position: Vec2,
+    },
+    /// A single finger was held in place. This is sent while the finger is still pressed.
+    LongPress {
+        /// The window that was pressed.
+        window: Entity,
+        /// Where the finger is.
+        position: Vec2,
+    },
+    /// A single finger moved quickly and was lifted.
+    Swipe {
+        /// The window that was swiped.
+        window: Entity,
+        /// The main direction of the swipe.
+        direction: SwipeDirection,
+        /// Where the finger was pressed.
+        start: Vec2,
+        /// Where the finger was lifted.
+        end: Vec2,
+        /// The average velocity of the finger, in logical pixels per second.
+        velocity: Vec2,
+    },
+    /// One or more fingers moved together.
+    Pan {
+        /// The window that was panned.
+        window: Entity,
+        /// The phase of the gesture.
+        phase: GesturePhase,
+        /// The center of the fingers.
+        position: Vec2,
+        /// How much the center of the fingers moved.
+        delta: Vec2,
+        /// The number of fingers.
+        touches: usize,
+    },
+    /// Two or more fingers moved apart or together.
+    Pinch {
+        /// The window that was pinched.
+        window: Entity,
+        /// The phase of the gesture.
+        phase: GesturePhase,
+        /// The center of the fingers.
+        center: Vec2,
+        /// The ratio of the current spread of the fingers to the previous one. Above one when the fingers
+        /// move apart, which usually zooms in.
+        scale: f32,
+    },
+    /// Two or more fingers turned around their center.
+    Rotate {
+        /// The window that was rotated.
+        window: Entity,
+        /// The phase of the gesture.
+        phase: GesturePhase,
+        /// The center of the fingers.
+        center: Vec2,
+        /// How much the fingers turned, in radians. Positive values are counterclockwise on the screen.
+        angle: f32,
+    },
+}
+
+/// Configures the recognizers of the [`TouchGesturePlugin`].
+///
+/// Distances are in logical pixels.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default, PartialEq, Clone)
+)]
+pub struct TouchGestureSettings {
+    /// Configures [`TouchGesture::Tap`].
+    pub tap: TapSettings,
+    /// Configures [`TouchGesture::DoubleTap`].
+    pub double_tap: DoubleTapSettings,
+    /// Configures [`TouchGesture::LongPress`].
+    pub long_press: LongPressSettings,
+    /// Configures [`TouchGesture::Swipe`].
+    pub swipe: SwipeSettings,
+    /// Configures [`TouchGesture::Pan`].
+    pub pan: PanSettings,
+    /// Configures [`TouchGesture::Pinch`].
+    pub pinch: PinchSettings,
+    /// Configures [`TouchGesture::Rotate`].
+    pub rotate: RotateSettings,
+}
+
+/// Configures [`TouchGesture::Tap`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct TapSettings {
+    /// Whether taps are recognized.
+    pub enabled: bool,
+    /// How far the finger can move before it is no longer a tap.
+    pub max_distance: f32,
+    /// How long the finger can be pressed before it is no longer a tap.
+    pub max_duration: Duration,
+    /// When set to `true`, and double taps are enabled, taps are only sent once a double tap is no longer
+    /// possible, so the taps of a double tap are not sent.
+    pub wait_for_double_tap: bool,
+}
+
+impl Default for TapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_distance: 10.0,
+            max_duration: Duration::from_millis(300),
+            wait_for_double_tap: false,
+        }
+    }
+}
+
+/// Configures [`TouchGesture::DoubleTap`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct DoubleTapSettings {
+    /// Whether double taps are recognized.
+    pub enabled: bool,
+    /// The longest time between the two taps.
+    pub max_interval: Duration,
+    /// The longest distance between the two taps.
+    pub max_distance: f32,
+}
+
+impl Default for DoubleTapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_interval: Duration::from_millis(300),
+            max_distance: 40.0,
+        }
+    }
+}
+
+/// Configures [`TouchGesture::LongPress`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct LongPressSettings {
+    /// Whether long presses are recognized.
+    pub enabled: bool,
+    /// How long the finger must be pressed.
+    pub duration: Duration,
+    /// How far the finger can move before it is no longer a long press.
+    pub max_distance: f32,
+}
+
+impl Default for LongPressSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration: Duration::from_millis(500),
+            max_distance: 10.0,
+        }
+    }
+}
+
+/// Configures [`TouchGesture::Swipe`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct SwipeSettings {
+    /// Whether swipes are recognized.
+    pub enabled: bool,
+    /// How far the finger must move.
+    pub min_distance: f32,
+    /// How fast the finger must move on average, in logical pixels per second.
+    pub min_velocity: f32,
+    /// How long the finger can be pressed before it is no longer a swipe.
+    pub max_duration: Duration,
+}
+
+impl Default for SwipeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_distance: 50.0,
+            min_velocity: 300.0,
+            max_duration: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Configures [`TouchGesture::Pan`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct PanSettings {
+    /// Whether pans are recognized.
+    pub enabled: bool,
+    /// How far the center of the fingers must move before the pan starts.
+    pub min_distance: f32,
+    /// How many fingers are needed to pan.
+    pub min_touches: usize,
+    /// Whether this gesture prevents pinches and rotations while it is active.
+    pub exclusive: bool,
+}
+
+impl Default for PanSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_distance: 10.0,
+            min_touches: 1,
+            exclusive: false,
+        }
+    }
+}
+
+/// Configures [`TouchGesture::Pinch`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct PinchSettings {
+    /// Whether pinches are recognized.
+    pub enabled: bool,
+    /// How much the spread of the fingers must change before the pinch starts, relative to the spread when
+    /// they started moving.
+    pub min_scale_change: f32,
+    /// Whether this gesture prevents pans and rotations while it is active.
+    pub exclusive: bool,
+}
+
+impl Default for PinchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_scale_change: 0.1,
+            exclusive: false,
+        }
+    }
+}
+
+/// Configures [`TouchGesture::Rotate`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Clone)
+)]
+pub struct RotateSettings {
+    /// Whether rotations are recognized.
+    pub enabled: bool,
+    /// How much the fingers must turn before the rotation starts, in radians.
+    pub min_angle: f32,
+    /// Whether this gesture prevents pans and pinches while it is active.
+    pub exclusive: bool,
+}
+
+impl Default for RotateSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_angle: 0.15,
+            exclusive: false,
+        }
+    }
+}
+
+/// Recognizes gestures from the [`TouchInput`] messages of this update.
+fn recognize_touch_gestures(
+    settings: Res<TouchGestureSettings>,
+    time: Res<Time<Real>>,
+    mut touch_inputs: MessageReader<TouchInput>,
+    mut gestures: MessageWriter<TouchGesture>,
+    mut recognizer: Local<Recognizer>,
+    mut recognized: Local<Vec<TouchGesture>>,
+) {
+    let now = time.elapsed();
+    for input in touch_inputs.read() {
+        recognizer.process(input, now, &settings, &mut recognized);
+    }
+    recognizer.update_continuous(now, &settings, &mut recognized);
+    recognizer.update_timers(now, &settings, &mut recognized);
+    gestures.write_batch(recognized.drain(..));
+}
+
+/// A finger that is currently pressed.
+#[derive(Clone, Copy)]
+struct TrackedTouch {
+    id: u64,
+    start: Vec2,
+    position: Vec2,
+}
+
+/// The touches from the first finger pressed to the last finger lifted.
+struct Session {
+    window: Entity,
+    start_time: Duration,
+    max_touches: usize,
+    /// The farthest any finger moved from where it was pressed.
+    max_travel: f32,
+    canceled: bool,
+    long_pressed: bool,
+}
+
+/// The fingers of a session during one update, used to compute continuous gestures.
+struct Snapshot {
+    ids: Vec<u64>,
+    centroid: Vec2,
+    /// The average distance of the fingers to their centroid.
+    spread: f32,
+    /// The angle from the first finger to the second one, if there are two.
+    angle: f32,
+}
+
+impl Snapshot {
+    fn new(touches: &[TrackedTouch]) -> Self {
+        let centroid =
+            touches.iter().map(|touch| touch.position).sum::<Vec2>() / touches.len() as f32;
+        let spread = touches
+            .iter()
+            .map(|touch| touch.position.distance(centroid))
+            .sum::<f32>()
+            / touches.len() as f32;
+        let angle = match touches {
+            [first, second, ..] => {
+                let offset = second.position - first.position;
+                // Negated, because `y` points down on the screen.
+                -ops::atan2(offset.y, offset.x)
+            }
+            _ => 0.0,
+        };
+        Self {
+            ids: touches.iter().map(|touch| touch.id).collect(),
+            centroid,
+            spread,
+            angle,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Continuous {
+    Pan,
+    Pinch,
+    Rotate,
+}
+
+/// The state of the recognizers between updates.
+#[derive(Default)]
+struct Recognizer {
+    touches: Vec<TrackedTouch>,
+    session: Option<Session>,
+    snapshot: Option<Snapshot>,
+    /// The time and position of the last tap, which may become a double tap.
+    last_tap: Option<(Duration, Vec2)>,
+    /// A tap waiting for a double tap to become impossible.
+    pending_tap: Option<(Duration, Entity, Vec2)>,
+    pan: Option<Vec2>,
+    /// Whether a pan started during the current session.
+    pan_started: bool,
+    pinch: Option<f32>,
+    rotate: Option<f32>,
+    /// The gestures that have started and not ended, in the order they started.
+    active: Vec<Continuous>,
+}
+
+impl Recognizer {
+    fn process(
+        &mut self,
+        input: &TouchInput,
+        now: Duration,
+        settings: &TouchGestureSettings,
+        recognized: &mut Vec<TouchGesture>,
+    ) {
+        match input.phase {
+            TouchPhase::Started => {
+                if self.touches.is_empty() {
+                    self.session = Some(Session {
+                        window: input.window,
+                        start_time: now,
+                        max_touches: 0,
+                        max_travel: 0.0,
+                        canceled: false,
+                        long_pressed: false,
+                    });
+                    self.pan = None;
+                    self.pan_started = false;
+                    self.pinch = None;
+                    self.rotate = None;
+                }
+                self.touches.push(TrackedTouch {
+                    id: input.id,
+                    start: input.position,
+                    position: input.position,
+                });
+                if let Some(session) = &mut self.session {
+                    session.max_touches = session.max_touches.max(self.touches.len());
+                }
+            }
+            TouchPhase::Moved => {
+                if let Some(touch) = self.touches.iter_mut().find(|touch| touch.id == input.id) {
+                    touch.position = input.position;
+                    if let Some(session) = &mut self.session {
+                        session.max_travel =
+                            session.max_travel.max(touch.position.distance(touch.start));
+                    }
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                let Some(index) = self.touches.iter().position(|touch| touch.id == input.id) else {
+                    return;
+                };
+                let mut touch = self.touches.remove(index);
+                touch.position = input.position;
+                let Some(session) = &mut self.session else {
+                    return;
+                };
+                session.max_travel = session.max_travel.max(touch.position.distance(touch.start));
+                session.canceled |= input.phase == TouchPhase::Canceled;
+                if self.touches.is_empty() {
+                    self.finish_session(touch, now, settings, recognized);
+                }
+            }
+        }
+    }
+
+    /// Ends the continuous gestures, and recognizes a tap or a swipe, when the last finger is lifted.
+    fn finish_session(
+        &mut self,
+        last: TrackedTouch,
+        now: Duration,
+        settings: &TouchGestureSettings,
+        recognized: &mut Vec<TouchGesture>,
+    ) {
+        let Some(session) = self.session.take() else {
+            return;
+        };
+        let phase = if session.canceled {
+            GesturePhase::Canceled
+        } else {
+            GesturePhase::Ended
+        };
+        let center = self
+            .snapshot
+            .take()
+            .map_or(last.position, |snapshot| snapshot.centroid);
+        for gesture in core::mem::take(&mut self.active) {
+            recognized.push(end_message(gesture, session.window, phase, center, 0));
+        }
+        if session.canceled || session.long_pressed || session.max_touches != 1 {
+            return;
+        }
+
+        let duration = now - session.start_time;
+        if session.max_travel <= settings.tap.max_distance && duration <= settings.tap.max_duration
+        {
+            self.tap(session.window, last.position, now, settings, recognized);
+            return;
+        }
+
+        let delta = last.position - last.start;
+        // Touches that start and end in the same update still move over some time.
+        let seconds = duration.as_secs_f32().max(0.001);
+        let velocity = delta / seconds;
+        if settings.swipe.enabled
+            && delta.length() >= settings.swipe.min_distance
+            && duration <= settings.swipe.max_duration
+            && velocity.length() >= settings.swipe.min_velocity
+        {
+            recognized.push(TouchGesture::Swipe {
+                window: session.window,
+                direction: SwipeDirection::from_delta(delta),
+                start: last.start,
+                end: last.position,
+                velocity,
+            });
+        } else if !self.pan_started
+            && settings.pan.enabled
+            && settings.pan.min_touches <= 1
+            && delta.length() >= settings.pan.min_distance
+        {
+            // The pan waited for a swipe that did not happen.
+            recognized.push(TouchGesture::Pan {
+                window: session.window,
+                phase: GesturePhase::Started,
+                position: last.position,
+                delta,
+                touches: 1,
+            });
+            recognized.push(end_message(
+                Continuous::Pan,
+                session.window,
+                GesturePhase::Ended,
+                last.position,
+                0,
+            ));
+        }
+    }
+
+    fn tap(
+        &mut self,
+        window: Entity,
+        position: Vec2,
+        now: Duration,
+        settings: &TouchGestureSettings,
+        recognized: &mut Vec<TouchGesture>,
+    ) {
+        if settings.double_tap.enabled
+            && let Some((time, last_position)) = self.last_tap
+            && now - time <= settings.double_tap.max_interval
+            && position.distance(last_position) <= settings.double_tap.max_distance
+        {
+            self.last_tap = None;
+            self.pending_tap = None;
+            recognized.push(TouchGesture::DoubleTap { window, position });
+            return;
+        }
+
+        // This tap is not the second one of the pending tap, which can be sent now.
+        if let Some((_, window, position)) = self.pending_tap.take() {
+            recognized.push(TouchGesture::Tap { window, position });
+        }
+        if settings.double_tap.enabled {
+            self.last_tap = Some((now, position));
+        }
+        if !settings.tap.enabled {
+            return;
+        }
+        if settings.double_tap.enabled && settings.tap.wait_for_double_tap {
+            self.pending_tap = Some((now, window, position));
+        } else {
+            recognized.push(TouchGesture::Tap { window, position });
+        }
+    }
+
+    /// Returns `true` if the fingers pressed so far may still be a swipe when they are lifted.
+    fn may_swipe(&self, now: Duration, settings: &TouchGestureSettings) -> bool {
+        settings.swipe.enabled
+            && self.session.as_ref().is_some_and(|session| {
+                session.max_touches == 1
+                    && !session.long_pressed
+                    && now - session.start_time <= settings.swipe.max_duration
+            })
+    }
+
+    /// Updates pans, pinches and rotations with how the fingers moved since the last update.
+    fn update_continuous(
+        &mut self,
+        now: Duration,
+        settings: &TouchGestureSettings,
+        recognized: &mut Vec<TouchGesture>,
+    ) {
+        let Some(session) = &self.session else {
+            return;
+        };
+        let window = session.window;
+        let current = Snapshot::new(&self.touches);
+        let touches = self.touches.len();
+
+        // Gestures whose fingers were lifted end, even if other fingers remain.
+        let center = current.centroid;
+        self.active.retain(|&gesture| {
+            let required = match gesture {
+                Continuous::Pan => settings.pan.min_touches,
+                Continuous::Pinch | Continuous::Rotate => 2,
+            };
+            let keep = touches >= required;
+            if !keep {
+                recognized.push(end_message(
+                    gesture,
+                    window,
+                    GesturePhase::Ended,
+                    center,
+                    touches,
+                ));
+            }
+            keep
+        });
+
+        // When fingers are pressed or lifted, the centroid jumps, so the movement starts over from here.
+        let Some(previous) = self.snapshot.replace(current) else {
+            return;
+        };
+        let current = self.snapshot.as_ref().unwrap();
+        if previous.ids != current.ids {
+            return;
+        }
+        let delta = current.centroid - previous.centroid;
+        let scale = if previous.spread > 0.0 {
+            current.spread / previous.spread
+        } else {
+            1.0
+        };
+        let angle = ops::rem_euclid(current.angle - previous.angle + PI, 2.0 * PI) - PI;
+
+        if settings.pan.enabled && touches >= settings.pan.min_touches {
+            if self.active.contains(&Continuous::Pan) {
+                if delta != Vec2::ZERO {
+                    recognized.push(TouchGesture::Pan {
+                        window,
+                        phase: GesturePhase::Changed,
+                        position: center,
+                        delta,
+                        touches,
+                    });
+                }
+            } else {
+                let accumulated = self.pan.unwrap_or_default() + delta;
+                self.pan = Some(accumulated);
+                if accumulated.length() >= settings.pan.min_distance
+                    && !self.may_swipe(now, settings)
+                    && self.can_start(Continuous::Pan, settings)
+                {
+                    self.active.push(Continuous::Pan);
+                    self.pan_started = true;
+                    recognized.push(TouchGesture::Pan {
+                        window,
+                        phase: GesturePhase::Started,
+                        position: center,
+                        delta: accumulated,
+                        touches,
+                    });
+                }
+            }
+        }
+
+        if settings.pinch.enabled && touches >= 2 {
+            if self.active.contains(&Continuous::Pinch) {
+                if scale != 1.0 {
+                    recognized.push(TouchGesture::Pinch {
+                        window,
+                        phase: GesturePhase::Changed,
+                        center,
+                        scale,
+                    });
+                }
+            } else {
+                let accumulated = self.pinch.unwrap_or(1.0) * scale;
+                self.pinch = Some(accumulated);
+                if (accumulated - 1.0).abs() >= settings.pinch.min_scale_change
+                    && self.can_start(Continuous::Pinch, settings)
+                {
+                    self.active.push(Continuous::Pinch);
+                    recognized.push(TouchGesture::Pinch {
+                        window,
+                        phase: GesturePhase::Started,
+                        center,
+                        scale: accumulated,
+                    });
+                }
+            }
+        }
+
+        if settings.rotate.enabled && touches >= 2 {
+            if self.active.contains(&Continuous::Rotate) {
+                if angle != 0.0 {
+                    recognized.push(TouchGesture::Rotate {
+                        window,
+                        phase: GesturePhase::Changed,
+                        center,
+                        angle,
+                    });
+                }
+            } else {
+                let accumulated = self.rotate.unwrap_or_default() + angle;
+                self.rotate = Some(accumulated);
+                if accumulated.abs() >= settings.rotate.min_angle
+                    && self.can_start(Continuous::Rotate, settings)
+                {
+                    self.active.push(Continuous::Rotate);
+                    recognized.push(TouchGesture::Rotate {
+                        window,
+                        phase: GesturePhase::Started,
+                        center,
+                        angle: accumulated,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `gesture` is not prevented by an exclusive gesture, and does not need to be exclusive
+    /// itself while others are active.
+    fn can_start(&self, gesture: Continuous, settings: &TouchGestureSettings) -> bool {
+        let exclusive = |gesture| match gesture {
+            Continuous::Pan => settings.pan.exclusive,
+            Continuous::Pinch => settings.pinch.exclusive,
+            Continuous::Rotate => settings.rotate.exclusive,
+        };
+        if exclusive(gesture) {
+            self.active.is_empty()
+        } else {
+            !self.active.iter().any(|&active| exclusive(active))
+        }
+    }
+
+    /// Sends long presses and delayed taps when their time comes.
+    fn update_timers(
+        &mut self,
+        now: Duration,
+        settings: &TouchGestureSettings,
+        recognized: &mut Vec<TouchGesture>,
+    ) {
+        if let Some((time, window, position)) = self.pending_tap
+            && now - time > settings.double_tap.max_interval
+        {
+            self.pending_tap = None;
+            recognized.push(TouchGesture::Tap { window, position });
+        }
+
+        let Some(session) = &mut self.session else {
+            return;
+        };
+        if settings.long_press.enabled
+            && !session.long_pressed
+            && !session.canceled
+            && session.max_touches == 1
+            && session.max_travel <= settings.long_press.max_distance
+            && now - session.start_time >= settings.long_press.duration
+            && let [touch] = self.touches.as_slice()
+        {
+            session.long_pressed = true;
+            recognized.push(TouchGesture::LongPress {
+                window: session.window,
+                position: touch.position,
+            });
+        }
+    }
+}
+
+/// Returns the message ending a continuous gesture, without any change.
+fn end_message(
+    gesture: Continuous,
+    window: Entity,
+    phase: GesturePhase,
+    center: Vec2,
+    touches: usize,
+) -> TouchGesture {
+    match gesture {
+        Continuous::Pan => TouchGesture::Pan {
+            window,
+            phase,
+            position: center,
+            delta: Vec2::ZERO,
+            touches,
+        },
+        Continuous::Pinch => TouchGesture::Pinch {
+            window,
+            phase,
+            center,
+            scale: 1.0,
+        },
+        Continuous::Rotate => TouchGesture::Rotate {
+            window,
+            phase,
+            center,
+            angle: 0.0,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use bevy_app::App;
+
+    struct Harness {
+        app: App,
+        window: Entity,
+    }
+
+    impl Harness {
+        fn new(settings: TouchGestureSettings) -> Self {
+            let mut app = App::new();
+            app.insert_resource(Time::<Real>::default())
+                .insert_resource(settings)
+                .add_plugins(TouchGesturePlugin);
+            let window = app.world_mut().spawn_empty().id();
+            // The first update of `Time<Real>` only starts the clock.
+            app.world_mut()
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(Duration::ZERO);
+            Self { app, window }
+        }
+
+        /// Advances time by `millis`, sends the touches as `(phase, id, position)`, and returns the gestures
+        /// recognized in the update.
+        fn update(
+            &mut self,
+            millis: u64,
+            touches: &[(TouchPhase, u64, Vec2)],
+        ) -> Vec<TouchGesture> {
+            self.app
+                .world_mut()
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(Duration::from_millis(millis));
+            for &(phase, id, position) in touches {
+                self.app.world_mut().write_message(TouchInput {
+                    phase,
+                    position,
+                    window: self.window,
+                    force: None,
+                    id,
+                });
+            }
+            self.app.update();
+            self.app
+                .world_mut()
+                .resource_mut::<Messages<TouchGesture>>()
+                .drain()
+                .collect()
+        }
+    }
+
+    use TouchPhase::{Ended, Moved, Started};
+
+    #[test]
+    fn taps_and_double_taps() {
+        let mut harness = Harness::new(TouchGestureSettings::default());
+        let window = harness.window;
+        let position = Vec2::new(100.0, 100.0);
+
+        assert!(harness.update(0, &[(Started, 0, position)]).is_empty());
+        let gestures = harness.update(50, &[(Ended, 0, position)]);
+        assert_eq!(gestures, vec![TouchGesture::Tap { window, position }]);
+
+        harness.update(100, &[(Started, 1, position)]);
+        let gestures = harness.update(50, &[(Ended, 1, position)]);
+        assert_eq!(gestures, vec![TouchGesture::DoubleTap { window, position }]);
+
+        // When waiting for double taps, the taps of a double tap are not sent.
+        let mut settings = TouchGestureSettings::default();
+        settings.tap.wait_for_double_tap = true;
+        let mut harness = Harness::new(settings);
+        let window = harness.window;
+        harness.update(0, &[(Started, 0, position), (Ended, 0, position)]);
+        harness.update(100, &[(Started, 1, position)]);
+        let gestures = harness.update(50, &[(Ended, 1, position)]);
+        assert_eq!(gestures, vec![TouchGesture::DoubleTap { window, position }]);
+
+        harness.update(1000, &[(Started, 2, position), (Ended, 2, position)]);
+        assert!(harness.update(100, &[]).is_empty());
+        let gestures = harness.update(250, &[]);
+        assert_eq!(gestures, vec![TouchGesture::Tap { window, position }]);
+    }
+
+    #[test]
+    fn long_presses_and_swipes() {
+        let mut harness = Harness::new(TouchGestureSettings::default());
+        let window = harness.window;
+        let position = Vec2::new(100.0, 100.0);
+
+        harness.update(0, &[(Started, 0, position)]);
+        assert!(harness.update(400, &[]).is_empty());
+        let gestures = harness.update(200, &[]);
+        assert_eq!(gestures, vec![TouchGesture::LongPress { window, position }]);
+        assert!(harness.update(100, &[(Ended, 0, position)]).is_empty());
+
+        let end = position + Vec2::new(0.0, -100.0);
+        harness.update(1000, &[(Started, 1, position)]);
+        harness.update(50, &[(Moved, 1, position.midpoint(end))]);
+        let gestures = harness.update(50, &[(Moved, 1, end), (Ended, 1, end)]);
+        // The swipe is not also a pan.
+        let [TouchGesture::Swipe {
+            direction,
+            velocity,
+            ..
+        }] = gestures[..]
+        else {
+            panic!("expected a swipe, got {gestures:?}");
+        };
+        assert_eq!(direction, SwipeDirection::Up);
+        assert!((velocity.y + 1000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn one_finger_pans_wait_for_swipes() {
+        let mut harness = Harness::new(TouchGestureSettings::default());
+        let window = harness.window;
+        let position = Vec2::new(100.0, 100.0);
+
+        // The pan starts once the finger has been pressed for too long to swipe.
+        harness.update(0, &[(Started, 0, position)]);
+        let gestures = harness.update(100, &[(Moved, 0, position + Vec2::new(30.0, 0.0))]);
+        assert!(gestures.is_empty(), "{gestures:?}");
+        let moved = position + Vec2::new(60.0, 0.0);
+        let gestures = harness.update(500, &[(Moved, 0, moved)]);
+        assert_eq!(
+            gestures,
+            vec![TouchGesture::Pan {
+                window,
+                phase: GesturePhase::Started,
+                position: moved,
+                delta: Vec2::new(60.0, 0.0),
+                touches: 1,
+            }]
+        );
+        harness.update(100, &[(Ended, 0, moved)]);
+
+        // A drag that is too slow to swipe is sent as a pan when the finger is lifted.
+        let end = position + Vec2::new(20.0, 0.0);
+        harness.update(1000, &[(Started, 1, position)]);
+        let gestures = harness.update(100, &[(Moved, 1, end), (Ended, 1, end)]);
+        assert_eq!(
+            gestures,
+            vec![
+                TouchGesture::Pan {
+                    window,
+                    phase: GesturePhase::Started,
+                    position: end,
+                    delta: Vec2::new(20.0, 0.0),
+                    touches: 1,
+                },
+                TouchGesture::Pan {
+                    window,
+                    phase: GesturePhase::Ended,
+                    position: end,
+                    delta: Vec2::ZERO,
+                    touches: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn two_finger_gestures() {
+        let phases = |gestures: &[TouchGesture]| {
+            gestures
+                .iter()
+                .map(|gesture| match gesture {
+                    TouchGesture::Pan { phase, .. } => ("pan", *phase),
+                    TouchGesture::Pinch { phase, .. } => ("pinch", *phase),
+                    TouchGesture::Rotate { phase, .. } => ("rotate", *phase),
+                    _ => panic!("unexpected gesture {gesture:?}"),
+                })
+                .collect::<Vec<_>>()
+        };
+        let pinch_apart = |harness: &mut Harness| {
+            harness.update(
+                0,
+                &[
+                    (Started, 0, Vec2::new(90.0, 100.0)),
+                    (Started, 1, Vec2::new(110.0, 100.0)),
+                ],
+            );
+            harness.update(16, &[]);
+            let started = harness.update(
+                16,
+                &[
+                    (Moved, 0, Vec2::new(80.0, 100.0)),
+                    (Moved, 1, Vec2::new(120.0, 100.0)),
+                ],
+            );
+            let ended = harness.update(
+                16,
+                &[
+                    (Ended, 0, Vec2::new(80.0, 100.0)),
+                    (Ended, 1, Vec2::new(120.0, 100.0)),
+                ],
+            );
+            (started, ended)
+        };
+
+        let mut harness = Harness::new(TouchGestureSettings::default());
+        let (started, ended) = pinch_apart(&mut harness);
+        assert_eq!(phases(&started), vec![("pinch", GesturePhase::Started)]);
+        let TouchGesture::Pinch { scale, center, .. } = started[0] else {
+            unreachable!();
+        };
+        assert_eq!(scale, 2.0);
+        assert_eq!(center, Vec2::new(100.0, 100.0));
+        assert_eq!(phases(&ended), vec![("pinch", GesturePhase::Ended)]);
+
+        // Turning the fingers a quarter turn counterclockwise on the screen, where `y` points down.
+        harness.update(
+            100,
+            &[
+                (Started, 2, Vec2::new(90.0, 100.0)),
+                (Started, 3, Vec2::new(110.0, 100.0)),
+            ],
+        );
+        harness.update(16, &[]);
+        let gestures = harness.update(
+            16,
+            &[
+                (Moved, 2, Vec2::new(100.0, 110.0)),
+                (Moved, 3, Vec2::new(100.0, 90.0)),
+            ],
+        );
+        let [TouchGesture::Rotate { angle, .. }] = gestures[..] else {
+            panic!("expected a rotation, got {gestures:?}");
+        };
+        assert!((angle - PI / 2.0).abs() < 1e-5);
+
+        // An exclusive pan prevents the pinch.
+        let mut settings = TouchGestureSettings::default();
+        settings.pan.exclusive = true;
+        settings.pan.min_touches = 2;
+        let mut harness = Harness::new(settings);
+        harness.update(
+            0,
+            &[
+                (Started, 0, Vec2::new(90.0, 100.0)),
+                (Started, 1, Vec2::new(110.0, 100.0)),
+            ],
+        );
+        harness.update(16, &[]);
+        let gestures = harness.update(
+            16,
+            &[
+                (Moved, 0, Vec2::new(90.0, 150.0)),
+                (Moved, 1, Vec2::new(110.0, 150.0)),
+            ],
+        );
+        assert_eq!(phases(&gestures), vec![("pan", GesturePhase::Started)]);
+        let gestures = harness.update(
+            16,
+            &[
+                (Moved, 0, Vec2::new(70.0, 150.0)),
+                (Moved, 1, Vec2::new(130.0, 150.0)),
+            ],
+        );
+        assert!(gestures.is_empty(), "{gestures:?}");
+    }
+}
@@ -7,6 +7,7 @@ mod components;
 mod conversions;
 mod index;
 mod mesh;
+mod mesh_validator;
 #[cfg(feature = "bevy_mikktspace")]
 mod mikktspace;
 #[cfg(feature = "morph")]
@@ -21,6 +22,7 @@ use bitflags::bitflags;
 pub use components::*;
 pub use index::*;
 pub use mesh::*;
+pub use mesh_validator::*;
 #[cfg(feature = "bevy_mikktspace")]
 pub use mikktspace::*;
 pub use primitives::*;
@@ -64,6 +66,7 @@ impl Plugin for MeshPlugin {
         app.init_asset::<Mesh>()
             .init_asset::<skinning::SkinnedMeshInverseBindposes>()
             .register_asset_reflect::<Mesh>()
+            .init_asset_memory_tracking::<Mesh>()
             .add_systems(
                 PostUpdate,
                 mark_3d_meshes_as_changed_if_their_assets_changed.after(AssetEventSystems),
@@ -14,7 +14,7 @@ use crate::morph::MorphAttributes;
 #[cfg(feature = "serialize")]
 use crate::SerializedMeshAttributeData;
 use alloc::collections::BTreeMap;
-use bevy_asset::{Asset, RenderAssetUsages};
+use bevy_asset::{memory::AssetMemorySize, Asset, RenderAssetUsages};
 use bevy_math::{
     bounding::{Aabb2d, Aabb3d},
     primitives::Triangle3d,
@@ -2857,6 +2857,35 @@ pub enum UvChannel {
     Uv1,
 }
 
+impl AssetMemorySize for Mesh {
+    /// Estimates the main world memory used by the vertex, index and morph target data of this mesh.
+    ///
+    /// Data that has been extracted to the `RenderWorld` is not counted.
+    fn memory_size(&self) -> usize {
+        let attributes = match &self.attributes {
+            MeshExtractableData::Data(attributes) => attributes
+                .values()
+                .map(|data| data.values.get_bytes().len())
+                .sum(),
+            _ => 0,
+        };
+        let indices = match &self.indices {
+            MeshExtractableData::Data(Indices::U16(indices)) => indices.len() * size_of::<u16>(),
+            MeshExtractableData::Data(Indices::U32(indices)) => indices.len() * size_of::<u32>(),
+            _ => 0,
+        };
+        #[cfg(feature = "morph")]
+        let morph_targets = match &self.morph_targets {
+            MeshExtractableData::Data(targets) => targets.len() * size_of::<MorphAttributes>(),
+            _ => 0,
+        };
+        #[cfg(not(feature = "morph"))]
+        let morph_targets = 0;
+
+        size_of::<Self>() + attributes + indices + morph_targets
+    }
+}
+
 /// Correctly scales and renormalizes an already normalized `normal` by the scale determined by its reciprocal `scale_recip`
 pub(crate) fn scale_normal(normal: Vec3, scale_recip: Vec3) -> Vec3 {
     // This is basically just `normal * scale_recip` but with the added rule that `0. * anything == 0.`
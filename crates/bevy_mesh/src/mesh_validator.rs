@@ -0,0 +1,48 @@
+use bevy_asset::processor::{AssetValidator, ValidationContext};
+use bevy_reflect::TypePath;
+
+use crate::Mesh;
+
+/// An [`AssetValidator`] that enforces attribute and size rules for [`Mesh`]es during asset processing.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_mesh::MeshValidator;
+/// # let mut app = App::new();
+/// app.register_asset_validator(MeshValidator {
+///     require_normals: true,
+///     require_tangents: true,
+///     max_vertices: Some(65_536),
+/// });
+/// ```
+#[derive(Clone, Debug, Default, TypePath)]
+pub struct MeshValidator {
+    /// Whether the mesh must have a [`Mesh::ATTRIBUTE_NORMAL`] attribute.
+    pub require_normals: bool,
+    /// Whether the mesh must have a [`Mesh::ATTRIBUTE_TANGENT`] attribute, as required by normal mapping.
+    pub require_tangents: bool,
+    /// The largest allowed number of vertices.
+    pub max_vertices: Option<usize>,
+}
+
+impl AssetValidator for MeshValidator {
+    type Asset = Mesh;
+
+    fn validate(&self, mesh: &Mesh, context: &mut ValidationContext) {
+        if self.require_normals && !mesh.contains_attribute(Mesh::ATTRIBUTE_NORMAL) {
+            context.error("mesh has no normals");
+        }
+        if self.require_tangents && !mesh.contains_attribute(Mesh::ATTRIBUTE_TANGENT) {
+            context.error("mesh has no tangents");
+        }
+        let vertices = mesh.count_vertices();
+        if let Some(max_vertices) = self.max_vertices
+            && vertices > max_vertices
+        {
+            context.error(format!(
+                "mesh has {vertices} vertices, which exceeds the maximum of {max_vertices}"
+            ));
+        }
+    }
+}
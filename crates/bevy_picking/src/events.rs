@@ -33,11 +33,12 @@
 //! + Hovering and movement: [`Over`], [`Enter`], [`Move`], [`Leave`], and [`Out`].
 //! + Clicking and pressing: [`Press`], [`Release`], and [`Click`].
 //! + Dragging and dropping: [`DragStart`], [`Drag`], [`DragEnd`], [`DragEnter`], [`DragOver`], [`DragDrop`], [`DragLeave`].
+//!   Typed data can be carried by dragged entities with [`DragPayloads`].
 //!
 //! When received by an observer, these events will always be wrapped by the [`Pointer`] type, which contains
 //! general metadata about the pointer event.
 
-use core::{fmt::Debug, time::Duration};
+use core::{any::Any, fmt::Debug, time::Duration};
 use std::collections::HashSet;
 
 use bevy_camera::NormalizedRenderTarget;
@@ -454,6 +455,181 @@ pub struct DragEntry {
     pub latest_pos: Vec2,
 }
 
+/// Typed data carried by dragged entities, and the feedback of the drop targets they are dragged
+/// over.
+///
+/// Attach a payload when a drag starts, then read it from the [`DragEnter`], [`DragOver`] and
+/// [`DragDrop`] events of drop targets, using their `dragged` or `dropped` entity. Drop targets
+/// can [`accept`](DragPayloads::accept) or [`reject`](DragPayloads::reject) a dragged entity,
+/// which the dragged entity can read back with [`DragPayloads::drop_feedback`] to show whether it
+/// can be dropped. Targets that reject a dragged entity don't receive its [`DragDrop`] event.
+///
+/// Payloads and feedback are cleared once the drag ends, after the [`DragDrop`] and [`DragEnd`]
+/// events have been handled.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_picking::prelude::*;
+/// struct Item {
+///     weight: f32,
+/// }
+///
+/// # let mut world = World::new();
+/// world.spawn_empty().observe(
+///     |drag_start: On<Pointer<DragStart>>, mut payloads: ResMut<DragPayloads>| {
+///         payloads.insert(drag_start.entity, Item { weight: 2.0 });
+///     },
+/// );
+///
+/// world
+///     .spawn_empty()
+///     .observe(
+///         |drag_enter: On<Pointer<DragEnter>>, mut payloads: ResMut<DragPayloads>| {
+///             let (dragged, target) = (drag_enter.dragged, drag_enter.entity);
+///             match payloads.get::<Item>(dragged) {
+///                 Some(item) if item.weight < 10.0 => payloads.accept(dragged, target),
+///                 _ => payloads.reject(dragged, target),
+///             }
+///         },
+///     )
+///     .observe(|drop: On<Pointer<DragDrop>>, payloads: Res<DragPayloads>| {
+///         if let Some(item) = payloads.get::<Item>(drop.dropped) {
+///             // Move the item into this inventory slot.
+///         }
+///     });
+/// ```
+#[derive(Debug, Default, Resource)]
+pub struct DragPayloads {
+    drags: EntityHashMap<DraggedEntity>,
+}
+
+/// The payload and drop feedback of a single dragged entity.
+#[derive(Debug, Default)]
+struct DraggedEntity {
+    payload: Option<Box<dyn Any + Send + Sync>>,
+    feedback: EntityHashMap<DropFeedback>,
+}
+
+/// Whether a drop target accepts a dragged entity, see [`DragPayloads`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Clone, Default, PartialEq, Hash)]
+pub enum DropFeedback {
+    /// The target hasn't given any feedback. It still receives [`DragDrop`] events.
+    #[default]
+    Undecided,
+    /// The target accepts the dragged entity.
+    Accepted,
+    /// The target rejects the dragged entity, and won't receive its [`DragDrop`] events.
+    Rejected,
+}
+
+impl DragPayloads {
+    /// Attaches `payload` to the `dragged` entity, replacing any previous payload.
+    ///
+    /// This should be called when the drag starts, as payloads of entities that aren't being
+    /// dragged are cleared.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, dragged: Entity, payload: T) {
+        self.drags.entry(dragged).or_default().payload = Some(Box::new(payload));
+    }
+
+    /// Returns the payload of the `dragged` entity, if it has one of type `T`.
+    pub fn get<T: 'static>(&self, dragged: Entity) -> Option<&T> {
+        self.drags
+            .get(&dragged)?
+            .payload
+            .as_ref()?
+            .downcast_ref::<T>()
+    }
+
+    /// Returns the payload of the `dragged` entity mutably, if it has one of type `T`.
+    pub fn get_mut<T: 'static>(&mut self, dragged: Entity) -> Option<&mut T> {
+        self.drags
+            .get_mut(&dragged)?
+            .payload
+            .as_mut()?
+            .downcast_mut::<T>()
+    }
+
+    /// Removes the payload of the `dragged` entity, returning `true` if it had one.
+    pub fn remove(&mut self, dragged: Entity) -> bool {
+        self.drags
+            .get_mut(&dragged)
+            .is_some_and(|drag| drag.payload.take().is_some())
+    }
+
+    /// Marks the `dragged` entity as accepted by the drop `target`.
+    pub fn accept(&mut self, dragged: Entity, target: Entity) {
+        self.set_feedback(dragged, target, DropFeedback::Accepted);
+    }
+
+    /// Marks the `dragged` entity as rejected by the drop `target`.
+    pub fn reject(&mut self, dragged: Entity, target: Entity) {
+        self.set_feedback(dragged, target, DropFeedback::Rejected);
+    }
+
+    /// Sets the feedback of the drop `target` for the `dragged` entity.
+    pub fn set_feedback(&mut self, dragged: Entity, target: Entity, feedback: DropFeedback) {
+        self.drags
+            .entry(dragged)
+            .or_default()
+            .feedback
+            .insert(target, feedback);
+    }
+
+    /// Returns the feedback of the drop `target` for the `dragged` entity.
+    pub fn feedback(&self, dragged: Entity, target: Entity) -> DropFeedback {
+        self.drags
+            .get(&dragged)
+            .and_then(|drag| drag.feedback.get(&target))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the combined feedback of every target the `dragged` entity is currently over.
+    ///
+    /// The entity is accepted if any target accepts it, and rejected if any target rejects it and
+    /// none accepts it.
+    pub fn drop_feedback(&self, dragged: Entity) -> DropFeedback {
+        let Some(drag) = self.drags.get(&dragged) else {
+            return DropFeedback::Undecided;
+        };
+        drag.feedback
+            .values()
+            .fold(DropFeedback::Undecided, |combined, feedback| {
+                match (combined, feedback) {
+                    (DropFeedback::Accepted, _) | (_, DropFeedback::Accepted) => {
+                        DropFeedback::Accepted
+                    }
+                    (DropFeedback::Rejected, _) | (_, DropFeedback::Rejected) => {
+                        DropFeedback::Rejected
+                    }
+                    _ => DropFeedback::Undecided,
+                }
+            })
+    }
+}
+
+/// Clears the [`DragPayloads`] of entities that are no longer dragged, and the feedback of targets
+/// they are no longer dragged over.
+pub fn update_drag_payloads(
+    pointer_state: Res<PointerState>,
+    mut drag_payloads: ResMut<DragPayloads>,
+) {
+    drag_payloads.drags.retain(|dragged, drag| {
+        let mut is_dragged = false;
+        let mut dragged_over = EntityHashSet::default();
+        for state in pointer_state.pointer_buttons.values() {
+            if state.dragging.contains_key(dragged) {
+                is_dragged = true;
+                dragged_over.extend(state.dragging_over.keys());
+            }
+        }
+        drag.feedback
+            .retain(|target, _| dragged_over.contains(target));
+        is_dragged
+    });
+}
+
 /// Fires while a pointer is scrolling over the [target entity](EntityEvent::event_target).
 #[derive(Clone, PartialEq, Debug, Reflect)]
 #[reflect(Clone, PartialEq)]
@@ -711,6 +887,7 @@ pub fn pointer_events(
     hover_map: Res<HoverMap>,
     previous_hover_map: Res<PreviousHoverMap>,
     picking_settings: Res<PickingSettings>,
+    drag_payloads: Option<Res<DragPayloads>>,
     mut pointer_state: ResMut<PointerState>,
     mut hovered_entity_ancestors: Local<HoveredEntityAncestors>,
     mut sent_leave: Local<HashSet<(PointerId, Entity)>>,
@@ -1039,8 +1216,13 @@ pub fn pointer_events(
 
                 // Then emit the drop events.
                 for (drag_target, drag) in state.dragging.drain() {
-                    // Emit DragDrop
+                    // Emit DragDrop, except on targets that rejected the dragged entity
                     for (dragged_over, hit) in state.dragging_over.iter() {
+                        if drag_payloads.as_ref().is_some_and(|payloads| {
+                            payloads.feedback(drag_target, *dragged_over) == DropFeedback::Rejected
+                        }) {
+                            continue;
+                        }
                         let drag_drop_event = Pointer::new(
                             pointer_id,
                             location.clone(),
@@ -1511,4 +1693,150 @@ mod tests {
         app.world_mut().increment_change_tick();
         // ---
     }
+
+    #[test]
+    fn drag_payloads_and_feedback() {
+        struct Item(u32);
+
+        #[derive(Resource, Default)]
+        struct Dropped(Vec<(Entity, u32)>);
+
+        fn send(app: &mut App, action: PointerAction) {
+            app.world_mut()
+                .write_message(PointerInput::new(POINTER_ID, STUB_LOCATION, action));
+            assert!(app.world_mut().run_system_cached(pointer_events).is_ok());
+            assert!(app
+                .world_mut()
+                .run_system_cached(update_drag_payloads)
+                .is_ok());
+            // Only read the new input next time.
+            app.world_mut()
+                .resource_mut::<Messages<PointerInput>>()
+                .update();
+        }
+
+        let mut app = App::new();
+        initialize_app_for_test(&mut app);
+        app.init_resource::<DragPayloads>()
+            .init_resource::<Dropped>();
+        let camera = app.world_mut().spawn(Camera::default()).id();
+
+        let item = app
+            .world_mut()
+            .spawn_empty()
+            .observe(
+                |drag_start: On<Pointer<DragStart>>, mut payloads: ResMut<DragPayloads>| {
+                    payloads.insert(drag_start.entity, Item(7));
+                },
+            )
+            .id();
+        let on_drop = |drop: On<Pointer<DragDrop>>,
+                       payloads: Res<DragPayloads>,
+                       mut dropped: ResMut<Dropped>| {
+            let item = payloads.get::<Item>(drop.dropped).unwrap();
+            dropped.0.push((drop.entity, item.0));
+        };
+        let accepting = app
+            .world_mut()
+            .spawn_empty()
+            .observe(
+                |drag_enter: On<Pointer<DragEnter>>, mut payloads: ResMut<DragPayloads>| {
+                    if payloads.get::<Item>(drag_enter.dragged).is_some() {
+                        payloads.accept(drag_enter.dragged, drag_enter.entity);
+                    }
+                },
+            )
+            .observe(on_drop)
+            .id();
+        let rejecting = app
+            .world_mut()
+            .spawn_empty()
+            .observe(
+                |drag_enter: On<Pointer<DragEnter>>, mut payloads: ResMut<DragPayloads>| {
+                    payloads.reject(drag_enter.dragged, drag_enter.entity);
+                },
+            )
+            .observe(on_drop)
+            .id();
+
+        update_hover_map_with_hovered_entities(&mut app, camera, &[item]);
+        send(&mut app, PointerAction::Press(PointerButton::Primary));
+        update_hover_map_with_hovered_entities(&mut app, camera, &[item]);
+        send(&mut app, PointerAction::Move { delta: Vec2::ONE });
+        assert_eq!(
+            app.world()
+                .resource::<DragPayloads>()
+                .get::<Item>(item)
+                .map(|item| item.0),
+            Some(7)
+        );
+
+        update_hover_map_with_hovered_entities(&mut app, camera, &[rejecting]);
+        send(&mut app, PointerAction::Move { delta: Vec2::ONE });
+        let payloads = app.world().resource::<DragPayloads>();
+        assert_eq!(payloads.drop_feedback(item), DropFeedback::Rejected);
+
+        update_hover_map_with_hovered_entities(&mut app, camera, &[accepting, rejecting]);
+        send(&mut app, PointerAction::Move { delta: Vec2::ONE });
+        let payloads = app.world().resource::<DragPayloads>();
+        assert_eq!(payloads.feedback(item, accepting), DropFeedback::Accepted);
+        assert_eq!(payloads.drop_feedback(item), DropFeedback::Accepted);
+
+        send(&mut app, PointerAction::Release(PointerButton::Primary));
+        assert_eq!(app.world().resource::<Dropped>().0, [(accepting, 7)]);
+        let payloads = app.world().resource::<DragPayloads>();
+        assert!(payloads.get::<Item>(item).is_none());
+        assert_eq!(payloads.drop_feedback(item), DropFeedback::Undecided);
+    }
+
+    #[test]
+    fn captor_receives_release() {
+        use crate::hover::{apply_pointer_captures, release_pointer_captures, PointerCaptures};
+
+        #[derive(Resource, Default)]
+        struct Released(Vec<Entity>);
+
+        let mut app = App::new();
+        initialize_app_for_test(&mut app);
+        app.init_resource::<PointerCaptures>()
+            .init_resource::<Released>();
+        let camera = app.world_mut().spawn(Camera::default()).id();
+        let captor = app
+            .world_mut()
+            .spawn_empty()
+            .observe(
+                |press: On<Pointer<Press>>, mut captures: ResMut<PointerCaptures>| {
+                    captures.capture(press.pointer_id, press.entity);
+                },
+            )
+            .observe(
+                |release: On<Pointer<Release>>, mut released: ResMut<Released>| {
+                    released.0.push(release.entity);
+                },
+            )
+            .id();
+        let other = app.world_mut().spawn_empty().id();
+
+        let mut send = |hovered: Entity, action: PointerAction| {
+            update_hover_map_with_hovered_entities(&mut app, camera, &[hovered]);
+            app.world_mut()
+                .write_message(PointerInput::new(POINTER_ID, STUB_LOCATION, action));
+            let world = app.world_mut();
+            assert!(world.run_system_cached(apply_pointer_captures).is_ok());
+            assert!(world.run_system_cached(pointer_events).is_ok());
+            assert!(world.run_system_cached(release_pointer_captures).is_ok());
+            app.world_mut()
+                .resource_mut::<Messages<PointerInput>>()
+                .update();
+        };
+        send(captor, PointerAction::Press(PointerButton::Primary));
+        // The pointer is released away from the captor.
+        send(other, PointerAction::Release(PointerButton::Primary));
+
+        assert_eq!(app.world().resource::<Released>().0, [captor]);
+        assert_eq!(
+            app.world().resource::<PointerCaptures>().get(POINTER_ID),
+            None
+        );
+    }
 }
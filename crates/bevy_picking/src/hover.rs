@@ -214,6 +214,132 @@ fn build_hover_map(
     }
 }
 
+/// Pointers that are captured by an entity, similar to the DOM's `setPointerCapture`.
+///
+/// While a pointer is captured, the [`HoverMap`] of that pointer only contains the capturing
+/// entity, whether or not the pointer is still over it. As a result, every pointer event of that
+/// pointer is sent to the capturing entity, which is useful to keep tracking a slider thumb or a
+/// resize handle after the pointer leaves it. Because nothing else is hovered, drag events such as
+/// [`DragEnter`](crate::events::DragEnter) are not sent to other entities while the pointer is
+/// captured.
+///
+/// A capture is released when [`PointerCaptures::release`] is called, after the pointer releases a
+/// button or is canceled, and when the pointer or the capturing entity is despawned. The capturing
+/// entity receives the [`Release`](crate::events::Release) or [`Cancel`](crate::events::Cancel)
+/// that ends the capture.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_picking::{hover::PointerCaptures, prelude::*};
+/// # let mut world = World::new();
+/// world.spawn_empty().observe(
+///     |press: On<Pointer<Press>>, mut captures: ResMut<PointerCaptures>| {
+///         captures.capture(press.pointer_id, press.entity);
+///     },
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Resource, Reflect)]
+#[reflect(Debug, Clone, Default, Resource)]
+pub struct PointerCaptures(HashMap<PointerId, PointerCapture>);
+
+/// The state of a captured pointer, see [`PointerCaptures`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+pub struct PointerCapture {
+    /// The entity capturing the pointer.
+    pub target: Entity,
+    /// The latest known hit between the pointer and the capturing entity.
+    ///
+    /// While the pointer is outside of the capturing entity, this is reported as its hit.
+    pub hit: Option<HitData>,
+}
+
+impl PointerCaptures {
+    /// Captures `pointer` to `target`, replacing any previous capture of that pointer.
+    pub fn capture(&mut self, pointer: PointerId, target: Entity) {
+        self.0.insert(pointer, PointerCapture { target, hit: None });
+    }
+
+    /// Releases the capture of `pointer`, returning the entity that captured it.
+    pub fn release(&mut self, pointer: PointerId) -> Option<Entity> {
+        self.0.remove(&pointer).map(|capture| capture.target)
+    }
+
+    /// Returns the entity capturing `pointer`, if any.
+    pub fn get(&self, pointer: PointerId) -> Option<Entity> {
+        self.0.get(&pointer).map(|capture| capture.target)
+    }
+
+    /// Returns `true` if `target` is capturing any pointer.
+    pub fn is_capturing(&self, target: Entity) -> bool {
+        self.0.values().any(|capture| capture.target == target)
+    }
+
+    /// Returns an iterator over the captured pointers and their captures.
+    pub fn iter(&self) -> impl Iterator<Item = (&PointerId, &PointerCapture)> {
+        self.0.iter()
+    }
+}
+
+/// Replaces the hovered entities of captured pointers in the [`HoverMap`] with the capturing
+/// entity, and releases captures whose pointer or capturing entity was despawned. See
+/// [`PointerCaptures`].
+pub fn apply_pointer_captures(
+    pointers: Query<&PointerId>,
+    entities: Query<()>,
+    previous_hover_map: Res<PreviousHoverMap>,
+    mut captures: ResMut<PointerCaptures>,
+    mut hover_map: ResMut<HoverMap>,
+) {
+    captures.0.retain(|pointer, capture| {
+        pointers.iter().any(|id| id == pointer) && entities.contains(capture.target)
+    });
+
+    for (pointer, capture) in captures.0.iter_mut() {
+        let hovered = hover_map.entry(*pointer).or_default();
+        let previous_hit = || {
+            previous_hover_map
+                .get(pointer)
+                .and_then(|hovered| hovered.get(&capture.target))
+                .cloned()
+        };
+        // Prefer the current hit, then the latest known one, and only report a hit without a
+        // position as a last resort.
+        let hit = hovered
+            .get(&capture.target)
+            .cloned()
+            .or_else(|| capture.hit.clone())
+            .or_else(previous_hit)
+            .or_else(|| {
+                hovered
+                    .values()
+                    .next()
+                    .map(|hit| HitData::new(hit.camera, hit.depth, None, None))
+            });
+        let Some(hit) = hit else {
+            continue;
+        };
+        capture.hit = Some(hit.clone());
+        hovered.clear();
+        hovered.insert(capture.target, hit);
+    }
+}
+
+/// Releases the captures of pointers that released a button or were canceled.
+///
+/// This runs after [`pointer_events`](crate::events::pointer_events), so that the capturing
+/// entity receives the event ending the capture.
+pub fn release_pointer_captures(
+    mut pointer_input_reader: MessageReader<PointerInput>,
+    mut captures: ResMut<PointerCaptures>,
+) {
+    for input in pointer_input_reader.read() {
+        if let PointerAction::Release(_) | PointerAction::Cancel = input.action {
+            captures.0.remove(&input.pointer_id);
+        }
+    }
+}
+
 /// A component that aggregates picking interaction state of this entity across all pointers.
 ///
 /// Unlike bevy's `Interaction` component, this is an aggregate of the state of all pointers
@@ -600,4 +726,70 @@ mod tests {
         assert!(!hover.get());
         assert!(hover.is_changed());
     }
+
+    #[test]
+    fn pointer_capture() {
+        let mut world = World::default();
+        world.init_resource::<Messages<PointerInput>>();
+        world.init_resource::<PointerCaptures>();
+        world.init_resource::<PreviousHoverMap>();
+        world.spawn(PointerId::Mouse);
+        let camera = world.spawn(Camera::default()).id();
+        let captor = world.spawn_empty().id();
+        let other = world.spawn_empty().id();
+        let hit = |depth| HitData::new(camera, depth, None, None);
+        let hover = |world: &mut World, entity: Entity, depth| {
+            let mut hover_map = HoverMap::default();
+            hover_map.insert(
+                PointerId::Mouse,
+                [(entity, hit(depth))].into_iter().collect(),
+            );
+            world.insert_resource(hover_map);
+            assert!(world.run_system_cached(apply_pointer_captures).is_ok());
+            assert!(world.run_system_cached(release_pointer_captures).is_ok());
+            world.resource::<HoverMap>()[&PointerId::Mouse]
+                .iter()
+                .map(|(entity, hit)| (*entity, hit.depth))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(hover(&mut world, captor, 1.0), [(captor, 1.0)]);
+        world
+            .resource_mut::<PointerCaptures>()
+            .capture(PointerId::Mouse, captor);
+        assert_eq!(hover(&mut world, captor, 2.0), [(captor, 2.0)]);
+        // The pointer left the captor, which keeps its latest hit.
+        assert_eq!(hover(&mut world, other, 3.0), [(captor, 2.0)]);
+        assert_eq!(
+            world.resource::<PointerCaptures>().get(PointerId::Mouse),
+            Some(captor)
+        );
+
+        // Releasing a button ends the capture once the captor received the release.
+        world.write_message(PointerInput::new(
+            PointerId::Mouse,
+            crate::pointer::Location {
+                target: bevy_camera::NormalizedRenderTarget::None {
+                    width: 1,
+                    height: 1,
+                },
+                position: Default::default(),
+            },
+            PointerAction::Release(crate::pointer::PointerButton::Primary),
+        ));
+        assert_eq!(hover(&mut world, other, 3.0), [(captor, 2.0)]);
+        assert_eq!(hover(&mut world, other, 3.0), [(other, 3.0)]);
+        assert!(!world.resource::<PointerCaptures>().is_capturing(captor));
+
+        // Despawning the captor ends the capture.
+        world
+            .resource_mut::<PointerCaptures>()
+            .capture(PointerId::Mouse, captor);
+        world.despawn(captor);
+        assert_eq!(hover(&mut world, other, 3.0), [(other, 3.0)]);
+        assert_eq!(
+            world.resource::<PointerCaptures>().get(PointerId::Mouse),
+            None
+        );
+    }
 }
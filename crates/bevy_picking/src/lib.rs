@@ -143,6 +143,9 @@
 //! order of the backend, and the optional [`Pickable`] component of the entity. In other
 //! words, if one entity is in front of another, usually only the topmost one will be hovered.
 //!
+//! An entity can also capture a pointer with [`PointerCaptures`](hover::PointerCaptures), so that
+//! it stays hovered, and receives all the events of that pointer, until the capture is released.
+//!
 //! #### Events ([`events`])
 //!
 //! In the final step, the high-level pointer events are generated, such as events that trigger when
@@ -151,6 +154,12 @@
 //!
 //! Because it is completely agnostic to the earlier stages of the pipeline, you can easily extend
 //! the plugin with arbitrary backends and input methods, yet still use all the high level features.
+//!
+//! #### Selection ([`selection`])
+//!
+//! Optionally, the [`SelectionPlugin`](selection::SelectionPlugin) builds on the hover state and
+//! pointer inputs to select entities by clicking them, or by dragging a rectangle or lasso marquee
+//! around them.
 
 extern crate alloc;
 
@@ -163,6 +172,7 @@ pub mod input;
 #[cfg(feature = "mesh_picking")]
 pub mod mesh_picking;
 pub mod pointer;
+pub mod selection;
 pub mod window;
 
 use bevy_app::{prelude::*, PluginGroupBuilder};
@@ -178,6 +188,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::mesh_picking::{
         ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastBackfaces, RayCastVisibility},
+        shape_cast::MeshShapeCast,
         MeshPickingCamera, MeshPickingPlugin, MeshPickingSettings,
     };
     #[doc(hidden)]
@@ -419,10 +430,15 @@ pub struct InteractionPlugin;
 impl Plugin for InteractionPlugin {
     fn build(&self, app: &mut App) {
         use events::*;
-        use hover::{generate_hovermap, update_interactions};
+        use hover::{
+            apply_pointer_captures, generate_hovermap, release_pointer_captures,
+            update_interactions,
+        };
 
         app.init_resource::<hover::HoverMap>()
             .init_resource::<hover::PreviousHoverMap>()
+            .init_resource::<hover::PointerCaptures>()
+            .init_resource::<DragPayloads>()
             .init_resource::<PickingSettings>()
             .init_resource::<PointerState>()
             .add_message::<Pointer<Cancel>>()
@@ -446,9 +462,12 @@ impl Plugin for InteractionPlugin {
                 PreUpdate,
                 (
                     generate_hovermap,
+                    apply_pointer_captures,
                     update_interactions,
                     (update_is_hovered, update_is_directly_hovered),
                     pointer_events,
+                    update_drag_payloads,
+                    release_pointer_captures,
                 )
                     .chain()
                     .in_set(PickingSystems::Hover),
@@ -9,12 +9,19 @@
 //!
 //! To manually perform mesh ray casts independent of picking, use the [`MeshRayCast`] system parameter.
 //!
+//! For large scenes and meshes with many triangles, add the [`MeshRayCastBvhPlugin`](ray_cast::bvh::MeshRayCastBvhPlugin)
+//! to accelerate ray casts with bounding volume hierarchies.
+//!
+//! To cast spheres against meshes, or find the meshes overlapping a volume or closest to a point, use the
+//! [`MeshShapeCast`] system parameter.
+//!
 //! ## Implementation Notes
 //!
 //! - The `position` reported in `HitData` is in world space. The `normal` is a vector pointing
 //!   away from the face, it is not guaranteed to be normalized for scaled meshes.
 
 pub mod ray_cast;
+pub mod shape_cast;
 
 use crate::{
     backend::{ray::RayMap, HitData, PointerHits},
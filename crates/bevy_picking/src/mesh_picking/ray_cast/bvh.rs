@@ -0,0 +1,867 @@
+//! Bounding volume hierarchies that accelerate [ray casts](super::MeshRayCast).
+//!
+//! Without acceleration, a ray cast tests the [`Aabb`] of every mesh entity, and then every triangle of each mesh
+//! whose [`Aabb`] is hit. [`MeshRayCastBvhPlugin`] replaces both of these linear searches:
+//! - [`MeshBvhs`] caches a [`MeshBvh`] for every large enough [`Mesh`] asset. They are built on the
+//!   [`AsyncComputeTaskPool`], and rebuilt whenever the asset changes. Until a [`MeshBvh`] is ready, the
+//!   triangles of the mesh are tested one by one.
+//! - [`RayCastBroadphase`] is a hierarchy over the world space bounds of every mesh entity. It is updated in
+//!   [`PostUpdate`], after transforms and bounds are updated: moved entities are refit in place, and added
+//!   entities are tested one by one until enough of them have accumulated to rebuild the hierarchy.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{AssetEvent, AssetEventSystems, AssetId, Assets};
+use bevy_camera::{primitives::Aabb, visibility::VisibilitySystems};
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
+use bevy_math::{bounding::Aabb3d, Affine3A, Ray3d, Vec3, Vec3A};
+use bevy_mesh::{Indices, Mesh, Mesh2d, Mesh3d, PrimitiveTopology};
+use bevy_platform::collections::HashMap;
+use bevy_tasks::{futures::check_ready, AsyncComputeTaskPool, Task};
+use bevy_transform::{components::GlobalTransform, TransformSystems};
+
+use super::{
+    intersections::ray_triangle_intersection, Backfaces, MeshFilter, RayTriangleHit, SimplifiedMesh,
+};
+
+/// Accelerates [`MeshRayCast`](super::MeshRayCast) and mesh picking with bounding volume hierarchies.
+///
+/// This adds the [`MeshBvhs`] and [`RayCastBroadphase`] resources, see the [module docs](self) for details.
+/// It is most useful for scenes with many entities or meshes with many triangles, like CAD models.
+pub struct MeshRayCastBvhPlugin {
+    /// Meshes with fewer triangles than this are not given a [`MeshBvh`], because testing all of their
+    /// triangles is about as fast.
+    pub min_triangles: usize,
+}
+
+impl Default for MeshRayCastBvhPlugin {
+    fn default() -> Self {
+        Self { min_triangles: 256 }
+    }
+}
+
+impl Plugin for MeshRayCastBvhPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MeshBvhs {
+            min_triangles: self.min_triangles,
+            ..Default::default()
+        })
+        .init_resource::<RayCastBroadphase>()
+        .add_systems(
+            PostUpdate,
+            (
+                update_mesh_bvhs.after(AssetEventSystems),
+                update_ray_cast_broadphase
+                    .after(TransformSystems::Propagate)
+                    .after(VisibilitySystems::CalculateBounds),
+            ),
+        );
+    }
+}
+
+/// A bounding volume hierarchy over the triangles of a [`Mesh`], in the space of the mesh.
+#[derive(Debug, Clone)]
+pub struct MeshBvh {
+    bvh: Bvh,
+    triangle_count: usize,
+}
+
+impl MeshBvh {
+    /// Builds the hierarchy of a triangle list mesh.
+    ///
+    /// Returns `None` if the mesh is not a [`PrimitiveTopology::TriangleList`], or has no positions.
+    pub fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+            return None;
+        }
+        let positions = mesh
+            .try_attribute(Mesh::ATTRIBUTE_POSITION)
+            .ok()?
+            .as_float3()?;
+        Some(Self::from_triangles(positions, mesh.try_indices().ok()))
+    }
+
+    /// Builds the hierarchy of the triangles formed by `positions`, in the order of `indices` if there are any.
+    ///
+    /// Triangles with out of bounds indices are left out.
+    pub fn from_triangles(positions: &[[f32; 3]], indices: Option<&Indices>) -> Self {
+        let triangle_count = triangle_count(positions, indices);
+        let bounds = (0..triangle_count).filter_map(|triangle_index| {
+            let [a, b, c] = triangle(positions, indices, triangle_index)?;
+            let bounds = Aabb3d {
+                min: a.min(b).min(c).into(),
+                max: a.max(b).max(c).into(),
+            };
+            Some((triangle_index as u32, bounds))
+        });
+        Self {
+            bvh: Bvh::new(bounds.collect()),
+            triangle_count,
+        }
+    }
+
+    /// Returns the number of triangles of the mesh this hierarchy was built for.
+    pub fn triangle_count(&self) -> usize {
+        self.triangle_count
+    }
+
+    /// Calls `f` with the index of every triangle that may overlap `bounds`, which are in the space of the mesh.
+    pub(crate) fn overlapping(&self, bounds: &Aabb3d, mut f: impl FnMut(usize)) {
+        self.bvh
+            .overlap(bounds, |triangle_index| f(triangle_index as usize));
+    }
+
+    /// Returns the nearest triangle hit by `ray`, which is in the space of the mesh.
+    pub(super) fn closest_hit(
+        &self,
+        ray: &Ray3d,
+        positions: &[[f32; 3]],
+        indices: Option<&Indices>,
+        backfaces: Backfaces,
+    ) -> Option<(usize, RayTriangleHit)> {
+        let mut closest = None;
+        self.bvh
+            .cast(ray, f32::MAX, |triangle_index, max_distance| {
+                let triangle_index = triangle_index as usize;
+                let Some(vertices) = triangle(positions, indices, triangle_index) else {
+                    return max_distance;
+                };
+                match ray_triangle_intersection(ray, &vertices, backfaces) {
+                    Some(hit) if hit.distance >= 0. && hit.distance < max_distance => {
+                        let distance = hit.distance;
+                        closest = Some((triangle_index, hit));
+                        distance
+                    }
+                    _ => max_distance,
+                }
+            });
+        closest
+    }
+}
+
+/// Returns the number of triangles formed by `positions` and `indices`.
+pub(crate) fn triangle_count(positions: &[[f32; 3]], indices: Option<&Indices>) -> usize {
+    match indices {
+        Some(indices) => indices.len() / 3,
+        None => positions.len() / 3,
+    }
+}
+
+/// Returns the vertices of the triangle at `triangle_index`, or `None` if its indices are out of bounds.
+pub(crate) fn triangle(
+    positions: &[[f32; 3]],
+    indices: Option<&Indices>,
+    triangle_index: usize,
+) -> Option<[Vec3; 3]> {
+    let first = triangle_index * 3;
+    let [a, b, c] = match indices {
+        Some(Indices::U16(indices)) => {
+            [0, 1, 2].map(|i| indices.get(first + i).map(|&i| i as usize))
+        }
+        Some(Indices::U32(indices)) => {
+            [0, 1, 2].map(|i| indices.get(first + i).map(|&i| i as usize))
+        }
+        None => [Some(first), Some(first + 1), Some(first + 2)],
+    };
+    Some([
+        Vec3::from(*positions.get(a?)?),
+        Vec3::from(*positions.get(b?)?),
+        Vec3::from(*positions.get(c?)?),
+    ])
+}
+
+/// A [`Resource`] caching the [`MeshBvh`] of every [`Mesh`] asset with at least
+/// [`min_triangles`](MeshRayCastBvhPlugin::min_triangles) triangles.
+#[derive(Resource, Default)]
+pub struct MeshBvhs {
+    bvhs: HashMap<AssetId<Mesh>, MeshBvh>,
+    tasks: HashMap<AssetId<Mesh>, Task<MeshBvh>>,
+    min_triangles: usize,
+}
+
+impl MeshBvhs {
+    /// Returns the hierarchy of the mesh, if it has been built.
+    pub fn get(&self, id: impl Into<AssetId<Mesh>>) -> Option<&MeshBvh> {
+        self.bvhs.get(&id.into())
+    }
+
+    /// Returns `true` if the hierarchy of the mesh is being built.
+    pub fn is_building(&self, id: impl Into<AssetId<Mesh>>) -> bool {
+        self.tasks.contains_key(&id.into())
+    }
+
+    /// Returns the number of hierarchies that have been built.
+    pub fn len(&self) -> usize {
+        self.bvhs.len()
+    }
+
+    /// Returns `true` if no hierarchy has been built.
+    pub fn is_empty(&self) -> bool {
+        self.bvhs.is_empty()
+    }
+}
+
+/// Starts building the [`MeshBvh`] of added and modified meshes, and collects the finished ones.
+pub fn update_mesh_bvhs(
+    mut bvhs: ResMut<MeshBvhs>,
+    mut mesh_events: MessageReader<AssetEvent<Mesh>>,
+    meshes: Res<Assets<Mesh>>,
+) {
+    let bvhs = &mut *bvhs;
+    for event in mesh_events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                // Dropping an outdated task cancels it.
+                bvhs.bvhs.remove(&id);
+                bvhs.tasks.remove(&id);
+                let Some(mesh) = meshes.get(id) else {
+                    continue;
+                };
+                if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+                    continue;
+                }
+                let Some(positions) = mesh
+                    .try_attribute(Mesh::ATTRIBUTE_POSITION)
+                    .ok()
+                    .and_then(|positions| positions.as_float3())
+                else {
+                    continue;
+                };
+                let indices = mesh.try_indices().ok();
+                if triangle_count(positions, indices) < bvhs.min_triangles {
+                    continue;
+                }
+
+                let positions = positions.to_vec();
+                let indices = indices.cloned();
+                let task = AsyncComputeTaskPool::get()
+                    .spawn(async move { MeshBvh::from_triangles(&positions, indices.as_ref()) });
+                bvhs.tasks.insert(id, task);
+            }
+            AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+                bvhs.bvhs.remove(&id);
+                bvhs.tasks.remove(&id);
+            }
+            AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+
+    bvhs.tasks.retain(|&id, task| match check_ready(task) {
+        Some(bvh) => {
+            bvhs.bvhs.insert(id, bvh);
+            false
+        }
+        None => true,
+    });
+}
+
+/// A [`Resource`] holding a hierarchy over the world space bounds of every mesh entity.
+///
+/// This is used by [`MeshRayCast`](super::MeshRayCast) to find the entities in the path of a ray.
+///
+/// Entities added since the hierarchy was last built are not in it yet, and are tested one by one instead.
+#[derive(Resource, Default)]
+pub struct RayCastBroadphase {
+    bvh: Bvh,
+    /// The entities and world space bounds of the primitives of `bvh`.
+    ///
+    /// Removed entities are replaced with [`Entity::PLACEHOLDER`] until the hierarchy is rebuilt.
+    entities: Vec<(Entity, Aabb3d)>,
+    /// The index in `entities` of every entity in the hierarchy.
+    indices: EntityHashMap<u32>,
+    /// The world space bounds of the entities that are not in the hierarchy yet.
+    unindexed: EntityHashMap<Aabb3d>,
+}
+
+impl RayCastBroadphase {
+    /// Returns the number of entities in the broadphase, including ones that are not in the hierarchy yet.
+    pub fn len(&self) -> usize {
+        self.indices.len() + self.unindexed.len()
+    }
+
+    /// Returns `true` if the broadphase has no entities.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calls `f` with every entity whose world space bounds are hit by `ray`.
+    pub fn cast_ray(&self, ray: Ray3d, mut f: impl FnMut(Entity)) {
+        let origin = Vec3A::from(ray.origin);
+        let direction_recip = Vec3A::from(*ray.direction).recip();
+        self.bvh.cast(&ray, f32::MAX, |index, max_distance| {
+            let (entity, bounds) = &self.entities[index as usize];
+            if *entity != Entity::PLACEHOLDER
+                && ray_bounds_distance(origin, direction_recip, bounds).is_some()
+            {
+                f(*entity);
+            }
+            max_distance
+        });
+        for (&entity, bounds) in &self.unindexed {
+            if ray_bounds_distance(origin, direction_recip, bounds).is_some() {
+                f(entity);
+            }
+        }
+    }
+
+    /// Calls `f` with every entity whose world space bounds overlap `bounds`.
+    pub fn overlap(&self, bounds: &Aabb3d, mut f: impl FnMut(Entity)) {
+        self.bvh.overlap(bounds, |index| {
+            let (entity, entity_bounds) = &self.entities[index as usize];
+            if *entity != Entity::PLACEHOLDER && bounds_overlap(entity_bounds, bounds) {
+                f(*entity);
+            }
+        });
+        for (&entity, entity_bounds) in &self.unindexed {
+            if bounds_overlap(entity_bounds, bounds) {
+                f(entity);
+            }
+        }
+    }
+
+    fn from_bounds(entities: Vec<(Entity, Aabb3d)>) -> Self {
+        let bounds = entities
+            .iter()
+            .enumerate()
+            .map(|(index, (_, bounds))| (index as u32, *bounds))
+            .collect();
+        let indices = entities
+            .iter()
+            .enumerate()
+            .map(|(index, (entity, _))| (*entity, index as u32))
+            .collect();
+        Self {
+            bvh: Bvh::new(bounds),
+            entities,
+            indices,
+            unindexed: EntityHashMap::default(),
+        }
+    }
+
+    /// Sets the world space bounds of `entity`, returning `true` if it is in the hierarchy and it needs to be refit.
+    fn set_bounds(&mut self, entity: Entity, bounds: Aabb3d) -> bool {
+        match self.indices.get(&entity) {
+            Some(&index) => {
+                self.entities[index as usize].1 = bounds;
+                true
+            }
+            None => {
+                self.unindexed.insert(entity, bounds);
+                false
+            }
+        }
+    }
+
+    /// Removes `entity`, leaving a hole in the hierarchy until it is rebuilt.
+    fn remove(&mut self, entity: Entity) {
+        if let Some(index) = self.indices.remove(&entity) {
+            self.entities[index as usize].0 = Entity::PLACEHOLDER;
+        }
+        self.unindexed.remove(&entity);
+    }
+
+    /// Returns `true` if enough entities were added or removed since the hierarchy was built that it should be
+    /// rebuilt rather than testing the added entities one by one.
+    fn needs_rebuild(&self) -> bool {
+        let removed = self.entities.len() - self.indices.len();
+        let threshold = (self.indices.len() / 8).max(Bvh::MAX_LEAF_SIZE);
+        self.unindexed.len() > threshold || removed > threshold
+    }
+
+    /// Rebuilds the hierarchy from every entity, including the ones that were not in it yet.
+    fn rebuild(&mut self) {
+        let mut entities = core::mem::take(&mut self.entities);
+        entities.retain(|(entity, _)| *entity != Entity::PLACEHOLDER);
+        entities.extend(self.unindexed.drain());
+        *self = Self::from_bounds(entities);
+    }
+}
+
+/// Updates the [`RayCastBroadphase`] for the mesh entities that were added, removed or moved.
+///
+/// Moved entities are refit in place. Added entities are tested one by one by ray casts until enough of them
+/// accumulate, or enough entities are removed, that the hierarchy is rebuilt.
+pub fn update_ray_cast_broadphase(
+    mut broadphase: ResMut<RayCastBroadphase>,
+    changed: Query<
+        (Entity, &Aabb, &GlobalTransform),
+        (
+            MeshFilter,
+            Or<(
+                Changed<GlobalTransform>,
+                Changed<Aabb>,
+                Added<Mesh3d>,
+                Added<Mesh2d>,
+                Added<SimplifiedMesh>,
+            )>,
+        ),
+    >,
+    entities: Query<(), (MeshFilter, With<Aabb>, With<GlobalTransform>)>,
+    mut removed_aabbs: RemovedComponents<Aabb>,
+    mut removed_transforms: RemovedComponents<GlobalTransform>,
+    mut removed_mesh_3ds: RemovedComponents<Mesh3d>,
+    mut removed_mesh_2ds: RemovedComponents<Mesh2d>,
+    mut removed_simplified_meshes: RemovedComponents<SimplifiedMesh>,
+) {
+    let broadphase = &mut *broadphase;
+    let removed = removed_aabbs
+        .read()
+        .chain(removed_transforms.read())
+        .chain(removed_mesh_3ds.read())
+        .chain(removed_mesh_2ds.read())
+        .chain(removed_simplified_meshes.read());
+    for entity in removed {
+        if !entities.contains(entity) {
+            broadphase.remove(entity);
+        }
+    }
+
+    let mut refit = false;
+    for (entity, aabb, transform) in &changed {
+        let bounds = transform_bounds(
+            &Aabb3d::new(aabb.center, aabb.half_extents),
+            &transform.affine(),
+        );
+        refit |= broadphase.set_bounds(entity, bounds);
+    }
+
+    if broadphase.needs_rebuild() {
+        broadphase.rebuild();
+    } else if refit {
+        let entities = &broadphase.entities;
+        broadphase
+            .bvh
+            .refit(|primitive| entities[primitive as usize].1);
+    }
+}
+
+/// Returns bounds containing `bounds` after they are transformed by `affine`.
+///
+/// Each axis is transformed separately, so very large bounds do not overflow to NaN.
+pub(crate) fn transform_bounds(bounds: &Aabb3d, affine: &Affine3A) -> Aabb3d {
+    let mut transformed = Aabb3d {
+        min: affine.translation,
+        max: affine.translation,
+    };
+    for axis in 0..3 {
+        let column = affine.matrix3.col(axis);
+        let a = column * bounds.min[axis];
+        let b = column * bounds.max[axis];
+        transformed.min += a.min(b);
+        transformed.max += a.max(b);
+    }
+    transformed
+}
+
+/// A node of a [`Bvh`].
+#[derive(Debug, Clone)]
+struct BvhNode {
+    bounds: Aabb3d,
+    /// The index of the first child node, or of the first primitive of a leaf.
+    first: u32,
+    /// The number of primitives of a leaf, or zero.
+    count: u32,
+}
+
+/// A bounding volume hierarchy over primitives identified by a `u32`.
+///
+/// Each node splits its primitives in half along the longest axis of their centers, so the depth of the tree is
+/// logarithmic in the number of primitives.
+#[derive(Debug, Clone, Default)]
+struct Bvh {
+    nodes: Vec<BvhNode>,
+    primitives: Vec<u32>,
+}
+
+impl Bvh {
+    const MAX_LEAF_SIZE: usize = 4;
+
+    fn new(bounds: Vec<(u32, Aabb3d)>) -> Self {
+        let mut bvh = Bvh {
+            nodes: Vec::with_capacity(2 * bounds.len() / Self::MAX_LEAF_SIZE + 1),
+            primitives: Vec::with_capacity(bounds.len()),
+        };
+        if bounds.is_empty() {
+            return bvh;
+        }
+        let mut bounds = bounds;
+        bvh.nodes.push(BvhNode {
+            bounds: bounds[0].1,
+            first: 0,
+            count: 0,
+        });
+        bvh.build(0, &mut bounds, 0);
+        bvh.primitives = bounds.into_iter().map(|(id, _)| id).collect();
+        bvh
+    }
+
+    fn build(&mut self, node: usize, bounds: &mut [(u32, Aabb3d)], first: usize) {
+        let mut min = Vec3A::INFINITY;
+        let mut max = Vec3A::NEG_INFINITY;
+        let mut center_min = Vec3A::INFINITY;
+        let mut center_max = Vec3A::NEG_INFINITY;
+        for (_, aabb) in bounds.iter() {
+            min = min.min(aabb.min);
+            max = max.max(aabb.max);
+            let center = (aabb.min + aabb.max) * 0.5;
+            center_min = center_min.min(center);
+            center_max = center_max.max(center);
+        }
+        let node_bounds = Aabb3d { min, max };
+
+        if bounds.len() <= Self::MAX_LEAF_SIZE {
+            self.nodes[node] = BvhNode {
+                bounds: node_bounds,
+                first: first as u32,
+                count: bounds.len() as u32,
+            };
+            return;
+        }
+
+        let axis = (center_max - center_min).max_position();
+        let middle = bounds.len() / 2;
+        bounds.select_nth_unstable_by(middle, |(_, a), (_, b)| {
+            (a.min[axis] + a.max[axis]).total_cmp(&(b.min[axis] + b.max[axis]))
+        });
+
+        let left = self.nodes.len();
+        let placeholder = BvhNode {
+            bounds: node_bounds,
+            first: 0,
+            count: 0,
+        };
+        self.nodes.push(placeholder.clone());
+        self.nodes.push(placeholder);
+        self.nodes[node] = BvhNode {
+            bounds: node_bounds,
+            first: left as u32,
+            count: 0,
+        };
+
+        let (left_bounds, right_bounds) = bounds.split_at_mut(middle);
+        self.build(left, left_bounds, first);
+        self.build(left + 1, right_bounds, first + middle);
+    }
+
+    /// Recomputes the bounds of every node from the current bounds of its primitives, keeping the structure of the
+    /// tree.
+    ///
+    /// This is much faster than building a new hierarchy, but the tree gets less efficient the further the
+    /// primitives move from where they were when it was built.
+    fn refit(&mut self, bounds: impl Fn(u32) -> Aabb3d) {
+        // Children are always after their parent, so they are refit first.
+        for node in (0..self.nodes.len()).rev() {
+            let BvhNode { first, count, .. } = self.nodes[node];
+            let first = first as usize;
+            let mut min = Vec3A::INFINITY;
+            let mut max = Vec3A::NEG_INFINITY;
+            if count > 0 {
+                for &primitive in &self.primitives[first..first + count as usize] {
+                    let bounds = bounds(primitive);
+                    min = min.min(bounds.min);
+                    max = max.max(bounds.max);
+                }
+            } else {
+                for child in [first, first + 1] {
+                    min = min.min(self.nodes[child].bounds.min);
+                    max = max.max(self.nodes[child].bounds.max);
+                }
+            }
+            self.nodes[node].bounds = Aabb3d { min, max };
+        }
+    }
+
+    /// Calls `f` with every primitive whose bounds are hit by `ray` closer than the maximum distance, nearest
+    /// nodes first.
+    ///
+    /// `f` is given the current maximum distance, and returns the new one.
+    fn cast(&self, ray: &Ray3d, mut max_distance: f32, mut f: impl FnMut(u32, f32) -> f32) {
+        let Some(root) = self.nodes.first() else {
+            return;
+        };
+        let origin = Vec3A::from(ray.origin);
+        let direction_recip = Vec3A::from(*ray.direction).recip();
+        let Some(root_distance) = ray_bounds_distance(origin, direction_recip, &root.bounds) else {
+            return;
+        };
+
+        let mut stack = Vec::with_capacity(64);
+        stack.push((0, root_distance));
+        while let Some((node, distance)) = stack.pop() {
+            if distance > max_distance {
+                continue;
+            }
+            let node = &self.nodes[node];
+            if node.count > 0 {
+                let first = node.first as usize;
+                for &primitive in &self.primitives[first..first + node.count as usize] {
+                    max_distance = f(primitive, max_distance);
+                }
+                continue;
+            }
+
+            let left = node.first as usize;
+            let hits = [left, left + 1].map(|child| {
+                ray_bounds_distance(origin, direction_recip, &self.nodes[child].bounds)
+                    .map(|distance| (child, distance))
+            });
+            match hits {
+                [Some(near), Some(far)] | [Some(far), Some(near)] if near.1 <= far.1 => {
+                    stack.push(far);
+                    stack.push(near);
+                }
+                [Some(hit), None] | [None, Some(hit)] => stack.push(hit),
+                _ => {}
+            }
+        }
+    }
+
+    /// Calls `f` with every primitive whose node bounds overlap `bounds`.
+    fn overlap(&self, bounds: &Aabb3d, mut f: impl FnMut(u32)) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let mut stack = Vec::with_capacity(64);
+        stack.push(0);
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !bounds_overlap(&node.bounds, bounds) {
+                continue;
+            }
+            let first = node.first as usize;
+            if node.count > 0 {
+                for &primitive in &self.primitives[first..first + node.count as usize] {
+                    f(primitive);
+                }
+            } else {
+                stack.push(first + 1);
+                stack.push(first);
+            }
+        }
+    }
+}
+
+/// Returns `true` if the two bounds overlap, including when they only touch.
+pub(crate) fn bounds_overlap(a: &Aabb3d, b: &Aabb3d) -> bool {
+    a.min.cmple(b.max).all() && a.max.cmpge(b.min).all()
+}
+
+/// Returns the distance along the ray to `bounds`, or zero if the ray starts inside them.
+fn ray_bounds_distance(origin: Vec3A, direction_recip: Vec3A, bounds: &Aabb3d) -> Option<f32> {
+    // Axes the ray is parallel to would produce NaN when the origin is on a face of the bounds, which is common
+    // for flat meshes and axis aligned rays, so they are checked separately.
+    let parallel = !direction_recip.is_finite_mask();
+    let inside = origin.cmpge(bounds.min) & origin.cmple(bounds.max);
+    if (parallel & !inside).any() {
+        return None;
+    }
+    let t1 = (bounds.min - origin) * direction_recip;
+    let t2 = (bounds.max - origin) * direction_recip;
+    let near = Vec3A::select(parallel, Vec3A::NEG_INFINITY, t1.min(t2));
+    let far = Vec3A::select(parallel, Vec3A::INFINITY, t1.max(t2));
+    let near = near.max_element().max(0.0);
+    (near <= far.min_element()).then_some(near)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::{ops, primitives::Sphere, Dir3, Vec3};
+    use bevy_mesh::Meshable;
+
+    #[test]
+    fn mesh_bvh_matches_brute_force() {
+        let mesh = Sphere::new(1.0).mesh().ico(4).unwrap();
+        let positions = mesh
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .unwrap()
+            .as_float3()
+            .unwrap();
+        let indices = mesh.indices();
+        let bvh = MeshBvh::from_mesh(&mesh).unwrap();
+        assert_eq!(bvh.triangle_count(), triangle_count(positions, indices));
+
+        for i in 0..200 {
+            let angle = i as f32 * 0.37;
+            let origin = Vec3::new(
+                ops::cos(angle) * 3.0,
+                ops::sin(i as f32 * 0.11),
+                ops::sin(angle) * 3.0,
+            );
+            let target = Vec3::new(
+                ops::sin(i as f32 * 0.7) * 0.9,
+                ops::cos(i as f32 * 0.3) * 0.9,
+                0.0,
+            );
+            let ray = Ray3d::new(origin, Dir3::new(target - origin).unwrap());
+
+            let brute_force = (0..triangle_count(positions, indices))
+                .filter_map(|index| {
+                    let vertices = triangle(positions, indices, index)?;
+                    ray_triangle_intersection(&ray, &vertices, Backfaces::Cull)
+                        .filter(|hit| hit.distance >= 0.0)
+                        .map(|hit| (index, hit.distance))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            let accelerated = bvh
+                .closest_hit(&ray, positions, indices, Backfaces::Cull)
+                .map(|(index, hit)| (index, hit.distance));
+            assert_eq!(brute_force, accelerated);
+        }
+
+        let miss = Ray3d::new(Vec3::new(0.0, 5.0, 0.0), Dir3::X);
+        assert!(bvh
+            .closest_hit(&miss, positions, indices, Backfaces::Cull)
+            .is_none());
+    }
+
+    #[test]
+    fn broadphase_finds_entities_in_path() {
+        let entities = (0..100)
+            .map(|i| {
+                let center = Vec3A::new(i as f32 * 2.0, (i % 3) as f32 * 2.0, 0.0);
+                let bounds = Aabb3d {
+                    min: center - 0.5,
+                    max: center + 0.5,
+                };
+                (Entity::from_raw_u32(i).unwrap(), bounds)
+            })
+            .collect();
+        let broadphase = RayCastBroadphase::from_bounds(entities);
+
+        let mut hits = Vec::new();
+        broadphase.cast_ray(Ray3d::new(Vec3::new(-10.0, 0.0, 0.0), Dir3::X), |entity| {
+            hits.push(entity.index_u32());
+        });
+        hits.sort();
+        assert_eq!(hits, (0..100).step_by(3).collect::<Vec<_>>());
+
+        // The ray starts on the faces of the bounds it is parallel to.
+        let mut hits = Vec::new();
+        broadphase.cast_ray(Ray3d::new(Vec3::new(-10.0, 1.5, 0.5), Dir3::X), |entity| {
+            hits.push(entity.index_u32());
+        });
+        hits.sort();
+        assert_eq!(hits, (1..100).step_by(3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn broadphase_updates_incrementally() {
+        use bevy_ecs::schedule::Schedule;
+        use bevy_mesh::Mesh3d;
+        use bevy_transform::components::Transform;
+
+        let mut world = World::new();
+        world.init_resource::<RayCastBroadphase>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_ray_cast_broadphase);
+
+        let aabb = Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5));
+        let spawn = |world: &mut World, position: Vec3| {
+            world
+                .spawn((
+                    Mesh3d::default(),
+                    aabb,
+                    GlobalTransform::from(Transform::from_translation(position)),
+                ))
+                .id()
+        };
+        let mut entities = (0..64)
+            .map(|i| spawn(&mut world, Vec3::new(i as f32 * 2.0, 0.0, 0.0)))
+            .collect::<Vec<_>>();
+        entities.sort();
+        let hits = |world: &World, y: f32| {
+            let mut hits = Vec::new();
+            world.resource::<RayCastBroadphase>().cast_ray(
+                Ray3d::new(Vec3::new(-10.0, y, 0.0), Dir3::X),
+                |entity| {
+                    hits.push(entity);
+                },
+            );
+            hits.sort();
+            hits
+        };
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RayCastBroadphase>().len(), 64);
+        assert_eq!(hits(&world, 0.0), entities);
+
+        // Moved entities are refit rather than rebuilt.
+        world
+            .entity_mut(entities[0])
+            .insert(GlobalTransform::from_xyz(0.0, 10.0, 0.0));
+        schedule.run(&mut world);
+        assert_eq!(hits(&world, 0.0), entities[1..]);
+        assert!(world.resource::<RayCastBroadphase>().unindexed.is_empty());
+        assert_eq!(hits(&world, 10.0), [entities[0]]);
+
+        // Added entities are found before the hierarchy is rebuilt.
+        let added = spawn(&mut world, Vec3::new(200.0, 0.0, 0.0));
+        schedule.run(&mut world);
+        assert!(world
+            .resource::<RayCastBroadphase>()
+            .unindexed
+            .contains_key(&added));
+        assert!(hits(&world, 0.0).contains(&added));
+
+        // Removed entities are skipped, even when the set of entities has the same size.
+        world.despawn(entities[1]);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RayCastBroadphase>().len(), 64);
+        assert!(!hits(&world, 0.0).contains(&entities[1]));
+    }
+
+    #[test]
+    fn mesh_ray_cast_uses_bvhs() {
+        use crate::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility};
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_camera::{
+            primitives::MeshAabb,
+            visibility::{InheritedVisibility, ViewVisibility},
+        };
+        use bevy_ecs::system::RunSystemOnce;
+        use bevy_mesh::Mesh3d;
+        use bevy_transform::components::Transform;
+
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), MeshRayCastBvhPlugin::default()))
+            .init_resource::<Assets<Mesh>>()
+            .add_message::<AssetEvent<Mesh>>();
+
+        let mesh = Sphere::new(1.0).mesh().ico(4).unwrap();
+        let aabb = mesh.get_aabb().unwrap();
+        let handle = app.world_mut().resource_mut::<Assets<Mesh>>().add(mesh);
+        app.world_mut()
+            .write_message(AssetEvent::Added { id: handle.id() });
+        let sphere = app
+            .world_mut()
+            .spawn((
+                Mesh3d(handle.clone()),
+                aabb,
+                GlobalTransform::from(Transform::from_xyz(5.0, 0.0, 0.0)),
+                InheritedVisibility::VISIBLE,
+                ViewVisibility::default(),
+            ))
+            .id();
+
+        while app.world().resource::<MeshBvhs>().get(&handle).is_none() {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<RayCastBroadphase>().len(), 1);
+
+        let hits = app
+            .world_mut()
+            .run_system_once(|mut ray_cast: MeshRayCast| {
+                let settings =
+                    MeshRayCastSettings::default().with_visibility(RayCastVisibility::Visible);
+                ray_cast
+                    .cast_ray(Ray3d::new(Vec3::ZERO, Dir3::X), &settings)
+                    .iter()
+                    .map(|(entity, hit)| (*entity, hit.distance))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0, sphere);
+        assert!((hits[0].1 - 4.0).abs() < 0.01);
+    }
+}
@@ -2,7 +2,7 @@ use bevy_math::{bounding::Aabb3d, Affine3A, Dir3, Ray3d, Vec2, Vec3, Vec3A};
 use bevy_mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
 use bevy_reflect::Reflect;
 
-use super::Backfaces;
+use super::{Backfaces, MeshBvh};
 
 /// Hit data for an intersection between a ray and a mesh.
 #[derive(Debug, Clone, Reflect)]
@@ -36,11 +36,14 @@ pub struct RayTriangleHit {
 }
 
 /// Casts a ray on a mesh, and returns the intersection.
+///
+/// The triangles are searched with `bvh` if it was built for this mesh, and tested one by one otherwise.
 pub(super) fn ray_intersection_over_mesh(
     mesh: &Mesh,
     transform: &Affine3A,
     ray: Ray3d,
     cull: Backfaces,
+    bvh: Option<&MeshBvh>,
 ) -> Option<RayMeshHit> {
     if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
         return None; // ray_mesh_intersection assumes vertices are laid out in a triangle list
@@ -65,7 +68,44 @@ pub(super) fn ray_intersection_over_mesh(
             _ => None,
         });
 
-    match mesh.try_indices().ok() {
+    let indices = mesh.try_indices().ok();
+    if let Some(bvh) = bvh
+        && bvh.triangle_count() == super::bvh::triangle_count(positions, indices)
+    {
+        let world_to_mesh = transform.inverse();
+        let mesh_ray = Ray3d::new(
+            world_to_mesh.transform_point3(ray.origin),
+            Dir3::new(world_to_mesh.transform_vector3(*ray.direction)).ok()?,
+        );
+        let (tri_idx, hit) = bvh.closest_hit(&mesh_ray, positions, indices, cull)?;
+        return match indices {
+            Some(Indices::U16(indices)) => triangle_hit(
+                &mesh_ray,
+                transform,
+                positions,
+                normals,
+                Some(indices),
+                uvs,
+                tri_idx,
+                hit,
+            ),
+            Some(Indices::U32(indices)) => triangle_hit(
+                &mesh_ray,
+                transform,
+                positions,
+                normals,
+                Some(indices),
+                uvs,
+                tri_idx,
+                hit,
+            ),
+            None => triangle_hit::<u32>(
+                &mesh_ray, transform, positions, normals, None, uvs, tri_idx, hit,
+            ),
+        };
+    }
+
+    match indices {
         Some(Indices::U16(indices)) => {
             ray_mesh_intersection(ray, transform, positions, normals, Some(indices), uvs, cull)
         }
@@ -154,83 +194,112 @@ where
     };
 
     closest_hit.and_then(|(tri_idx, hit)| {
-        let [a, b, c] = match indices {
-            Some(indices) => {
-                let [i, j, k] = [tri_idx * 3, tri_idx * 3 + 1, tri_idx * 3 + 2];
-                [
-                    indices.get(i).copied()?.try_into().ok()?,
-                    indices.get(j).copied()?.try_into().ok()?,
-                    indices.get(k).copied()?.try_into().ok()?,
-                ]
-            }
-            None => [tri_idx * 3, tri_idx * 3 + 1, tri_idx * 3 + 2],
-        };
+        triangle_hit(
+            &ray,
+            mesh_transform,
+            positions,
+            vertex_normals,
+            indices,
+            uvs,
+            tri_idx,
+            hit,
+        )
+    })
+}
 
-        let tri_vertices = match [positions.get(a), positions.get(b), positions.get(c)] {
-            [Some(a), Some(b), Some(c)] => [Vec3::from(*a), Vec3::from(*b), Vec3::from(*c)],
-            _ => return None,
-        };
+/// Computes the [`RayMeshHit`] of `ray`, in the space of the mesh, hitting the triangle at `tri_idx`.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "this is shared by ray casts with and without a bvh"
+)]
+fn triangle_hit<I>(
+    ray: &Ray3d,
+    mesh_transform: &Affine3A,
+    positions: &[[f32; 3]],
+    vertex_normals: Option<&[[f32; 3]]>,
+    indices: Option<&[I]>,
+    uvs: Option<&[[f32; 2]]>,
+    tri_idx: usize,
+    hit: RayTriangleHit,
+) -> Option<RayMeshHit>
+where
+    I: TryInto<usize> + Clone + Copy,
+{
+    let [a, b, c] = match indices {
+        Some(indices) => {
+            let [i, j, k] = [tri_idx * 3, tri_idx * 3 + 1, tri_idx * 3 + 2];
+            [
+                indices.get(i).copied()?.try_into().ok()?,
+                indices.get(j).copied()?.try_into().ok()?,
+                indices.get(k).copied()?.try_into().ok()?,
+            ]
+        }
+        None => [tri_idx * 3, tri_idx * 3 + 1, tri_idx * 3 + 2],
+    };
 
-        let tri_normals = vertex_normals.and_then(|normals| {
-            let [Some(a), Some(b), Some(c)] = [normals.get(a), normals.get(b), normals.get(c)]
-            else {
-                return None;
-            };
-            Some([Vec3::from(*a), Vec3::from(*b), Vec3::from(*c)])
-        });
+    let tri_vertices = match [positions.get(a), positions.get(b), positions.get(c)] {
+        [Some(a), Some(b), Some(c)] => [Vec3::from(*a), Vec3::from(*b), Vec3::from(*c)],
+        _ => return None,
+    };
 
-        let point = ray.get_point(hit.distance);
-        // Note that we need to convert from the Möller-Trumbore convention to the more common
-        // P = uA + vB + (1 - u - v)C convention.
-        let u = hit.barycentric_coords.0;
-        let v = hit.barycentric_coords.1;
-        let w = 1.0 - u - v;
-        let barycentric = Vec3::new(w, u, v);
+    let tri_normals = vertex_normals.and_then(|normals| {
+        let [Some(a), Some(b), Some(c)] = [normals.get(a), normals.get(b), normals.get(c)] else {
+            return None;
+        };
+        Some([Vec3::from(*a), Vec3::from(*b), Vec3::from(*c)])
+    });
+
+    let point = ray.get_point(hit.distance);
+    // Note that we need to convert from the Möller-Trumbore convention to the more common
+    // P = uA + vB + (1 - u - v)C convention.
+    let u = hit.barycentric_coords.0;
+    let v = hit.barycentric_coords.1;
+    let w = 1.0 - u - v;
+    let barycentric = Vec3::new(w, u, v);
+
+    let normal = if let Some(normals) = tri_normals {
+        normals[1] * u + normals[2] * v + normals[0] * w
+    } else {
+        (tri_vertices[1] - tri_vertices[0])
+            .cross(tri_vertices[2] - tri_vertices[0])
+            .normalize()
+    };
 
-        let normal = if let Some(normals) = tri_normals {
-            normals[1] * u + normals[2] * v + normals[0] * w
+    let uv = uvs.and_then(|uvs| {
+        let tri_uvs = if let Some(indices) = indices {
+            let i = tri_idx * 3;
+            [
+                uvs[indices[i].try_into().ok()?],
+                uvs[indices[i + 1].try_into().ok()?],
+                uvs[indices[i + 2].try_into().ok()?],
+            ]
         } else {
-            (tri_vertices[1] - tri_vertices[0])
-                .cross(tri_vertices[2] - tri_vertices[0])
-                .normalize()
+            let i = tri_idx * 3;
+            [uvs[i], uvs[i + 1], uvs[i + 2]]
         };
-
-        let uv = uvs.and_then(|uvs| {
-            let tri_uvs = if let Some(indices) = indices {
-                let i = tri_idx * 3;
-                [
-                    uvs[indices[i].try_into().ok()?],
-                    uvs[indices[i + 1].try_into().ok()?],
-                    uvs[indices[i + 2].try_into().ok()?],
-                ]
-            } else {
-                let i = tri_idx * 3;
-                [uvs[i], uvs[i + 1], uvs[i + 2]]
-            };
-            Some(
-                barycentric.x * Vec2::from(tri_uvs[0])
-                    + barycentric.y * Vec2::from(tri_uvs[1])
-                    + barycentric.z * Vec2::from(tri_uvs[2]),
-            )
-        });
-
-        Some(RayMeshHit {
-            point: mesh_transform.transform_point3(point),
-            normal: mesh_transform.transform_vector3(normal),
-            uv,
-            barycentric_coords: barycentric,
-            distance: mesh_transform
-                .transform_vector3(ray.direction * hit.distance)
-                .length(),
-            triangle: Some(tri_vertices.map(|v| mesh_transform.transform_point3(v))),
-            triangle_index: Some(tri_idx),
-        })
+        Some(
+            barycentric.x * Vec2::from(tri_uvs[0])
+                + barycentric.y * Vec2::from(tri_uvs[1])
+                + barycentric.z * Vec2::from(tri_uvs[2]),
+        )
+    });
+
+    Some(RayMeshHit {
+        point: mesh_transform.transform_point3(point),
+        normal: mesh_transform.transform_vector3(normal),
+        uv,
+        barycentric_coords: barycentric,
+        distance: mesh_transform
+            .transform_vector3(ray.direction * hit.distance)
+            .length(),
+        triangle: Some(tri_vertices.map(|v| mesh_transform.transform_point3(v))),
+        triangle_index: Some(tri_idx),
     })
 }
 
 /// Takes a ray and triangle and computes the intersection.
 #[inline]
-fn ray_triangle_intersection(
+pub(super) fn ray_triangle_intersection(
     ray: &Ray3d,
     triangle: &[Vec3; 3],
     backface_culling: Backfaces,
@@ -2,6 +2,7 @@
 //!
 //! See the [`MeshRayCast`] system parameter for more information.
 
+pub mod bvh;
 mod intersections;
 
 use bevy_derive::{Deref, DerefMut};
@@ -14,6 +15,7 @@ use bevy_math::{bounding::Aabb3d, Ray3d};
 use bevy_mesh::{Mesh, Mesh2d, Mesh3d};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 
+use bvh::{MeshBvh, MeshBvhs, RayCastBroadphase};
 use intersections::*;
 pub use intersections::{ray_aabb_intersection_3d, ray_mesh_intersection, RayMeshHit};
 
@@ -114,7 +116,7 @@ pub struct RayCastBackfaces;
 #[reflect(Component, Debug, Clone)]
 pub struct SimplifiedMesh(pub Handle<Mesh>);
 
-type MeshFilter = Or<(With<Mesh3d>, With<Mesh2d>, With<SimplifiedMesh>)>;
+pub(crate) type MeshFilter = Or<(With<Mesh3d>, With<Mesh2d>, With<SimplifiedMesh>)>;
 
 /// Add this ray casting [`SystemParam`] to your system to cast rays into the world with an
 /// immediate-mode API. Call `cast_ray` to immediately perform a ray cast and get a result.
@@ -136,6 +138,12 @@ type MeshFilter = Or<(With<Mesh3d>, With<Mesh2d>, With<SimplifiedMesh>)>;
 /// }
 /// ```
 ///
+/// ## Acceleration
+///
+/// By default, every mesh entity and every triangle of the meshes in the path of the ray are tested. Add the
+/// [`MeshRayCastBvhPlugin`](bvh::MeshRayCastBvhPlugin) to use bounding volume hierarchies instead, which is much
+/// faster for large scenes and meshes with many triangles.
+///
 /// ## Configuration
 ///
 /// You can specify the behavior of the ray cast using [`MeshRayCastSettings`]. This allows you to filter out
@@ -205,6 +213,10 @@ pub struct MeshRayCast<'w, 's> {
         ),
         MeshFilter,
     >,
+    #[doc(hidden)]
+    pub mesh_bvhs: Option<Res<'w, MeshBvhs>>,
+    #[doc(hidden)]
+    pub broadphase: Option<Res<'w, RayCastBroadphase>>,
 }
 
 impl<'w, 's> MeshRayCast<'w, 's> {
@@ -223,27 +235,46 @@ impl<'w, 's> MeshRayCast<'w, 's> {
 
         // Check all entities to see if the ray intersects the AABB. Use this to build a short list
         // of entities that are in the path of the ray.
-        let (aabb_hits_tx, aabb_hits_rx) = crossbeam_channel::unbounded::<(FloatOrd, Entity)>();
         let visibility_setting = settings.visibility;
-        self.culling_query.par_iter().for_each(
-            |(inherited_visibility, view_visibility, aabb, transform, entity)| {
-                let should_ray_cast = match visibility_setting {
-                    RayCastVisibility::Any => true,
-                    RayCastVisibility::Visible => inherited_visibility.get(),
-                    RayCastVisibility::VisibleInView => view_visibility.get(),
-                };
-                if should_ray_cast
-                    && let Some(distance) = ray_aabb_intersection_3d(
-                        ray,
-                        &Aabb3d::new(aabb.center, aabb.half_extents),
-                        &transform.affine(),
-                    )
-                {
+        let aabb_hit = |(inherited_visibility, view_visibility, aabb, transform, _): (
+            &InheritedVisibility,
+            &ViewVisibility,
+            &Aabb,
+            &GlobalTransform,
+            Entity,
+        )| {
+            let should_ray_cast = match visibility_setting {
+                RayCastVisibility::Any => true,
+                RayCastVisibility::Visible => inherited_visibility.get(),
+                RayCastVisibility::VisibleInView => view_visibility.get(),
+            };
+            if !should_ray_cast {
+                return None;
+            }
+            ray_aabb_intersection_3d(
+                ray,
+                &Aabb3d::new(aabb.center, aabb.half_extents),
+                &transform.affine(),
+            )
+        };
+        if let Some(broadphase) = &self.broadphase {
+            // Only the entities whose world space bounds are in the path of the ray need to be checked.
+            let culled_list = &mut *self.culled_list;
+            broadphase.cast_ray(ray, |entity| {
+                if let Some(distance) = self.culling_query.get(entity).ok().and_then(aabb_hit) {
+                    culled_list.push((FloatOrd(distance), entity));
+                }
+            });
+        } else {
+            let (aabb_hits_tx, aabb_hits_rx) = crossbeam_channel::unbounded::<(FloatOrd, Entity)>();
+            self.culling_query.par_iter().for_each(|item| {
+                let entity = item.4;
+                if let Some(distance) = aabb_hit(item) {
                     aabb_hits_tx.send((FloatOrd(distance), entity)).ok();
                 }
-            },
-        );
-        *self.culled_list = aabb_hits_rx.try_iter().collect();
+            });
+            *self.culled_list = aabb_hits_rx.try_iter().collect();
+        }
 
         // Sort by the distance along the ray.
         self.culled_list.sort_by_key(|(aabb_near, _)| *aabb_near);
@@ -291,7 +322,12 @@ impl<'w, 's> MeshRayCast<'w, 's> {
                 // Perform the actual ray cast.
                 let _ray_cast_guard = ray_cast_guard.enter();
                 let transform = transform.affine();
-                let intersection = ray_intersection_over_mesh(mesh, &transform, ray, backfaces);
+                let bvh: Option<&MeshBvh> = self
+                    .mesh_bvhs
+                    .as_ref()
+                    .and_then(|bvhs| bvhs.get(mesh_handle));
+                let intersection =
+                    ray_intersection_over_mesh(mesh, &transform, ray, backfaces, bvh);
 
                 if let Some(intersection) = intersection {
                     let distance = FloatOrd(intersection.distance);
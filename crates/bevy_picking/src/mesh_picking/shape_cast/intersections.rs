@@ -0,0 +1,268 @@
+use bevy_math::{ops, Vec3};
+
+/// Returns the point of the triangle closest to `point`.
+///
+/// Source: Christer Ericson, Real-Time Collision Detection, section 5.1.5.
+pub fn closest_point_on_triangle(point: Vec3, [a, b, c]: [Vec3; 3]) -> Vec3 {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = point - a;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+
+    let bp = point - b;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = point - c;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denominator = 1.0 / (va + vb + vc);
+    a + ab * (vb * denominator) + ac * (vc * denominator)
+}
+
+/// Checks if a triangle overlaps an axis-aligned box, with the separating axis test.
+///
+/// Source: Tomas Akenine-Möller, Fast 3D Triangle-Box Overlap Testing.
+pub fn triangle_aabb_overlap(triangle: [Vec3; 3], center: Vec3, half_extents: Vec3) -> bool {
+    let [v0, v1, v2] = triangle.map(|v| v - center);
+    let separated = |axis: Vec3| {
+        let (p0, p1, p2) = (v0.dot(axis), v1.dot(axis), v2.dot(axis));
+        let radius = half_extents.dot(axis.abs());
+        p0.min(p1).min(p2) > radius || p0.max(p1).max(p2) < -radius
+    };
+
+    // The axes of the box.
+    if (0..3).any(|i| separated(Vec3::AXES[i])) {
+        return false;
+    }
+
+    // The normal of the triangle.
+    let edges = [v1 - v0, v2 - v1, v0 - v2];
+    if separated(edges[0].cross(edges[1])) {
+        return false;
+    }
+
+    // The cross products of the edges of the triangle with the axes of the box.
+    !edges
+        .iter()
+        .any(|edge| Vec3::AXES.iter().any(|axis| separated(axis.cross(*edge))))
+}
+
+/// A hit between a sphere moving along a ray and a triangle.
+#[derive(Debug, Clone, Copy)]
+pub struct SphereTriangleHit {
+    /// The distance the sphere traveled along the ray.
+    pub distance: f32,
+    /// The point of the triangle the sphere touches.
+    pub point: Vec3,
+}
+
+/// Casts a sphere of `radius` from `origin` along the normalized `direction`, and returns the first contact with
+/// the triangle. Both sides of the triangle are hit.
+///
+/// If the sphere already overlaps the triangle at `origin`, the distance is zero.
+pub fn sphere_triangle_cast(
+    origin: Vec3,
+    direction: Vec3,
+    radius: f32,
+    triangle: [Vec3; 3],
+) -> Option<SphereTriangleHit> {
+    let closest = closest_point_on_triangle(origin, triangle);
+    if closest.distance_squared(origin) <= radius * radius {
+        return Some(SphereTriangleHit {
+            distance: 0.0,
+            point: closest,
+        });
+    }
+
+    let [a, b, c] = triangle;
+    let mut nearest: Option<SphereTriangleHit> = None;
+    let mut consider = |distance: f32, point: Vec3| {
+        if distance >= 0.0 && nearest.is_none_or(|hit| distance < hit.distance) {
+            nearest = Some(SphereTriangleHit { distance, point });
+        }
+    };
+
+    // The sphere touching the face of the triangle.
+    let normal = (b - a).cross(c - a);
+    if let Some(unit_normal) = normal.try_normalize() {
+        let signed_distance = unit_normal.dot(origin - a);
+        let facing = if signed_distance < 0.0 {
+            -unit_normal
+        } else {
+            unit_normal
+        };
+        let approach = facing.dot(direction);
+        if approach < 0.0 {
+            let distance = (signed_distance.abs() - radius) / -approach;
+            let point = origin + direction * distance - facing * radius;
+            let inside = [(a, b), (b, c), (c, a)]
+                .iter()
+                .all(|&(p, q)| (q - p).cross(point - p).dot(normal) >= 0.0);
+            if inside {
+                consider(distance, point);
+            }
+        }
+    }
+
+    // The sphere touching an edge.
+    for (p, q) in [(a, b), (b, c), (c, a)] {
+        if let Some((distance, point)) = ray_cylinder_cast(origin, direction, radius, p, q) {
+            consider(distance, point);
+        }
+    }
+
+    // The sphere touching a vertex.
+    for vertex in triangle {
+        if let Some(distance) = ray_sphere_cast(origin, direction, radius, vertex) {
+            consider(distance, vertex);
+        }
+    }
+
+    nearest
+}
+
+/// Returns the distance along the ray to a sphere, if the ray starts outside of it.
+fn ray_sphere_cast(origin: Vec3, direction: Vec3, radius: f32, center: Vec3) -> Option<f32> {
+    let offset = origin - center;
+    let b = offset.dot(direction);
+    let c = offset.length_squared() - radius * radius;
+    if c > 0.0 && b > 0.0 {
+        return None;
+    }
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let distance = -b - ops::sqrt(discriminant);
+    (distance >= 0.0).then_some(distance)
+}
+
+/// Returns the distance along the ray to the side of a cylinder around the segment `p` to `q`, and the point of
+/// the segment closest to the hit.
+fn ray_cylinder_cast(
+    origin: Vec3,
+    direction: Vec3,
+    radius: f32,
+    p: Vec3,
+    q: Vec3,
+) -> Option<(f32, Vec3)> {
+    let axis = q - p;
+    let axis_length_squared = axis.length_squared();
+    if axis_length_squared <= f32::EPSILON {
+        return None;
+    }
+    let offset = origin - p;
+    let direction_across = direction - axis * (direction.dot(axis) / axis_length_squared);
+    let offset_across = offset - axis * (offset.dot(axis) / axis_length_squared);
+
+    let a = direction_across.length_squared();
+    if a <= f32::EPSILON {
+        return None;
+    }
+    let b = 2.0 * offset_across.dot(direction_across);
+    let c = offset_across.length_squared() - radius * radius;
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let distance = (-b - ops::sqrt(discriminant)) / (2.0 * a);
+    if distance < 0.0 {
+        return None;
+    }
+    let along = (offset + direction * distance).dot(axis) / axis_length_squared;
+    (0.0..=1.0)
+        .contains(&along)
+        .then(|| (distance, p + axis * along))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIANGLE: [Vec3; 3] = [
+        Vec3::new(-1.0, 0.0, -1.0),
+        Vec3::new(1.0, 0.0, -1.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    ];
+
+    #[test]
+    fn closest_point() {
+        let point = closest_point_on_triangle(Vec3::new(0.0, 2.0, 0.0), TRIANGLE);
+        assert_eq!(point, Vec3::ZERO);
+        let point = closest_point_on_triangle(Vec3::new(0.0, 0.0, -3.0), TRIANGLE);
+        assert_eq!(point, Vec3::new(0.0, 0.0, -1.0));
+        let point = closest_point_on_triangle(Vec3::new(5.0, 1.0, -5.0), TRIANGLE);
+        assert_eq!(point, TRIANGLE[1]);
+    }
+
+    #[test]
+    fn aabb_overlap() {
+        assert!(triangle_aabb_overlap(
+            TRIANGLE,
+            Vec3::ZERO,
+            Vec3::splat(0.1)
+        ));
+        assert!(!triangle_aabb_overlap(
+            TRIANGLE,
+            Vec3::new(0.0, 0.5, 0.0),
+            Vec3::splat(0.1)
+        ));
+        // Only the box's corner is near the triangle, but outside of its edge.
+        assert!(!triangle_aabb_overlap(
+            TRIANGLE,
+            Vec3::new(1.0, 0.0, 0.5),
+            Vec3::splat(0.3)
+        ));
+    }
+
+    #[test]
+    fn sphere_cast() {
+        // Hits the face.
+        let hit =
+            sphere_triangle_cast(Vec3::new(0.0, 2.0, 0.0), Vec3::NEG_Y, 0.5, TRIANGLE).unwrap();
+        assert!((hit.distance - 1.5).abs() < 1e-5);
+        assert!(hit.point.distance(Vec3::ZERO) < 1e-5);
+
+        // Grazes the edge at z = -1, which a ray would miss.
+        let hit =
+            sphere_triangle_cast(Vec3::new(0.0, 2.0, -1.4), Vec3::NEG_Y, 0.5, TRIANGLE).unwrap();
+        assert!(hit.point.distance(Vec3::new(0.0, 0.0, -1.0)) < 1e-5);
+        assert!((hit.distance - (2.0 - ops::sqrt(0.5 * 0.5 - 0.4 * 0.4))).abs() < 1e-5);
+
+        // Touches a vertex.
+        let hit = sphere_triangle_cast(Vec3::new(-3.0, 0.0, -1.0), Vec3::X, 0.5, TRIANGLE).unwrap();
+        assert!((hit.distance - 1.5).abs() < 1e-5);
+        assert!(hit.point.distance(TRIANGLE[0]) < 1e-5);
+
+        assert!(
+            sphere_triangle_cast(Vec3::new(0.0, 2.0, -2.0), Vec3::NEG_Y, 0.5, TRIANGLE).is_none()
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! Shape casts and overlap queries against meshes.
+//!
+//! See the [`MeshShapeCast`] system parameter for more information.
+
+mod intersections;
+
+pub use intersections::{
+    closest_point_on_triangle, sphere_triangle_cast, triangle_aabb_overlap, SphereTriangleHit,
+};
+
+use bevy_asset::Assets;
+use bevy_camera::{
+    primitives::Aabb,
+    visibility::{InheritedVisibility, ViewVisibility},
+};
+use bevy_ecs::{prelude::*, system::lifetimeless::Read, system::SystemParam};
+use bevy_math::{
+    bounding::{Aabb3d, BoundingSphere},
+    FloatOrd, Ray3d, Vec3, Vec3A,
+};
+use bevy_mesh::{Indices, Mesh, Mesh2d, Mesh3d, PrimitiveTopology};
+use bevy_reflect::Reflect;
+use bevy_transform::components::GlobalTransform;
+
+use crate::mesh_picking::ray_cast::{
+    bvh::{
+        bounds_overlap, transform_bounds, triangle, triangle_count, MeshBvh, MeshBvhs,
+        RayCastBroadphase,
+    },
+    MeshFilter, MeshRayCastSettings, RayCastVisibility, SimplifiedMesh,
+};
+
+/// A hit between a shape cast with [`MeshShapeCast`] and a mesh.
+#[derive(Debug, Clone, Reflect)]
+#[reflect(Clone)]
+pub struct ShapeCastHit {
+    /// The point of the mesh the shape touches, in world space.
+    pub point: Vec3,
+    /// The direction from the point to the center of the shape when it touches the mesh, in world space.
+    pub normal: Vec3,
+    /// The distance the shape traveled along the ray.
+    pub distance: f32,
+    /// The vertices of the triangle that was hit, in world space.
+    pub triangle: [Vec3; 3],
+    /// The index of the triangle that was hit.
+    pub triangle_index: usize,
+}
+
+/// The point of a mesh closest to another point, found with [`MeshShapeCast::closest_point`].
+#[derive(Debug, Clone, Reflect)]
+#[reflect(Clone)]
+pub struct ClosestPoint {
+    /// The closest point of the mesh, in world space.
+    pub point: Vec3,
+    /// The distance between the points.
+    pub distance: f32,
+    /// The index of the triangle the point is on.
+    pub triangle_index: usize,
+}
+
+/// Add this [`SystemParam`] to your system to cast shapes and query overlaps against meshes, without a physics
+/// engine. It is the counterpart of [`MeshRayCast`](crate::mesh_picking::ray_cast::MeshRayCast) for volumes:
+///
+/// - [`cast_sphere`](Self::cast_sphere) moves a sphere along a ray and returns the meshes it touches.
+/// - [`overlap_aabb`](Self::overlap_aabb) and [`overlap_sphere`](Self::overlap_sphere) return the meshes that
+///   intersect a volume, for example for selection marquees.
+/// - [`closest_point`](Self::closest_point) returns the point of any mesh closest to a point.
+///
+/// Every query takes a [`MeshRayCastSettings`] to filter entities and handle their visibility. Queries are exact
+/// against the triangles of the meshes, which are tested in world space, so scaled meshes are supported. Both
+/// sides of the triangles are always considered. If the
+/// [`MeshRayCastBvhPlugin`](crate::mesh_picking::ray_cast::bvh::MeshRayCastBvhPlugin) is added, its bounding
+/// volume hierarchies are used to find candidate entities and triangles.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{bounding::Aabb3d, prelude::*};
+/// # use bevy_picking::prelude::*;
+/// # use bevy_picking::mesh_picking::shape_cast::MeshShapeCast;
+/// fn line_of_sight(mut shape_cast: MeshShapeCast) {
+///     let settings = MeshRayCastSettings::default();
+///
+///     // Is there anything within 10 units in front of the player, for a character with a radius of 0.5?
+///     let ray = Ray3d::new(Vec3::ZERO, Dir3::NEG_Z);
+///     let blocked = !shape_cast.cast_sphere(ray, 0.5, 10.0, &settings).is_empty();
+///
+///     // Which meshes are in this box?
+///     let selected = shape_cast.overlap_aabb(Aabb3d::new(Vec3::ZERO, Vec3::ONE), &settings);
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct MeshShapeCast<'w, 's> {
+    #[doc(hidden)]
+    pub meshes: Res<'w, Assets<Mesh>>,
+    #[doc(hidden)]
+    pub mesh_bvhs: Option<Res<'w, MeshBvhs>>,
+    #[doc(hidden)]
+    pub broadphase: Option<Res<'w, RayCastBroadphase>>,
+    #[doc(hidden)]
+    pub candidates: Local<'s, Vec<Entity>>,
+    #[doc(hidden)]
+    pub hits: Local<'s, Vec<(Entity, ShapeCastHit)>>,
+    #[doc(hidden)]
+    pub overlaps: Local<'s, Vec<Entity>>,
+    #[doc(hidden)]
+    pub culling_query: Query<
+        'w,
+        's,
+        (
+            Read<InheritedVisibility>,
+            Read<ViewVisibility>,
+            Read<Aabb>,
+            Read<GlobalTransform>,
+            Entity,
+        ),
+        MeshFilter,
+    >,
+    #[doc(hidden)]
+    pub mesh_query: Query<
+        'w,
+        's,
+        (
+            Option<Read<Mesh2d>>,
+            Option<Read<Mesh3d>>,
+            Option<Read<SimplifiedMesh>>,
+            Read<GlobalTransform>,
+        ),
+        MeshFilter,
+    >,
+}
+
+impl<'w, 's> MeshShapeCast<'w, 's> {
+    /// Moves a sphere of `radius` along the `ray`, up to `max_distance`, and returns a sorted list of the meshes
+    /// it touches, nearest first.
+    ///
+    /// Meshes the sphere overlaps at the origin of the ray are hit at a distance of zero. The
+    /// [`early_exit_test`](MeshRayCastSettings::early_exit_test) works the same as for ray casts.
+    pub fn cast_sphere(
+        &mut self,
+        ray: Ray3d,
+        radius: f32,
+        max_distance: f32,
+        settings: &MeshRayCastSettings,
+    ) -> &[(Entity, ShapeCastHit)] {
+        self.hits.clear();
+        let origin = ray.origin;
+        let direction = *ray.direction;
+        let bounds = max_distance.is_finite().then(|| {
+            let end = origin + direction * max_distance;
+            Aabb3d {
+                min: Vec3A::from(origin.min(end) - radius),
+                max: Vec3A::from(origin.max(end) + radius),
+            }
+        });
+        self.find_candidates(bounds.as_ref(), settings);
+
+        let mut nearest_blocking_hit = FloatOrd(max_distance);
+        for i in 0..self.candidates.len() {
+            let entity = self.candidates[i];
+            let mut nearest: Option<ShapeCastHit> = None;
+            self.for_each_triangle(entity, bounds.as_ref(), |triangle_index, triangle| {
+                let Some(hit) = sphere_triangle_cast(origin, direction, radius, triangle) else {
+                    return;
+                };
+                if hit.distance > max_distance
+                    || nearest
+                        .as_ref()
+                        .is_some_and(|nearest| nearest.distance <= hit.distance)
+                {
+                    return;
+                }
+                let center = origin + direction * hit.distance;
+                nearest = Some(ShapeCastHit {
+                    point: hit.point,
+                    normal: (center - hit.point).try_normalize().unwrap_or(-direction),
+                    distance: hit.distance,
+                    triangle,
+                    triangle_index,
+                });
+            });
+            if let Some(hit) = nearest {
+                let distance = FloatOrd(hit.distance);
+                if (settings.early_exit_test)(entity) && distance < nearest_blocking_hit {
+                    nearest_blocking_hit = distance;
+                }
+                self.hits.push((entity, hit));
+            }
+        }
+
+        self.hits
+            .retain(|(_, hit)| FloatOrd(hit.distance) <= nearest_blocking_hit);
+        self.hits.sort_by_key(|(_, hit)| FloatOrd(hit.distance));
+        self.hits.as_ref()
+    }
+
+    /// Returns the meshes with a triangle that intersects the axis-aligned box, in world space.
+    pub fn overlap_aabb(&mut self, aabb: Aabb3d, settings: &MeshRayCastSettings) -> &[Entity] {
+        let center = Vec3::from((aabb.min + aabb.max) * 0.5);
+        let half_extents = Vec3::from((aabb.max - aabb.min) * 0.5);
+        self.overlap(&aabb, settings, |triangle| {
+            triangle_aabb_overlap(triangle, center, half_extents)
+        })
+    }
+
+    /// Returns the meshes with a triangle that intersects the sphere, in world space.
+    pub fn overlap_sphere(
+        &mut self,
+        sphere: BoundingSphere,
+        settings: &MeshRayCastSettings,
+    ) -> &[Entity] {
+        let center = Vec3::from(sphere.center);
+        let radius = sphere.radius();
+        let bounds = Aabb3d::new(center, Vec3::splat(radius));
+        self.overlap(&bounds, settings, |triangle| {
+            closest_point_on_triangle(center, triangle).distance_squared(center) <= radius * radius
+        })
+    }
+
+    /// Returns the point of any mesh closest to `point`, if there is one within `max_distance`.
+    pub fn closest_point(
+        &mut self,
+        point: Vec3,
+        max_distance: f32,
+        settings: &MeshRayCastSettings,
+    ) -> Option<(Entity, ClosestPoint)> {
+        let bounds = max_distance
+            .is_finite()
+            .then(|| Aabb3d::new(point, Vec3::splat(max_distance)));
+        self.find_candidates(bounds.as_ref(), settings);
+
+        let mut closest: Option<(Entity, ClosestPoint)> = None;
+        for i in 0..self.candidates.len() {
+            let entity = self.candidates[i];
+            self.for_each_triangle(entity, bounds.as_ref(), |triangle_index, triangle| {
+                let candidate = closest_point_on_triangle(point, triangle);
+                let distance = candidate.distance(point);
+                if distance > max_distance
+                    || closest
+                        .as_ref()
+                        .is_some_and(|(_, closest)| closest.distance <= distance)
+                {
+                    return;
+                }
+                closest = Some((
+                    entity,
+                    ClosestPoint {
+                        point: candidate,
+                        distance,
+                        triangle_index,
+                    },
+                ));
+            });
+        }
+        closest
+    }
+
+    /// Returns the candidates with a triangle for which `overlaps` returns `true`.
+    fn overlap(
+        &mut self,
+        bounds: &Aabb3d,
+        settings: &MeshRayCastSettings,
+        overlaps: impl Fn([Vec3; 3]) -> bool,
+    ) -> &[Entity] {
+        self.overlaps.clear();
+        self.find_candidates(Some(bounds), settings);
+        for i in 0..self.candidates.len() {
+            let entity = self.candidates[i];
+            let mut found = false;
+            self.for_each_triangle(entity, Some(bounds), |_, triangle| {
+                found = found || overlaps(triangle);
+            });
+            if found {
+                self.overlaps.push(entity);
+            }
+        }
+        self.overlaps.as_ref()
+    }
+
+    /// Collects the visible and filtered entities whose world space bounds overlap `bounds`, or every one of them
+    /// if there are no bounds.
+    fn find_candidates(&mut self, bounds: Option<&Aabb3d>, settings: &MeshRayCastSettings) {
+        self.candidates.clear();
+        let visible = |inherited_visibility: &InheritedVisibility,
+                       view_visibility: &ViewVisibility| match settings
+            .visibility
+        {
+            RayCastVisibility::Any => true,
+            RayCastVisibility::Visible => inherited_visibility.get(),
+            RayCastVisibility::VisibleInView => view_visibility.get(),
+        };
+
+        if let (Some(broadphase), Some(bounds)) = (&self.broadphase, bounds) {
+            let candidates = &mut *self.candidates;
+            broadphase.overlap(bounds, |entity| {
+                if let Ok((inherited_visibility, view_visibility, ..)) =
+                    self.culling_query.get(entity)
+                    && visible(inherited_visibility, view_visibility)
+                    && (settings.filter)(entity)
+                {
+                    candidates.push(entity);
+                }
+            });
+            return;
+        }
+
+        for (inherited_visibility, view_visibility, aabb, transform, entity) in &self.culling_query
+        {
+            if !visible(inherited_visibility, view_visibility) {
+                continue;
+            }
+            if let Some(bounds) = bounds {
+                let entity_bounds = transform_bounds(
+                    &Aabb3d::new(aabb.center, aabb.half_extents),
+                    &transform.affine(),
+                );
+                if !bounds_overlap(&entity_bounds, bounds) {
+                    continue;
+                }
+            }
+            if (settings.filter)(entity) {
+                self.candidates.push(entity);
+            }
+        }
+    }
+
+    /// Calls `f` with the index and world space vertices of every triangle of the mesh of `entity` that may
+    /// overlap `bounds`, or of every triangle if there are no bounds.
+    fn for_each_triangle(
+        &self,
+        entity: Entity,
+        bounds: Option<&Aabb3d>,
+        mut f: impl FnMut(usize, [Vec3; 3]),
+    ) {
+        let Ok((mesh2d, mesh3d, simplified_mesh, transform)) = self.mesh_query.get(entity) else {
+            return;
+        };
+        let Some(mesh_handle) = simplified_mesh
+            .map(|m| &m.0)
+            .or(mesh3d.map(|m| &m.0).or(mesh2d.map(|m| &m.0)))
+        else {
+            return;
+        };
+        let Some(mesh) = self.meshes.get(mesh_handle) else {
+            return;
+        };
+        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+            return;
+        }
+        let Some(positions) = mesh
+            .try_attribute(Mesh::ATTRIBUTE_POSITION)
+            .ok()
+            .and_then(|positions| positions.as_float3())
+        else {
+            return;
+        };
+        let indices = mesh.try_indices().ok();
+        let affine = transform.affine();
+        let bvh = self
+            .mesh_bvhs
+            .as_ref()
+            .and_then(|bvhs| bvhs.get(mesh_handle))
+            .filter(|bvh| bvh.triangle_count() == triangle_count(positions, indices));
+
+        let mesh_bounds = bounds.map(|bounds| transform_bounds(bounds, &affine.inverse()));
+        let mut visit = |triangle_index: usize| {
+            let Some(vertices) = triangle(positions, indices, triangle_index) else {
+                return;
+            };
+            f(triangle_index, vertices.map(|v| affine.transform_point3(v)));
+        };
+        for_each_candidate_triangle(positions, indices, bvh, mesh_bounds.as_ref(), &mut visit);
+    }
+}
+
+/// Calls `f` with the index of every triangle that may overlap `bounds`, in the space of the mesh.
+fn for_each_candidate_triangle(
+    positions: &[[f32; 3]],
+    indices: Option<&Indices>,
+    bvh: Option<&MeshBvh>,
+    bounds: Option<&Aabb3d>,
+    f: &mut impl FnMut(usize),
+) {
+    match (bvh, bounds) {
+        (Some(bvh), Some(bounds)) => bvh.overlapping(bounds, f),
+        (_, Some(bounds)) => {
+            for triangle_index in 0..triangle_count(positions, indices) {
+                let Some([a, b, c]) = triangle(positions, indices, triangle_index) else {
+                    continue;
+                };
+                let triangle_bounds = Aabb3d {
+                    min: a.min(b).min(c).into(),
+                    max: a.max(b).max(c).into(),
+                };
+                if bounds_overlap(&triangle_bounds, bounds) {
+                    f(triangle_index);
+                }
+            }
+        }
+        (_, None) => (0..triangle_count(positions, indices)).for_each(f),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::App;
+    use bevy_camera::primitives::MeshAabb;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_math::{primitives::Cuboid, Dir3};
+    use bevy_transform::components::Transform;
+
+    #[test]
+    fn shape_queries_find_meshes() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>();
+
+        let mesh = Mesh::from(Cuboid::from_length(2.0));
+        let aabb = mesh.get_aabb().unwrap();
+        let handle = app.world_mut().resource_mut::<Assets<Mesh>>().add(mesh);
+        let mut spawn_cube = |x: f32| {
+            app.world_mut()
+                .spawn((
+                    Mesh3d(handle.clone()),
+                    aabb,
+                    GlobalTransform::from(Transform::from_xyz(x, 0.0, 0.0)),
+                    InheritedVisibility::VISIBLE,
+                    ViewVisibility::default(),
+                ))
+                .id()
+        };
+        let near = spawn_cube(5.0);
+        let far = spawn_cube(10.0);
+
+        app.world_mut()
+            .run_system_once(move |mut shape_cast: MeshShapeCast| {
+                let settings =
+                    MeshRayCastSettings::default().with_visibility(RayCastVisibility::Visible);
+
+                // A ray 1.2 units above the cubes misses them, but a sphere of radius 0.5 does not.
+                let ray = Ray3d::new(Vec3::new(0.0, 1.2, 0.0), Dir3::X);
+                let hits = shape_cast.cast_sphere(ray, 0.5, f32::MAX, &settings);
+                assert_eq!(hits.len(), 1);
+                assert_eq!(hits[0].0, near);
+                assert!(hits[0].1.distance < 4.0);
+                assert!(shape_cast
+                    .cast_sphere(ray, 0.1, f32::MAX, &settings)
+                    .is_empty());
+                assert!(shape_cast.cast_sphere(ray, 0.5, 1.0, &settings).is_empty());
+
+                let overlaps = shape_cast.overlap_aabb(
+                    Aabb3d::new(Vec3::new(7.0, 0.0, 0.0), Vec3::splat(2.5)),
+                    &settings,
+                );
+                assert_eq!(overlaps.len(), 2);
+                let overlaps = shape_cast.overlap_sphere(
+                    BoundingSphere::new(Vec3::new(10.0, 0.0, 0.0), 0.5),
+                    &settings,
+                );
+                assert!(overlaps.is_empty(), "the sphere is inside the hollow cube");
+
+                let (entity, closest) = shape_cast
+                    .closest_point(Vec3::new(7.8, 0.0, 0.0), f32::MAX, &settings)
+                    .unwrap();
+                assert_eq!(entity, far);
+                assert!((closest.distance - 1.2).abs() < 1e-5);
+                assert!(shape_cast
+                    .closest_point(Vec3::new(7.8, 0.0, 0.0), 1.0, &settings)
+                    .is_none());
+            })
+            .unwrap();
+    }
+}
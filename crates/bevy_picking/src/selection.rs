@@ -0,0 +1,745 @@
+//! Selection of entities by clicking them, or by dragging a marquee around them.
+//!
+//! Add the [`SelectionPlugin`] to let pointers select entities, which are marked with the [`Selected`]
+//! component:
+//!
+//! - Clicking a hovered entity selects it, and clicking empty space clears the selection.
+//! - Dragging from empty space draws a [`Marquee`], a rectangle or a lasso. When the pointer is released, every
+//!   entity whose bounds project inside it is selected.
+//!
+//! Holding one of the [`add_keys`](SelectionSettings::add_keys) adds to the selection, and holding one of the
+//! [`subtract_keys`](SelectionSettings::subtract_keys) removes from it. Every change of the selection is
+//! announced with a [`SelectionChanged`] message, and can also be observed with the [`Add`] and [`Remove`]
+//! lifecycle events of [`Selected`].
+//!
+//! Marquees are independent of picking backends: they select entities by projecting their [`Aabb`] with the
+//! cameras rendering to the render target of the pointer, so they work the same for sprites and meshes.
+//! Clicks select the entities reported by any backend, including UI nodes.
+//!
+//! ## Drawing the marquee
+//!
+//! While a marquee is being dragged, it is stored in the [`Marquee`] component of the pointer entity, in the
+//! same logical pixels as [`Location::position`]. Query it to draw the rectangle or lasso with UI nodes or gizmos.
+
+use alloc::vec::Vec;
+
+use bevy_app::prelude::*;
+use bevy_camera::{
+    primitives::Aabb,
+    visibility::{RenderLayers, ViewVisibility},
+    Camera, NormalizedRenderTarget, RenderTarget,
+};
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_math::{FloatOrd, Rect, Vec2, Vec3};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::prelude::*;
+use bevy_transform::components::GlobalTransform;
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::{
+    hover::HoverMap,
+    pointer::{Location, PointerAction, PointerButton, PointerId, PointerInput},
+    Pickable, PickingSystems,
+};
+
+/// Lets pointers select entities, see the [module docs](self).
+///
+/// This plugin requires the [`PickingPlugin`](crate::PickingPlugin) and the
+/// [`InteractionPlugin`](crate::InteractionPlugin), which are part of the
+/// [`DefaultPickingPlugins`](crate::DefaultPickingPlugins). It is not added by them.
+#[derive(Default)]
+pub struct SelectionPlugin;
+
+impl Plugin for SelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectionSettings>()
+            .add_message::<SelectionChanged>()
+            .add_systems(PreUpdate, update_selection.in_set(PickingSystems::Last));
+    }
+}
+
+/// Controls how pointers select entities with the [`SelectionPlugin`].
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource, Default, Debug, Clone)]
+pub struct SelectionSettings {
+    /// The pointer button that selects entities and drags marquees.
+    pub button: PointerButton,
+    /// The shape of the marquees.
+    pub shape: MarqueeShape,
+    /// How much of an entity must be inside a marquee to select it.
+    pub mode: MarqueeMode,
+    /// The distance in logical pixels the pointer must move while pressed before a marquee is started.
+    pub min_drag_distance: f32,
+    /// When set to `true`, only entities with the [`Selectable`] component, or whose ancestor has it when they
+    /// are clicked, can be selected. Otherwise, every pickable entity can be selected.
+    pub require_markers: bool,
+    /// Holding any of these keys adds to the selection instead of replacing it.
+    pub add_keys: Vec<KeyCode>,
+    /// Holding any of these keys removes from the selection instead of replacing it.
+    pub subtract_keys: Vec<KeyCode>,
+}
+
+impl Default for SelectionSettings {
+    fn default() -> Self {
+        Self {
+            button: PointerButton::Primary,
+            shape: MarqueeShape::Rectangle,
+            mode: MarqueeMode::Contains,
+            min_drag_distance: 4.0,
+            require_markers: false,
+            add_keys: vec![KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            subtract_keys: vec![KeyCode::ControlLeft, KeyCode::ControlRight],
+        }
+    }
+}
+
+/// The shape of a [`Marquee`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum MarqueeShape {
+    /// A rectangle between the point where the drag started and the pointer.
+    #[default]
+    Rectangle,
+    /// A polygon following the path of the pointer, closed back to where the drag started.
+    Lasso,
+}
+
+/// How much of an entity must be inside a [`Marquee`] to select it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum MarqueeMode {
+    /// The projected bounds of the entity must be entirely inside the marquee.
+    #[default]
+    Contains,
+    /// The projected bounds of the entity must touch the marquee. For lassos, a corner or the center of the
+    /// projected bounds must be inside it.
+    Intersects,
+}
+
+/// How a click or a [`Marquee`] changes the selection.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum SelectionOperation {
+    /// The picked entities become the selection.
+    #[default]
+    Replace,
+    /// The picked entities are added to the selection.
+    Add,
+    /// The picked entities are removed from the selection.
+    Subtract,
+}
+
+/// Marks an entity that can be selected when [`SelectionSettings::require_markers`] is `true`.
+///
+/// When a child of the entity is clicked, the entity is selected instead, so this can be added to the root of a
+/// scene.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct Selectable;
+
+/// Marks an entity that is selected.
+///
+/// This is inserted and removed by the [`SelectionPlugin`], but can also be inserted and removed manually.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct Selected;
+
+/// A [`Message`] written when a pointer changes the selection.
+#[derive(Message, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq, Clone)]
+pub struct SelectionChanged {
+    /// The pointer that changed the selection.
+    pub pointer_id: PointerId,
+    /// The entities that were selected.
+    pub selected: Vec<Entity>,
+    /// The entities that were deselected.
+    pub deselected: Vec<Entity>,
+}
+
+/// A marquee being dragged by a pointer, stored on the pointer entity.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug, Clone)]
+pub struct Marquee {
+    /// The render target the marquee is dragged on.
+    pub target: NormalizedRenderTarget,
+    /// The shape of the marquee.
+    pub shape: MarqueeShape,
+    /// How the marquee will change the selection.
+    pub operation: SelectionOperation,
+    /// The path of the pointer, from where the drag started to its current position.
+    pub points: Vec<Vec2>,
+}
+
+impl Marquee {
+    /// Returns the position where the drag started.
+    pub fn origin(&self) -> Vec2 {
+        self.points.first().copied().unwrap_or_default()
+    }
+
+    /// Returns the current position of the pointer.
+    pub fn end(&self) -> Vec2 {
+        self.points.last().copied().unwrap_or_default()
+    }
+
+    /// Returns the rectangle between the origin and the end of the marquee, or the bounds of the lasso.
+    pub fn rect(&self) -> Rect {
+        match self.shape {
+            MarqueeShape::Rectangle => Rect::from_corners(self.origin(), self.end()),
+            MarqueeShape::Lasso => self.points.iter().fold(
+                Rect::from_corners(self.origin(), self.origin()),
+                |rect, &point| rect.union_point(point),
+            ),
+        }
+    }
+
+    /// Returns `true` if `point` is inside the marquee.
+    pub fn contains(&self, point: Vec2) -> bool {
+        match self.shape {
+            MarqueeShape::Rectangle => self.rect().contains(point),
+            MarqueeShape::Lasso => polygon_contains(&self.points, point),
+        }
+    }
+
+    /// Returns `true` if the projected `corners` of some bounds are inside the marquee according to `mode`.
+    fn selects(&self, corners: &[Vec2], mode: MarqueeMode) -> bool {
+        match (self.shape, mode) {
+            (_, MarqueeMode::Contains) => corners.iter().all(|&corner| self.contains(corner)),
+            (MarqueeShape::Rectangle, MarqueeMode::Intersects) => {
+                let bounds = corners
+                    .iter()
+                    .fold(Rect::EMPTY, |rect, &corner| rect.union_point(corner));
+                !self.rect().intersect(bounds).is_empty()
+            }
+            (MarqueeShape::Lasso, MarqueeMode::Intersects) => {
+                let center = corners.iter().sum::<Vec2>() / corners.len() as f32;
+                corners
+                    .iter()
+                    .chain([&center])
+                    .any(|&point| self.contains(point))
+            }
+        }
+    }
+}
+
+/// Returns `true` if `point` is inside the closed polygon, with the even-odd rule.
+fn polygon_contains(polygon: &[Vec2], point: Vec2) -> bool {
+    let Some(&last) = polygon.last() else {
+        return false;
+    };
+    let mut inside = false;
+    let mut previous = last;
+    for &current in polygon {
+        if (current.y > point.y) != (previous.y > point.y) {
+            let crossing = current.x
+                + (point.y - current.y) / (previous.y - current.y) * (previous.x - current.x);
+            if point.x < crossing {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// A press of the selection button that has not been released yet.
+struct PendingPress {
+    location: Location,
+    operation: SelectionOperation,
+    /// The selectable entity under the pointer when it was pressed.
+    hovered: Option<Entity>,
+}
+
+/// What a pointer is doing with the selection button held down.
+enum PointerSelection {
+    /// The button was pressed, and the pointer has not moved far enough to start a marquee.
+    Pressed(PendingPress),
+    /// The pointer is dragging a marquee.
+    Dragging(Marquee),
+}
+
+/// Selects entities when pointers click them or release a [`Marquee`].
+///
+/// The state of each pointer is kept locally rather than read back from its [`Marquee`] component, so a press,
+/// drag and release in the same frame are handled like ones spread over several frames.
+fn update_selection(
+    mut pointer_selections: Local<HashMap<PointerId, PointerSelection>>,
+    mut pointer_inputs: MessageReader<PointerInput>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+    mut commands: Commands,
+    settings: Res<SelectionSettings>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    hover_map: Res<HoverMap>,
+    pointers: Query<(Entity, &PointerId)>,
+    selected: Query<Entity, With<Selected>>,
+    selectables: Query<(), With<Selectable>>,
+    windows: Query<(), With<Window>>,
+    ancestors: Query<&ChildOf>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    cameras: Query<(
+        &Camera,
+        &RenderTarget,
+        &GlobalTransform,
+        Option<&RenderLayers>,
+    )>,
+    targets: Query<(
+        Entity,
+        &Aabb,
+        &GlobalTransform,
+        &ViewVisibility,
+        Option<&RenderLayers>,
+        Option<&Pickable>,
+    )>,
+) {
+    let pressed = |keys_to_check: &[KeyCode]| {
+        keys.as_ref()
+            .is_some_and(|keys| keys.any_pressed(keys_to_check.iter().copied()))
+    };
+    let is_selectable = |entity: Entity| !settings.require_markers || selectables.contains(entity);
+    // The pointers whose marquee was started, changed or ended, to update their `Marquee` component.
+    let mut changed_marquees = Vec::new();
+
+    for input in pointer_inputs.read() {
+        let pointer_id = input.pointer_id;
+        match input.action {
+            PointerAction::Press(button) if button == settings.button => {
+                let operation = if pressed(&settings.subtract_keys) {
+                    SelectionOperation::Subtract
+                } else if pressed(&settings.add_keys) {
+                    SelectionOperation::Add
+                } else {
+                    SelectionOperation::Replace
+                };
+                let hovered = hover_map.get(&pointer_id).and_then(|hits| {
+                    hits.iter()
+                        .filter(|(entity, _)| !windows.contains(**entity))
+                        .min_by_key(|(_, hit)| FloatOrd(hit.depth))
+                        .and_then(|(&entity, _)| {
+                            core::iter::once(entity)
+                                .chain(ancestors.iter_ancestors(entity))
+                                .find(|&entity| is_selectable(entity))
+                        })
+                });
+                let press = PendingPress {
+                    location: input.location.clone(),
+                    operation,
+                    hovered,
+                };
+                if let Some(PointerSelection::Dragging(_)) =
+                    pointer_selections.insert(pointer_id, PointerSelection::Pressed(press))
+                {
+                    changed_marquees.push(pointer_id);
+                }
+            }
+            PointerAction::Move { .. } => match pointer_selections.get_mut(&pointer_id) {
+                Some(PointerSelection::Dragging(marquee)) => {
+                    match marquee.shape {
+                        MarqueeShape::Rectangle => {
+                            marquee.points.truncate(1);
+                            marquee.points.push(input.location.position);
+                        }
+                        MarqueeShape::Lasso => marquee.points.push(input.location.position),
+                    }
+                    changed_marquees.push(pointer_id);
+                }
+                Some(PointerSelection::Pressed(press))
+                    if press.hovered.is_none()
+                        && press.location.target == input.location.target
+                        && press.location.position.distance(input.location.position)
+                            >= settings.min_drag_distance =>
+                {
+                    let marquee = Marquee {
+                        target: press.location.target.clone(),
+                        shape: settings.shape,
+                        operation: press.operation,
+                        points: vec![press.location.position, input.location.position],
+                    };
+                    pointer_selections.insert(pointer_id, PointerSelection::Dragging(marquee));
+                    changed_marquees.push(pointer_id);
+                }
+                Some(PointerSelection::Pressed(_)) | None => {}
+            },
+            PointerAction::Release(button) if button == settings.button => {
+                let mut picked = Vec::new();
+                let operation = match pointer_selections.remove(&pointer_id) {
+                    Some(PointerSelection::Dragging(marquee)) => {
+                        changed_marquees.push(pointer_id);
+                        let mut corners = Vec::with_capacity(8);
+                        for (camera, render_target, camera_transform, camera_layers) in &cameras {
+                            let normalized_target =
+                                render_target.normalize(primary_window.single().ok());
+                            if !camera.is_active
+                                || normalized_target.as_ref() != Some(&marquee.target)
+                            {
+                                continue;
+                            }
+                            let camera_layers = camera_layers.cloned().unwrap_or_default();
+                            for (entity, aabb, transform, visibility, layers, pickable) in &targets
+                            {
+                                let layers = layers.cloned().unwrap_or_default();
+                                if !visibility.get()
+                                    || !camera_layers.intersects(&layers)
+                                    || pickable.is_some_and(|pickable| !pickable.is_hoverable)
+                                    || !is_selectable(entity)
+                                {
+                                    continue;
+                                }
+                                corners.clear();
+                                // `world_to_viewport` includes the origin of the viewport, so the corners
+                                // are relative to the render target like the marquee.
+                                corners.extend(aabb_corners(aabb).filter_map(|corner| {
+                                    camera
+                                        .world_to_viewport(
+                                            camera_transform,
+                                            transform.transform_point(corner),
+                                        )
+                                        .ok()
+                                }));
+                                // Bounds partly behind the camera can't be projected.
+                                if corners.len() == 8 && marquee.selects(&corners, settings.mode) {
+                                    picked.push(entity);
+                                }
+                            }
+                        }
+                        marquee.operation
+                    }
+                    Some(PointerSelection::Pressed(press)) => {
+                        picked.extend(press.hovered);
+                        press.operation
+                    }
+                    None => continue,
+                };
+
+                let picked: EntityHashSet = picked.into_iter().collect();
+                let (selected_entities, deselected_entities) = match operation {
+                    SelectionOperation::Replace => (
+                        picked
+                            .iter()
+                            .copied()
+                            .filter(|&entity| !selected.contains(entity))
+                            .collect(),
+                        selected
+                            .iter()
+                            .filter(|entity| !picked.contains(entity))
+                            .collect(),
+                    ),
+                    SelectionOperation::Add => (
+                        picked
+                            .iter()
+                            .copied()
+                            .filter(|&entity| !selected.contains(entity))
+                            .collect(),
+                        Vec::new(),
+                    ),
+                    SelectionOperation::Subtract => (
+                        Vec::new(),
+                        picked
+                            .iter()
+                            .copied()
+                            .filter(|&entity| selected.contains(entity))
+                            .collect(),
+                    ),
+                };
+                apply_selection(
+                    &mut commands,
+                    &mut selection_changed,
+                    pointer_id,
+                    selected_entities,
+                    deselected_entities,
+                );
+            }
+            PointerAction::Cancel => {
+                if let Some(PointerSelection::Dragging(_)) = pointer_selections.remove(&pointer_id)
+                {
+                    changed_marquees.push(pointer_id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (pointer, pointer_id) in &pointers {
+        if !changed_marquees.contains(pointer_id) {
+            continue;
+        }
+        match pointer_selections.get(pointer_id) {
+            Some(PointerSelection::Dragging(marquee)) => {
+                commands.entity(pointer).insert(marquee.clone());
+            }
+            _ => {
+                commands.entity(pointer).remove::<Marquee>();
+            }
+        }
+    }
+}
+
+/// Inserts and removes [`Selected`], and writes a [`SelectionChanged`] message if anything changed.
+fn apply_selection(
+    commands: &mut Commands,
+    selection_changed: &mut MessageWriter<SelectionChanged>,
+    pointer_id: PointerId,
+    selected: Vec<Entity>,
+    deselected: Vec<Entity>,
+) {
+    if selected.is_empty() && deselected.is_empty() {
+        return;
+    }
+    for &entity in &selected {
+        commands.entity(entity).try_insert(Selected);
+    }
+    for &entity in &deselected {
+        commands.entity(entity).try_remove::<Selected>();
+    }
+    selection_changed.write(SelectionChanged {
+        pointer_id,
+        selected,
+        deselected,
+    });
+}
+
+/// Returns the corners of `aabb`, in the space of its entity.
+fn aabb_corners(aabb: &Aabb) -> impl Iterator<Item = Vec3> {
+    let center = Vec3::from(aabb.center);
+    let half_extents = Vec3::from(aabb.half_extents);
+    (0..8).map(move |i| {
+        let sign = Vec3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        );
+        center + half_extents * sign
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::HitData;
+    use bevy_camera::{ComputedCameraValues, RenderTargetInfo, Viewport};
+    use bevy_ecs::entity::EntityHashMap;
+    use bevy_math::{Mat4, UVec2};
+    use bevy_window::WindowRef;
+
+    #[test]
+    fn lasso_contains() {
+        // An L shaped lasso.
+        let marquee = Marquee {
+            target: NormalizedRenderTarget::None {
+                width: 100,
+                height: 100,
+            },
+            shape: MarqueeShape::Lasso,
+            operation: SelectionOperation::Replace,
+            points: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(10.0, 0.0),
+                Vec2::new(10.0, 5.0),
+                Vec2::new(5.0, 5.0),
+                Vec2::new(5.0, 10.0),
+                Vec2::new(0.0, 10.0),
+            ],
+        };
+        assert!(marquee.contains(Vec2::new(2.0, 8.0)));
+        assert!(marquee.contains(Vec2::new(8.0, 2.0)));
+        assert!(!marquee.contains(Vec2::new(8.0, 8.0)));
+        assert_eq!(
+            marquee.rect(),
+            Rect::from_corners(Vec2::ZERO, Vec2::splat(10.0))
+        );
+    }
+
+    fn setup() -> (App, Location) {
+        let mut app = App::new();
+        app.add_plugins(SelectionPlugin)
+            .add_message::<PointerInput>()
+            .init_resource::<HoverMap>();
+
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        app.world_mut().spawn(PointerId::Mouse);
+        // An orthographic camera looking down -Z, showing 100 by 100 units on a 100 by 100 pixel window.
+        app.world_mut().spawn((
+            Camera {
+                computed: ComputedCameraValues {
+                    clip_from_view: Mat4::orthographic_rh(-50.0, 50.0, -50.0, 50.0, 0.0, 100.0),
+                    target_info: Some(RenderTargetInfo {
+                        physical_size: UVec2::splat(100),
+                        scale_factor: 1.0,
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            GlobalTransform::IDENTITY,
+        ));
+
+        let location = Location {
+            target: NormalizedRenderTarget::Window(
+                WindowRef::Primary.normalize(Some(window)).unwrap(),
+            ),
+            position: Vec2::ZERO,
+        };
+        (app, location)
+    }
+
+    fn spawn_target(app: &mut App, x: f32, y: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(1.0)),
+                GlobalTransform::from_xyz(x, y, -10.0),
+                ViewVisibility::VISIBLE,
+            ))
+            .id()
+    }
+
+    fn write_input(app: &mut App, location: &Location, position: Vec2, action: PointerAction) {
+        let location = Location {
+            position,
+            ..location.clone()
+        };
+        app.world_mut()
+            .write_message(PointerInput::new(PointerId::Mouse, location, action));
+        app.update();
+    }
+
+    #[test]
+    fn marquee_selects_entities_inside() {
+        let (mut app, location) = setup();
+        // Projects to (70, 30) in the window.
+        let inside = spawn_target(&mut app, 20.0, 20.0);
+        let outside = spawn_target(&mut app, -20.0, -20.0);
+
+        let press = PointerAction::Press(PointerButton::Primary);
+        write_input(&mut app, &location, Vec2::new(60.0, 20.0), press);
+        let moved = PointerAction::Move { delta: Vec2::ONE };
+        write_input(&mut app, &location, Vec2::new(80.0, 40.0), moved);
+
+        let mut marquees = app.world_mut().query::<&Marquee>();
+        let marquee = marquees.single(app.world()).unwrap();
+        assert_eq!(
+            marquee.rect(),
+            Rect::new(60.0, 20.0, 80.0, 40.0),
+            "the marquee is dragged from the press to the pointer"
+        );
+
+        let release = PointerAction::Release(PointerButton::Primary);
+        write_input(&mut app, &location, Vec2::new(80.0, 40.0), release);
+        assert!(app.world().entity(inside).contains::<Selected>());
+        assert!(!app.world().entity(outside).contains::<Selected>());
+        assert!(marquees.iter(app.world()).next().is_none());
+
+        let changes = app.world().resource::<Messages<SelectionChanged>>();
+        let change = changes.iter_current_update_messages().next().unwrap();
+        assert_eq!(change.selected, vec![inside]);
+        assert!(change.deselected.is_empty());
+    }
+
+    #[test]
+    fn marquee_in_a_single_frame() {
+        let (mut app, location) = setup();
+        let inside = spawn_target(&mut app, 20.0, 20.0);
+
+        let inputs = [
+            (60.0, 20.0, PointerAction::Press(PointerButton::Primary)),
+            (80.0, 40.0, PointerAction::Move { delta: Vec2::ONE }),
+            (80.0, 40.0, PointerAction::Release(PointerButton::Primary)),
+        ];
+        for (x, y, action) in inputs {
+            let location = Location {
+                position: Vec2::new(x, y),
+                ..location.clone()
+            };
+            app.world_mut()
+                .write_message(PointerInput::new(PointerId::Mouse, location, action));
+        }
+        app.update();
+
+        assert!(app.world().entity(inside).contains::<Selected>());
+        let mut marquees = app.world_mut().query::<&Marquee>();
+        assert!(marquees.iter(app.world()).next().is_none());
+    }
+
+    #[test]
+    fn marquee_in_offset_viewport() {
+        let (mut app, location) = setup();
+        // The camera renders to the right half of a 200 by 100 pixel window.
+        let mut cameras = app.world_mut().query::<&mut Camera>();
+        let mut camera = cameras.single_mut(app.world_mut()).unwrap();
+        camera.viewport = Some(Viewport {
+            physical_position: UVec2::new(100, 0),
+            physical_size: UVec2::splat(100),
+            ..Default::default()
+        });
+        camera.computed.target_info = Some(RenderTargetInfo {
+            physical_size: UVec2::new(200, 100),
+            scale_factor: 1.0,
+        });
+        // Projects to (70, 30) in the viewport, and (170, 30) in the window.
+        let inside = spawn_target(&mut app, 20.0, 20.0);
+
+        let press = PointerAction::Press(PointerButton::Primary);
+        write_input(&mut app, &location, Vec2::new(160.0, 20.0), press);
+        let moved = PointerAction::Move { delta: Vec2::ONE };
+        write_input(&mut app, &location, Vec2::new(180.0, 40.0), moved);
+        let release = PointerAction::Release(PointerButton::Primary);
+        write_input(&mut app, &location, Vec2::new(180.0, 40.0), release);
+        assert!(app.world().entity(inside).contains::<Selected>());
+    }
+
+    #[test]
+    fn click_selection_with_modifiers() {
+        let (mut app, location) = setup();
+        let camera = app.world_mut().spawn_empty().id();
+        let a = spawn_target(&mut app, 0.0, 0.0);
+        let b = spawn_target(&mut app, 10.0, 0.0);
+        app.init_resource::<ButtonInput<KeyCode>>();
+
+        let click = |app: &mut App, entity: Option<Entity>| {
+            let mut hits = EntityHashMap::default();
+            if let Some(entity) = entity {
+                hits.insert(entity, HitData::new(camera, 0.0, None, None));
+            }
+            app.world_mut()
+                .resource_mut::<HoverMap>()
+                .insert(PointerId::Mouse, hits);
+            let press = PointerAction::Press(PointerButton::Primary);
+            write_input(app, &location, Vec2::ZERO, press);
+            let release = PointerAction::Release(PointerButton::Primary);
+            write_input(app, &location, Vec2::ZERO, release);
+        };
+        let selection = |app: &mut App| {
+            let mut selected = app
+                .world_mut()
+                .query_filtered::<Entity, With<Selected>>()
+                .iter(app.world())
+                .collect::<Vec<_>>();
+            selected.sort();
+            selected
+        };
+
+        click(&mut app, Some(a));
+        assert_eq!(selection(&mut app), vec![a]);
+        click(&mut app, Some(b));
+        assert_eq!(selection(&mut app), vec![b]);
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::ShiftLeft);
+        click(&mut app, Some(a));
+        let mut both = vec![a, b];
+        both.sort();
+        assert_eq!(selection(&mut app), both);
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::ShiftLeft);
+        keys.press(KeyCode::ControlLeft);
+        click(&mut app, Some(b));
+        assert_eq!(selection(&mut app), vec![a]);
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::ControlLeft);
+        click(&mut app, None);
+        assert!(selection(&mut app).is_empty());
+    }
+}
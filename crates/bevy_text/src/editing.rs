@@ -18,6 +18,11 @@
 //! - Multi-click: double-click to select a word, triple-click to select a line
 //! - Optional select-all on focus via the `SelectAllOnFocus` component
 //! - Per-character input filtering via the [`EditableTextFilter`] component
+//! - Transforming or rejecting whole edits via the [`EditableTextEditFilter`] component
+//! - Validation of the value (e.g. numeric or email input) via the [`EditableTextValidator`] component
+//! - Undo/redo, with consecutive typing coalesced into a single step, via the [`EditableTextHistory`] component
+//! - Password-style character masking via the [`EditableTextMask`] component
+//! - Placeholder text displayed when the input is empty via the [`EditableTextPlaceholder`] component
 //! - Max character limits via [`EditableText::max_characters`]
 //! - Cursor blinking
 //! - Newline support for multi-line input
@@ -42,6 +47,8 @@
 //!
 //! With the correct plugin enabled, when an [`EditableText`] entity is focused,
 //! keyboard input events are captured and processed into [`TextEdit`] actions.
+//! Pressing Enter (or Ctrl + Enter when newlines are allowed) submits the input,
+//! triggering a `TextInputSubmit` event.
 //!
 //! ## Limitations
 //!
@@ -56,15 +63,10 @@
 //!
 //! However, the following features are planned but currently not implemented:
 //!
-//! - Placeholder text (displayed when the input is empty)
-//! - Undo/redo functionality
-//! - Text validation (e.g., email format, numeric input)
-//! - Password-style character masking
 //! - Mobile pop-up keyboard support
 //! - Overwrite mode (typically toggled by the `Insert` key)
 //! - AccessKit integration for screen readers and other assistive technologies
 //! - World-space text input
-//! - Text form submission handling
 //!
 //! If you require any of these features, please consider contributing it to the crate,
 //! one feature at a time!
@@ -73,15 +75,16 @@
 // and `bevy_ui`, such as text layout and font management.
 
 use crate::{
-    text_edit::{poll_and_apply_paste, TextEdit},
+    text_edit::{poll_paste, TextEdit},
     FontCx, FontHinting, LayoutCx, LineHeight, TextBrush, TextColor, TextFont, TextLayout,
 };
 use alloc::sync::Arc;
 use bevy_clipboard::ClipboardRead;
+use bevy_color::Color;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::prelude::*;
 use core::time::Duration;
-use parley::{FontContext, LayoutContext, PlainEditor, SplitString};
+use parley::{FontContext, LayoutContext, PlainEditor, PlainEditorDriver, SplitString};
 
 /// A plain-text text input field.
 ///
@@ -187,6 +190,8 @@ impl EditableText {
     /// Get the current text input as a [`SplitString`].
     ///
     /// A [`SplitString`] can be converted into a [`String`] using `to_string` if needed.
+    ///
+    /// For inputs with an [`EditableTextMask`], this is still the real value: only the displayed glyphs are masked.
     pub fn value(&self) -> SplitString<'_> {
         self.editor.text()
     }
@@ -211,6 +216,26 @@ impl EditableText {
         layout_context: &mut LayoutContext<TextBrush>,
         clipboard: &mut bevy_clipboard::Clipboard,
         char_filter: impl Fn(char) -> bool,
+    ) {
+        self.apply_pending_edits_with(
+            font_context,
+            layout_context,
+            clipboard,
+            char_filter,
+            TextEditExtensions::default(),
+        );
+    }
+
+    /// Applies all [`TextEdit`]s in `pending_edits` immediately, like [`apply_pending_edits`](Self::apply_pending_edits),
+    /// while also honoring the optional behaviors in [`TextEditExtensions`]:
+    /// edit filtering, validation, masking and undo history.
+    pub fn apply_pending_edits_with(
+        &mut self,
+        font_context: &mut FontContext,
+        layout_context: &mut LayoutContext<TextBrush>,
+        clipboard: &mut bevy_clipboard::Clipboard,
+        char_filter: impl Fn(char) -> bool,
+        mut extensions: TextEditExtensions<'_>,
     ) {
         let Self {
             editor,
@@ -225,11 +250,19 @@ impl EditableText {
         // First: resolve any paste carried over from a previous frame. If it's still
         // pending, hold the remaining edits (untouched in `pending_edits`) for next frame
         // so ordering relative to the paste is preserved.
-        if let Some(mut read) = pending_paste.take()
-            && !poll_and_apply_paste(&mut read, &mut driver, *max_characters, &char_filter)
-        {
-            *pending_paste = Some(read);
-            return;
+        if let Some(mut read) = pending_paste.take() {
+            let Some(text) = poll_paste(&mut read) else {
+                *pending_paste = Some(read);
+                return;
+            };
+            apply_paste(
+                &mut driver,
+                clipboard,
+                *max_characters,
+                &char_filter,
+                &mut extensions,
+                text,
+            );
         }
 
         // Drain edits one at a time. A paste that resolves synchronously (always the case
@@ -240,14 +273,32 @@ impl EditableText {
             match edit {
                 TextEdit::Paste => {
                     let mut read = clipboard.fetch_text();
-                    if !poll_and_apply_paste(&mut read, &mut driver, *max_characters, &char_filter)
-                    {
+                    let Some(text) = poll_paste(&mut read) else {
                         *pending_paste = Some(read);
                         pending_edits.extend(edits);
                         return;
-                    }
+                    };
+                    apply_paste(
+                        &mut driver,
+                        clipboard,
+                        *max_characters,
+                        &char_filter,
+                        &mut extensions,
+                        text,
+                    );
+                }
+                other => {
+                    let group = EditGroup::of(&other);
+                    apply_edit(
+                        &mut driver,
+                        clipboard,
+                        *max_characters,
+                        &char_filter,
+                        &mut extensions,
+                        other,
+                        group,
+                    );
                 }
-                other => other.apply(&mut driver, clipboard, *max_characters, &char_filter),
             }
         }
     }
@@ -290,6 +341,560 @@ impl EditableTextFilter {
     }
 }
 
+/// Transforms or rejects [`TextEdit`]s before they are applied to this text input.
+///
+/// The hook receives the current value of the input and the requested edit, and returns the edit to apply,
+/// or `None` to drop it. Pastes are passed to the hook as [`TextEdit::Insert`] once the clipboard contents are available.
+///
+/// ```
+/// # use bevy_text::{EditableTextEditFilter, TextEdit};
+/// // Upper-case everything that is typed or pasted.
+/// let filter = EditableTextEditFilter::new(|_value, edit| match edit {
+///     TextEdit::Insert(text) => Some(TextEdit::Insert(text.to_uppercase().into())),
+///     other => Some(other),
+/// });
+/// ```
+#[derive(Component, Clone)]
+pub struct EditableTextEditFilter(
+    Arc<dyn Fn(&str, TextEdit) -> Option<TextEdit> + Send + Sync + 'static>,
+);
+
+impl EditableTextEditFilter {
+    /// Create a new `EditableTextEditFilter` from the given hook.
+    pub fn new(
+        filter: impl Fn(&str, TextEdit) -> Option<TextEdit> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(filter))
+    }
+}
+
+/// The result of validating the value of a text input with an [`EditableTextValidator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextValidity {
+    /// The value is complete and valid.
+    Valid,
+    /// The value is not valid yet, but could become valid with further typing, e.g. an empty input.
+    #[default]
+    Incomplete,
+    /// The value can never become valid. Edits producing invalid values are rejected.
+    Invalid,
+}
+
+/// Validates the value of this text input after every edit.
+///
+/// Edits that would make the value [`TextValidity::Invalid`] are reverted,
+/// while [`TextValidity::Incomplete`] values are accepted so the user can finish typing.
+/// The validity of the current value is available through [`EditableTextValidator::validity`].
+#[derive(Component, Clone)]
+pub struct EditableTextValidator {
+    validate: Arc<dyn Fn(&str) -> TextValidity + Send + Sync + 'static>,
+    validity: TextValidity,
+}
+
+impl EditableTextValidator {
+    /// Create a new `EditableTextValidator` from the given validation function.
+    pub fn new(validate: impl Fn(&str) -> TextValidity + Send + Sync + 'static) -> Self {
+        Self {
+            validate: Arc::new(validate),
+            validity: TextValidity::Incomplete,
+        }
+    }
+
+    /// Accepts decimal numbers with an optional sign, such as `-12.5`.
+    pub fn numeric() -> Self {
+        Self::new(|value| validate_number(value, true))
+    }
+
+    /// Accepts whole numbers with an optional sign, such as `-12`.
+    pub fn integer() -> Self {
+        Self::new(|value| validate_number(value, false))
+    }
+
+    /// Accepts email addresses of the form `name@domain.tld`.
+    ///
+    /// This only checks the overall shape of the address, and rejects whitespace and repeated `@`s as they are typed.
+    pub fn email() -> Self {
+        Self::new(|value| {
+            if value.chars().any(char::is_whitespace) || value.matches('@').count() > 1 {
+                return TextValidity::Invalid;
+            }
+            match value.split_once('@') {
+                Some((name, domain))
+                    if !name.is_empty()
+                        && domain
+                            .split_once('.')
+                            .is_some_and(|(host, _)| !host.is_empty())
+                        && !domain.ends_with('.') =>
+                {
+                    TextValidity::Valid
+                }
+                _ => TextValidity::Incomplete,
+            }
+        })
+    }
+
+    /// Validates the given value.
+    pub fn validate(&self, value: &str) -> TextValidity {
+        (self.validate)(value)
+    }
+
+    /// The validity of the input's value after the last edits were applied.
+    pub fn validity(&self) -> TextValidity {
+        self.validity
+    }
+
+    /// Is the input's value [`TextValidity::Valid`]?
+    pub fn is_valid(&self) -> bool {
+        self.validity == TextValidity::Valid
+    }
+}
+
+/// Validates a partially typed number, optionally allowing a fractional part.
+fn validate_number(value: &str, allow_fraction: bool) -> TextValidity {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let (whole, fraction) = match digits.split_once('.') {
+        Some(_) if !allow_fraction => return TextValidity::Invalid,
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (digits, None),
+    };
+    if !whole
+        .chars()
+        .chain(fraction.into_iter().flat_map(str::chars))
+        .all(|c| c.is_ascii_digit())
+    {
+        TextValidity::Invalid
+    } else if whole.is_empty() && fraction.is_none_or(str::is_empty) {
+        TextValidity::Incomplete
+    } else {
+        TextValidity::Valid
+    }
+}
+
+/// Hides the value of this text input by displaying a mask character in place of each character,
+/// as is usual for password fields.
+///
+/// The [`EditableText`] keeps the real value: the mask characters are only substituted when the input is laid out,
+/// and pointer edits are resolved against the masked layout.
+/// Copying, cutting and IME composition are disabled for masked inputs.
+#[derive(Component, Clone)]
+pub struct EditableTextMask {
+    /// The character displayed in place of each character of the value.
+    pub mask: char,
+    /// Lays out the mask characters with the same styles and selection as the input.
+    editor: PlainEditor<TextBrush>,
+}
+
+impl Default for EditableTextMask {
+    fn default() -> Self {
+        Self::new('•')
+    }
+}
+
+impl EditableTextMask {
+    /// Create a new `EditableTextMask` displaying the given character.
+    pub fn new(mask: char) -> Self {
+        Self {
+            mask,
+            editor: PlainEditor::new(100.),
+        }
+    }
+
+    /// Access the [`PlainEditor`] used to lay out the mask characters.
+    pub fn editor(&self) -> &PlainEditor<TextBrush> {
+        &self.editor
+    }
+
+    /// Mutably access the [`PlainEditor`] used to lay out the mask characters.
+    ///
+    /// Its width and alignment are kept in sync with the input's editor by the UI layout systems.
+    pub fn editor_mut(&mut self) -> &mut PlainEditor<TextBrush> {
+        &mut self.editor
+    }
+
+    /// Updates the mask editor to display one mask character per character of `editor`'s text,
+    /// with the same styles, scale and selection.
+    pub fn sync(
+        &mut self,
+        editor: &PlainEditor<TextBrush>,
+        font_context: &mut FontContext,
+        layout_context: &mut LayoutContext<TextBrush>,
+    ) {
+        let text = editor.raw_text();
+        let masked: String = core::iter::repeat_n(self.mask, text.chars().count()).collect();
+        if self.editor.raw_text() != masked {
+            self.editor.set_text(&masked);
+        }
+        *self.editor.edit_styles() = editor.get_styles().clone();
+        self.editor.set_scale(editor.get_scale());
+
+        let mask_len = self.mask.len_utf8();
+        let to_mask = |index: usize| text[..index].chars().count() * mask_len;
+        let selection = editor.raw_selection();
+        self.editor
+            .driver(font_context, layout_context)
+            .select_byte_range(
+                to_mask(selection.anchor().index()),
+                to_mask(selection.focus().index()),
+            );
+    }
+
+    /// Applies an edit locating text by a point, such as a click, to the masked layout the user sees,
+    /// then moves the selection of the input to the matching characters.
+    fn apply_point_edit(
+        &mut self,
+        driver: &mut PlainEditorDriver<TextBrush>,
+        clipboard: &mut bevy_clipboard::Clipboard,
+        edit: TextEdit,
+    ) {
+        self.sync(driver.editor, driver.font_cx, driver.layout_cx);
+        edit.apply(
+            &mut self.editor.driver(driver.font_cx, driver.layout_cx),
+            clipboard,
+            None,
+            |_| true,
+        );
+
+        let mask_len = self.mask.len_utf8();
+        let text = driver.editor.raw_text();
+        let to_text = |index: usize| {
+            text.char_indices()
+                .nth(index / mask_len)
+                .map_or(text.len(), |(index, _)| index)
+        };
+        let selection = self.editor.raw_selection();
+        let (anchor, focus) = (
+            to_text(selection.anchor().index()),
+            to_text(selection.focus().index()),
+        );
+        driver.select_byte_range(anchor, focus);
+    }
+}
+
+/// Displays placeholder text while this text input is empty.
+#[derive(Component, Clone)]
+pub struct EditableTextPlaceholder {
+    /// The text displayed while the input is empty.
+    pub text: String,
+    /// The color of the placeholder text.
+    pub color: Color,
+    /// Lays out the placeholder text with the same styles as the input.
+    editor: PlainEditor<TextBrush>,
+}
+
+impl EditableTextPlaceholder {
+    /// Create a new `EditableTextPlaceholder` displaying the given text in a muted gray.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: Color::srgba(0.5, 0.5, 0.5, 1.0),
+            editor: PlainEditor::new(100.),
+        }
+    }
+
+    /// Sets the color of the placeholder text.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Access the [`PlainEditor`] used to lay out the placeholder text.
+    pub fn editor(&self) -> &PlainEditor<TextBrush> {
+        &self.editor
+    }
+
+    /// Mutably access the [`PlainEditor`] used to lay out the placeholder text.
+    ///
+    /// Its styles are kept in sync with the input's editor by the UI layout systems.
+    pub fn editor_mut(&mut self) -> &mut PlainEditor<TextBrush> {
+        &mut self.editor
+    }
+}
+
+/// Records the edits made to this text input, so they can be reverted with [`TextEdit::Undo`] and reapplied with [`TextEdit::Redo`].
+///
+/// Consecutive typing, and consecutive single character deletions, are coalesced into a single undo step,
+/// so that typing is undone one word at a time.
+/// Typing whitespace, moving the cursor, or any other edit starts a new step.
+#[derive(Component, Clone, Debug)]
+pub struct EditableTextHistory {
+    /// The maximum number of undo steps to keep. The oldest steps are discarded first.
+    pub max_steps: usize,
+    undo: Vec<TextSnapshot>,
+    redo: Vec<TextSnapshot>,
+    /// The kind of the last recorded edit, if it can still be coalesced with.
+    group: Option<EditGroup>,
+}
+
+impl Default for EditableTextHistory {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl EditableTextHistory {
+    /// Create a new `EditableTextHistory` keeping up to `max_steps` undo steps.
+    pub fn new(max_steps: usize) -> Self {
+        Self {
+            max_steps,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            group: None,
+        }
+    }
+
+    /// Is there anything to undo?
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Is there anything to redo?
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all recorded edits.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group = None;
+    }
+
+    /// Records the state of the input before an edit of the given group.
+    fn record(&mut self, before: TextSnapshot, group: EditGroup) {
+        if matches!(group, EditGroup::WordBoundary | EditGroup::Other) || self.group != Some(group)
+        {
+            self.undo.push(before);
+            if self.undo.len() > self.max_steps {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        // The word following a boundary is coalesced with it.
+        self.group = Some(match group {
+            EditGroup::WordBoundary => EditGroup::Typing,
+            group => group,
+        });
+    }
+
+    /// Stops coalescing further edits into the current undo step.
+    fn break_group(&mut self) {
+        self.group = None;
+    }
+
+    fn undo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.undo.pop()?;
+        self.redo.push(current);
+        self.group = None;
+        Some(snapshot)
+    }
+
+    fn redo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.redo.pop()?;
+        self.undo.push(current);
+        self.group = None;
+        Some(snapshot)
+    }
+}
+
+/// The kind of an edit, used to coalesce undo steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditGroup {
+    /// Typing a word.
+    Typing,
+    /// Typing whitespace, which starts a new step that the following word is coalesced into.
+    WordBoundary,
+    /// Deleting single characters.
+    Deleting,
+    /// Any other edit, which is never coalesced.
+    Other,
+}
+
+impl EditGroup {
+    fn of(edit: &TextEdit) -> Self {
+        match edit {
+            TextEdit::Insert(text) if text.chars().any(char::is_whitespace) => Self::WordBoundary,
+            TextEdit::Insert(_) => Self::Typing,
+            TextEdit::Backspace | TextEdit::Delete => Self::Deleting,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The value and selection of a text input at some point in time.
+#[derive(Clone, Debug)]
+struct TextSnapshot {
+    /// The text of the editor.
+    value: String,
+    /// The anchor and focus of the selection, as byte offsets into the editor text.
+    selection: (usize, usize),
+}
+
+impl TextSnapshot {
+    fn capture(editor: &PlainEditor<TextBrush>) -> Self {
+        Self {
+            value: editor.text().to_string(),
+            selection: Self::selection(editor),
+        }
+    }
+
+    /// The anchor and focus of the editor's selection, as byte offsets into its text.
+    fn selection(editor: &PlainEditor<TextBrush>) -> (usize, usize) {
+        let selection = editor.raw_selection();
+        (selection.anchor().index(), selection.focus().index())
+    }
+
+    fn restore(self, driver: &mut PlainEditorDriver<TextBrush>) {
+        driver.editor.set_text(&self.value);
+        driver.select_byte_range(self.selection.0, self.selection.1);
+    }
+}
+
+/// Optional behaviors applied alongside [`TextEdit`]s by [`EditableText::apply_pending_edits_with`].
+///
+/// Each field corresponds to an optional component that can be added next to an [`EditableText`].
+#[derive(Default)]
+pub struct TextEditExtensions<'a> {
+    /// Transforms or rejects edits before they are applied.
+    pub edit_filter: Option<&'a EditableTextEditFilter>,
+    /// Reverts edits producing invalid values.
+    pub validator: Option<&'a EditableTextValidator>,
+    /// Resolves pointer edits of masked inputs against the masked layout.
+    pub mask: Option<&'a mut EditableTextMask>,
+    /// Records edits for undo and redo.
+    pub history: Option<&'a mut EditableTextHistory>,
+}
+
+/// Applies a resolved paste, which goes through the same pipeline as typed text
+/// but always forms its own undo step.
+fn apply_paste(
+    driver: &mut PlainEditorDriver<TextBrush>,
+    clipboard: &mut bevy_clipboard::Clipboard,
+    max_characters: Option<usize>,
+    char_filter: &impl Fn(char) -> bool,
+    extensions: &mut TextEditExtensions<'_>,
+    text: Option<String>,
+) {
+    let Some(text) = text else {
+        return;
+    };
+    if !text.chars().all(char_filter) {
+        bevy_log::debug!(
+            "Paste rejected: clipboard contents contained characters not allowed by the char filter."
+        );
+        return;
+    }
+    apply_edit(
+        driver,
+        clipboard,
+        max_characters,
+        char_filter,
+        extensions,
+        TextEdit::Insert(text.into()),
+        EditGroup::Other,
+    );
+}
+
+/// Applies a single edit, honoring the [`TextEditExtensions`].
+fn apply_edit(
+    driver: &mut PlainEditorDriver<TextBrush>,
+    clipboard: &mut bevy_clipboard::Clipboard,
+    max_characters: Option<usize>,
+    char_filter: &impl Fn(char) -> bool,
+    extensions: &mut TextEditExtensions<'_>,
+    edit: TextEdit,
+    group: EditGroup,
+) {
+    let TextEditExtensions {
+        edit_filter,
+        validator,
+        mask,
+        history,
+    } = extensions;
+
+    if matches!(edit, TextEdit::Undo | TextEdit::Redo) {
+        let Some(history) = history.as_deref_mut() else {
+            return;
+        };
+        let current = TextSnapshot::capture(driver.editor);
+        let snapshot = if edit == TextEdit::Undo {
+            history.undo(current)
+        } else {
+            history.redo(current)
+        };
+        if let Some(snapshot) = snapshot {
+            snapshot.restore(driver);
+        }
+        return;
+    }
+
+    let edit = match (mask.is_some(), edit) {
+        // Masked values must not leak through the clipboard or the IME's preedit text.
+        (true, TextEdit::Copy | TextEdit::Cut | TextEdit::ImeSetCompose { .. }) => return,
+        (true, TextEdit::ImeCommit { value }) => TextEdit::Insert(value),
+        (_, edit) => edit,
+    };
+
+    let edit = match edit_filter {
+        Some(EditableTextEditFilter(filter)) => {
+            match filter(&driver.editor.text().to_string(), edit) {
+                Some(edit) => edit,
+                None => return,
+            }
+        }
+        None => edit,
+    };
+
+    let mask = mask.as_deref_mut();
+    if validator.is_none() && history.is_none() {
+        apply_to_editor(driver, clipboard, max_characters, char_filter, mask, edit);
+        return;
+    }
+
+    let before = TextSnapshot::capture(driver.editor);
+    apply_to_editor(driver, clipboard, max_characters, char_filter, mask, edit);
+
+    if driver.editor.text() == before.value.as_str() {
+        if let Some(history) = history.as_deref_mut() {
+            history.break_group();
+        }
+        return;
+    }
+
+    if let Some(validator) = validator
+        && validator.validate(&driver.editor.text().to_string()) == TextValidity::Invalid
+    {
+        before.restore(driver);
+        return;
+    }
+
+    if let Some(history) = history.as_deref_mut() {
+        history.record(before, group);
+    }
+}
+
+/// Applies an edit to the editor, resolving edits that locate text by a point against the masked layout of masked inputs.
+fn apply_to_editor(
+    driver: &mut PlainEditorDriver<TextBrush>,
+    clipboard: &mut bevy_clipboard::Clipboard,
+    max_characters: Option<usize>,
+    char_filter: &impl Fn(char) -> bool,
+    mask: Option<&mut EditableTextMask>,
+    edit: TextEdit,
+) {
+    match (mask, edit) {
+        (
+            Some(mask),
+            edit @ (TextEdit::MoveToPoint(_)
+            | TextEdit::SelectWordAtPoint(_)
+            | TextEdit::SelectLineAtPoint(_)
+            | TextEdit::SelectedHardLineAtPoint(_)
+            | TextEdit::ExtendSelectionToPoint(_)
+            | TextEdit::ShiftClickExtension(_)),
+        ) => mask.apply_point_edit(driver, clipboard, edit),
+        (_, edit) => edit.apply(driver, clipboard, max_characters, char_filter),
+    }
+}
+
 /// Applies pending text edit actions to all [`EditableText`] widgets.
 pub fn apply_text_edits(
     mut query: Query<(
@@ -297,17 +902,32 @@ pub fn apply_text_edits(
         &mut EditableText,
         Option<&EditableTextFilter>,
         &EditableTextGeneration,
+        (
+            Option<&EditableTextEditFilter>,
+            Option<&mut EditableTextValidator>,
+            Option<&mut EditableTextMask>,
+            Option<&mut EditableTextHistory>,
+        ),
     )>,
     mut font_context: ResMut<FontCx>,
     mut layout_context: ResMut<LayoutCx>,
     mut clipboard: ResMut<bevy_clipboard::Clipboard>,
     mut commands: Commands,
 ) {
-    for (entity, mut editable_text, filter, generation) in query.iter_mut() {
+    for (
+        entity,
+        mut editable_text,
+        filter,
+        generation,
+        (edit_filter, mut validator, mut mask, mut history),
+    ) in query.iter_mut()
+    {
         // `pending_paste` can hold a cross-frame paste even when no new edits are queued,
         // so check for either before doing work.
-        if !editable_text.pending_edits.is_empty() || editable_text.pending_paste.is_some() {
-            editable_text.apply_pending_edits(
+        let needs_apply =
+            !editable_text.pending_edits.is_empty() || editable_text.pending_paste.is_some();
+        if needs_apply {
+            editable_text.apply_pending_edits_with(
                 &mut font_context,
                 &mut layout_context.0,
                 &mut clipboard,
@@ -315,9 +935,24 @@ pub fn apply_text_edits(
                     Some(EditableTextFilter(Some(filter))) => filter.as_ref(),
                     _ => &|_| true,
                 },
+                TextEditExtensions {
+                    edit_filter,
+                    validator: validator.as_deref(),
+                    mask: mask.as_deref_mut(),
+                    history: history.as_deref_mut(),
+                },
             );
         }
 
+        if let Some(validator) = validator.as_mut()
+            && (needs_apply || validator.is_added())
+        {
+            let validity = validator.validate(&editable_text.value().to_string());
+            if validator.validity != validity {
+                validator.validity = validity;
+            }
+        }
+
         if **generation != editable_text.editor.generation() {
             commands.trigger(TextEditChange { entity });
         }
@@ -331,3 +966,155 @@ pub fn apply_text_edits(
 pub struct TextEditChange {
     entity: Entity,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec2;
+
+    /// Creates an input using the test font, so that cursor movement has clusters to work with.
+    fn input(initial_text: &str) -> EditableText {
+        let mut text = EditableText::new(initial_text);
+        text.editor
+            .edit_styles()
+            .insert(parley::StyleProperty::FontFamily(
+                parley::FontFamily::Single(parley::FontFamilyName::Named("Fira Mono".into())),
+            ));
+        text
+    }
+
+    /// Queues `edits` on `text` and applies them with the given extensions.
+    fn apply(text: &mut EditableText, extensions: TextEditExtensions<'_>, edits: &[TextEdit]) {
+        let mut font_cx = FontCx::default();
+        font_cx.collection.register_fonts(
+            parley::fontique::Blob::from(include_bytes!("FiraMono-subset.ttf").to_vec()),
+            None,
+        );
+        let mut layout_cx = LayoutCx::default();
+        let mut clipboard = bevy_clipboard::Clipboard::default();
+        for edit in edits {
+            text.queue_edit(edit.clone());
+        }
+        text.apply_pending_edits_with(
+            &mut font_cx,
+            &mut layout_cx,
+            &mut clipboard,
+            |_| true,
+            extensions,
+        );
+    }
+
+    fn typed(text: &str) -> Vec<TextEdit> {
+        text.chars()
+            .map(|c| TextEdit::Insert(c.to_string().into()))
+            .collect()
+    }
+
+    #[test]
+    fn undo_redo_coalesces_words() {
+        let mut text = input("");
+        let mut history = EditableTextHistory::default();
+        let history = &mut history;
+        let mut edit = |text: &mut EditableText, edits: &[TextEdit]| {
+            apply(
+                text,
+                TextEditExtensions {
+                    history: Some(history),
+                    ..Default::default()
+                },
+                edits,
+            );
+        };
+
+        edit(&mut text, &typed("hello world"));
+        assert_eq!(text.value(), "hello world");
+        edit(&mut text, &[TextEdit::Undo]);
+        assert_eq!(text.value(), "hello");
+        edit(&mut text, &[TextEdit::Undo]);
+        assert_eq!(text.value(), "");
+        edit(&mut text, &[TextEdit::Redo, TextEdit::Redo]);
+        assert_eq!(text.value(), "hello world");
+
+        // A new edit clears the redo stack.
+        edit(&mut text, &[TextEdit::Undo, TextEdit::Backspace]);
+        edit(&mut text, &[TextEdit::Redo]);
+        assert_eq!(text.value(), "hell");
+        edit(&mut text, &[TextEdit::Undo]);
+        assert_eq!(text.value(), "hello");
+    }
+
+    #[test]
+    fn mask_keeps_real_value() {
+        let mut text = input("pé");
+        let mut mask = EditableTextMask::new('*');
+        let edit = |text: &mut EditableText, mask: &mut EditableTextMask, edits: &[TextEdit]| {
+            apply(
+                text,
+                TextEditExtensions {
+                    mask: Some(mask),
+                    ..Default::default()
+                },
+                edits,
+            );
+        };
+
+        edit(&mut text, &mut mask, &typed("ss"));
+        edit(
+            &mut text,
+            &mut mask,
+            &[
+                TextEdit::Copy,
+                TextEdit::Cut,
+                TextEdit::MoveToPoint(Vec2::ZERO),
+            ],
+        );
+        assert_eq!(text.value(), "péss");
+        assert_eq!(mask.editor().raw_text(), "****");
+
+        // Clicks are resolved against the mask glyphs, then mapped back onto the real text.
+        let advance = mask.editor().try_layout().unwrap().full_width() / 4.0;
+        edit(
+            &mut text,
+            &mut mask,
+            &[TextEdit::MoveToPoint(Vec2::new(2.0 * advance, 0.0))],
+        );
+        edit(&mut text, &mut mask, &typed("!"));
+        assert_eq!(text.value(), "pé!ss");
+    }
+
+    #[test]
+    fn validators_and_edit_filters() {
+        let numeric = EditableTextValidator::numeric();
+        let filter = EditableTextEditFilter::new(|value, edit| match edit {
+            TextEdit::Insert(text) if value.len() < 4 => {
+                Some(TextEdit::Insert(text.replace(',', ".").into()))
+            }
+            TextEdit::Insert(_) => None,
+            other => Some(other),
+        });
+        let mut text = input("");
+        apply(
+            &mut text,
+            TextEditExtensions {
+                edit_filter: Some(&filter),
+                validator: Some(&numeric),
+                ..Default::default()
+            },
+            &typed("-1a,2.56"),
+        );
+        assert_eq!(text.value(), "-1.2");
+
+        assert_eq!(numeric.validate("-"), TextValidity::Incomplete);
+        assert_eq!(numeric.validate("1.2.3"), TextValidity::Invalid);
+        assert_eq!(
+            EditableTextValidator::integer().validate("1.5"),
+            TextValidity::Invalid
+        );
+
+        let email = EditableTextValidator::email();
+        assert_eq!(email.validate("me@example.com"), TextValidity::Valid);
+        assert_eq!(email.validate("me@example"), TextValidity::Incomplete);
+        assert_eq!(email.validate("me@@example.com"), TextValidity::Invalid);
+        assert_eq!(email.validate("me @example.com"), TextValidity::Invalid);
+    }
+}
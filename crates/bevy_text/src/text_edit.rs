@@ -199,6 +199,18 @@ pub enum TextEdit {
         /// The committed text to insert at the cursor.
         value: SmolStr,
     },
+    /// Reverts the most recent group of edits.
+    ///
+    /// Only has an effect on inputs with an [`EditableTextHistory`](crate::EditableTextHistory).
+    ///
+    /// Typically generated in response to Ctrl + Z or Cmd + Z.
+    Undo,
+    /// Reapplies the most recently undone group of edits.
+    ///
+    /// Only has an effect on inputs with an [`EditableTextHistory`](crate::EditableTextHistory).
+    ///
+    /// Typically generated in response to Ctrl + Shift + Z, Ctrl + Y or Cmd + Shift + Z.
+    Redo,
 }
 
 impl TextEdit {
@@ -313,6 +325,8 @@ impl TextEdit {
                     driver.insert_or_replace_selection(text.as_str());
                 }
             }
+            // History is stored outside of the editor, see `EditableText::apply_pending_edits_with`.
+            TextEdit::Undo | TextEdit::Redo => {}
         }
     }
 }
@@ -356,34 +370,44 @@ fn insert_filtered(
     Ok(())
 }
 
+/// Polls a clipboard read, returning the clipboard text once the read has resolved.
+///
+/// Returns `None` while the read is still pending,
+/// and `Some(None)` if the read failed.
+pub(crate) fn poll_paste(read: &mut ClipboardRead) -> Option<Option<String>> {
+    match read.poll_result()? {
+        Ok(text) => Some(Some(text)),
+        Err(e) => {
+            bevy_log::warn!("Failed to read clipboard for paste: {e:?}");
+            Some(None)
+        }
+    }
+}
+
 /// Polls a clipboard read and, if ready, applies the resulting text as a paste.
 ///
 /// Returns `true` when the read has resolved (applied, filter-rejected, or errored)
 /// and the caller should move on.
 /// Returns `false` when the read is still pending
 /// and the caller should hold onto the [`ClipboardRead`] to poll again on a later frame.
-pub(crate) fn poll_and_apply_paste(
+fn poll_and_apply_paste(
     read: &mut ClipboardRead,
     driver: &mut PlainEditorDriver<TextBrush>,
     max_characters: Option<usize>,
     char_filter: impl Fn(char) -> bool,
 ) -> bool {
-    match read.poll_result() {
-        Some(Ok(text)) => {
-            if matches!(
-                insert_filtered(driver, &text, max_characters, char_filter),
-                Err(InsertRejection::CharFilter)
-            ) {
-                bevy_log::debug!(
-                    "Paste rejected: clipboard contents contained characters not allowed by the char filter."
-                );
-            }
-            true
-        }
-        Some(Err(e)) => {
-            bevy_log::warn!("Failed to read clipboard for paste: {e:?}");
-            true
-        }
-        None => false,
+    let Some(text) = poll_paste(read) else {
+        return false;
+    };
+    if let Some(text) = text
+        && matches!(
+            insert_filtered(driver, &text, max_characters, char_filter),
+            Err(InsertRejection::CharFilter)
+        )
+    {
+        bevy_log::debug!(
+            "Paste rejected: clipboard contents contained characters not allowed by the char filter."
+        );
     }
+    true
 }
@@ -0,0 +1,567 @@
+//! A virtual cursor for navigating UI with a gamepad.
+//!
+//! [`GamepadCursorPlugin`] spawns a [`GamepadCursor`]: a UI node that follows a gamepad stick and
+//! acts as a `bevy_picking` pointer. The cursor writes [`PointerInput`] messages just like the
+//! mouse does, so hover, click and drag events are triggered on the nodes beneath it without any
+//! changes to the UI itself.
+//!
+//! The cursor accelerates towards the speed requested by the stick, and is gently pulled towards
+//! the center of nearby pickable nodes once the stick is released, which makes small buttons much
+//! easier to hit. The stick is read after its [`AxisSettings`](bevy_input::gamepad::AxisSettings)
+//! have been applied, so its dead zone is configured through
+//! [`GamepadSettings`](bevy_input::gamepad::GamepadSettings).
+//!
+//! The cursor lives on the primary window, and assumes that the UI camera covers the whole window.
+//! Its position is expressed in the logical pixels of the window, like the mouse cursor, so it is
+//! divided by [`UiScale`] when placing the cursor node.
+
+use crate::{
+    BackgroundColor, BorderRadius, ComputedNode, ComputedStackIndex, GlobalZIndex, Node,
+    PositionType, UiGlobalTransform, UiScale, Val,
+};
+use bevy_app::prelude::*;
+use bevy_camera::{visibility::InheritedVisibility, RenderTarget};
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{Gamepad, GamepadAxis, GamepadButton},
+    InputSystems,
+};
+use bevy_math::{Rect, StableInterpolate, Vec2};
+use bevy_picking::{
+    pointer::{Location, PointerAction, PointerButton, PointerId, PointerInput, PointerLocation},
+    Pickable, PickingSystems,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::Time;
+use bevy_window::{PrimaryWindow, Window, WindowRef};
+
+/// Adds a gamepad controlled virtual cursor that drives `bevy_picking`.
+///
+/// The cursor is configured through the [`GamepadCursorSettings`] resource, which will be
+/// initialized with default values if it is not present when this plugin is added.
+pub struct GamepadCursorPlugin;
+
+impl Plugin for GamepadCursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamepadCursorSettings>()
+            .add_systems(Startup, spawn_gamepad_cursor)
+            .add_systems(
+                PreUpdate,
+                gamepad_cursor_input
+                    .after(InputSystems)
+                    .before(PickingSystems::ProcessInput),
+            );
+    }
+}
+
+/// Runtime settings for the [`GamepadCursorPlugin`].
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource, Default, Debug, Clone)]
+pub struct GamepadCursorSettings {
+    /// The axis moving the cursor horizontally.
+    pub x_axis: GamepadAxis,
+    /// The axis moving the cursor vertically. Positive values move the cursor up.
+    pub y_axis: GamepadAxis,
+    /// The speed of the cursor at full stick deflection, in logical pixels per second.
+    pub max_speed: f32,
+    /// How quickly the cursor reaches the speed requested by the stick, in logical pixels per
+    /// second squared.
+    pub acceleration: f32,
+    /// The button acting as the primary mouse button.
+    pub primary_button: GamepadButton,
+    /// The button acting as the secondary mouse button, if any.
+    pub secondary_button: Option<GamepadButton>,
+    /// Pickable nodes closer to the cursor than this distance, in logical pixels, pull the cursor
+    /// towards their center.
+    ///
+    /// Only nodes that would be hovered at their center are snapped to, and nodes covering the
+    /// whole snap area around the cursor, such as backgrounds and layout containers, are skipped.
+    pub snap_radius: f32,
+    /// How strongly the cursor is pulled towards nearby nodes. This is a decay rate: higher values
+    /// snap faster, and `0.0` disables snapping entirely.
+    ///
+    /// The pull weakens as the stick is deflected, so it never fights the player.
+    pub snap_strength: f32,
+}
+
+impl Default for GamepadCursorSettings {
+    fn default() -> Self {
+        Self {
+            x_axis: GamepadAxis::LeftStickX,
+            y_axis: GamepadAxis::LeftStickY,
+            max_speed: 900.0,
+            acceleration: 4000.0,
+            primary_button: GamepadButton::South,
+            secondary_button: Some(GamepadButton::East),
+            snap_radius: 40.0,
+            snap_strength: 12.0,
+        }
+    }
+}
+
+/// A UI node acting as a pointer controlled by a gamepad.
+///
+/// The node is positioned absolutely and is ignored by picking, so it can be styled freely. Its
+/// center is the hotspot of the pointer.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(
+    Node = GamepadCursor::default_node(),
+    BackgroundColor = BackgroundColor(Color::WHITE),
+    GlobalZIndex = GlobalZIndex(i32::MAX),
+    Pickable = Pickable::IGNORE,
+    PointerId::Custom(uuid::Uuid::new_v4())
+)]
+pub struct GamepadCursor {
+    /// The gamepad controlling this cursor. When `None`, every connected gamepad moves it.
+    pub gamepad: Option<Entity>,
+    /// The current velocity of the cursor, in logical pixels per second.
+    pub velocity: Vec2,
+}
+
+impl GamepadCursor {
+    /// Creates a cursor controlled by the given gamepad entity.
+    pub const fn new(gamepad: Entity) -> Self {
+        Self {
+            gamepad: Some(gamepad),
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    /// The [`Node`] used for cursors spawned without one: a small white dot.
+    fn default_node() -> Node {
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(16.0),
+            height: Val::Px(16.0),
+            border_radius: BorderRadius::MAX,
+            ..Default::default()
+        }
+    }
+}
+
+/// Spawns the default [`GamepadCursor`], controlled by every connected gamepad.
+pub fn spawn_gamepad_cursor(mut commands: Commands) {
+    commands.spawn(GamepadCursor::default());
+}
+
+/// Moves every [`GamepadCursor`] according to its gamepad, and writes the matching
+/// [`PointerInput`] messages.
+pub fn gamepad_cursor_input(
+    settings: Res<GamepadCursorSettings>,
+    time: Res<Time>,
+    ui_scale: Res<UiScale>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    mut cursors: Query<(
+        &mut GamepadCursor,
+        &PointerId,
+        &PointerLocation,
+        &mut Node,
+        Option<&ComputedNode>,
+    )>,
+    nodes: Query<(
+        Entity,
+        &ComputedNode,
+        &UiGlobalTransform,
+        Option<&ComputedStackIndex>,
+        Option<&Pickable>,
+        &InheritedVisibility,
+    )>,
+    parents: Query<&ChildOf>,
+    mut pointer_inputs: MessageWriter<PointerInput>,
+) {
+    let Ok((window_entity, window)) = primary_window.single() else {
+        return;
+    };
+    let Some(target) = RenderTarget::Window(WindowRef::Primary).normalize(Some(window_entity))
+    else {
+        return;
+    };
+    let bounds = Rect::from_corners(Vec2::ZERO, window.size());
+    let delta_secs = time.delta_secs();
+    let snap_nodes: Vec<_> = if settings.snap_strength > 0.0 {
+        nodes
+            .iter()
+            .filter(|(.., visibility)| visibility.get())
+            .map(|(entity, node, transform, stack_index, pickable, _)| {
+                // Converts from physical pixels to the logical pixels of the window.
+                let scale = node.inverse_scale_factor() * ui_scale.0;
+                SnapNode {
+                    entity,
+                    rect: Rect::from_center_size(
+                        transform.translation * scale,
+                        node.size() * scale,
+                    ),
+                    stack_index: stack_index.map_or(0, |stack_index| stack_index.0),
+                    // Nodes without `Pickable` are hoverable and block lower nodes.
+                    pickable: pickable.cloned().unwrap_or_default(),
+                }
+            })
+            .filter(|node| !node.rect.is_empty())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    for (mut cursor, &pointer_id, pointer_location, mut node, computed_node) in &mut cursors {
+        let controllers = gamepads
+            .iter()
+            .filter(|(entity, _)| cursor.gamepad.is_none_or(|gamepad| gamepad == *entity))
+            .map(|(_, gamepad)| gamepad);
+
+        let mut stick = Vec2::ZERO;
+        let mut actions = Vec::new();
+        for gamepad in controllers {
+            stick += Vec2::new(
+                gamepad.get(settings.x_axis).unwrap_or(0.0),
+                // Stick Y points up, while window coordinates point down.
+                -gamepad.get(settings.y_axis).unwrap_or(0.0),
+            );
+            let buttons = core::iter::once((settings.primary_button, PointerButton::Primary))
+                .chain(
+                    settings
+                        .secondary_button
+                        .map(|button| (button, PointerButton::Secondary)),
+                );
+            for (button, pointer_button) in buttons {
+                if gamepad.just_pressed(button) {
+                    actions.push(PointerAction::Press(pointer_button));
+                }
+                if gamepad.just_released(button) {
+                    actions.push(PointerAction::Release(pointer_button));
+                }
+            }
+        }
+        let stick = stick.clamp_length_max(1.0);
+
+        let previous = pointer_location
+            .location()
+            .filter(|location| location.target == target)
+            .map(|location| location.position);
+        let mut position = previous.unwrap_or_else(|| bounds.center());
+
+        cursor.velocity = cursor.velocity.move_towards(
+            stick * settings.max_speed,
+            settings.acceleration * delta_secs,
+        );
+        position += cursor.velocity * delta_secs;
+
+        if settings.snap_strength > 0.0
+            && let Some(center) = snap_target(position, settings.snap_radius, &snap_nodes, &parents)
+        {
+            let decay_rate = settings.snap_strength * (1.0 - stick.length());
+            position.smooth_nudge(&center, decay_rate, delta_secs);
+        }
+        position = position.clamp(bounds.min, bounds.max);
+
+        if previous != Some(position) {
+            let delta = previous.map_or(Vec2::ZERO, |previous| position - previous);
+            pointer_inputs.write(PointerInput::new(
+                pointer_id,
+                Location {
+                    target: target.clone(),
+                    position,
+                },
+                PointerAction::Move { delta },
+            ));
+
+            let half_size = computed_node.map_or(Vec2::ZERO, |computed| {
+                0.5 * computed.size() * computed.inverse_scale_factor()
+            });
+            let ui_position = position / ui_scale.0;
+            node.left = Val::Px(ui_position.x - half_size.x);
+            node.top = Val::Px(ui_position.y - half_size.y);
+        }
+
+        for action in actions {
+            pointer_inputs.write(PointerInput::new(
+                pointer_id,
+                Location {
+                    target: target.clone(),
+                    position,
+                },
+                action,
+            ));
+        }
+    }
+}
+
+/// A visible node considered by [`snap_target`].
+struct SnapNode {
+    /// The entity of the node.
+    entity: Entity,
+    /// The rectangle covered by the node, in the logical pixels of the window.
+    rect: Rect,
+    /// The [`ComputedStackIndex`] of the node.
+    stack_index: u32,
+    /// How the node responds to picking.
+    pickable: Pickable,
+}
+
+/// Returns the center, in logical pixels, of the hoverable node closest to `position`, if one
+/// lies within `radius`.
+///
+/// Nodes whose center is covered by a node blocking lower nodes are skipped, unless the blocking
+/// node is one of their descendants, such as the text of a button.
+fn snap_target(
+    position: Vec2,
+    radius: f32,
+    nodes: &[SnapNode],
+    parents: &Query<&ChildOf>,
+) -> Option<Vec2> {
+    let snap_area = Rect::from_center_half_size(position, Vec2::splat(radius));
+    let is_hovered_at_center = |target: &SnapNode| {
+        let center = target.rect.center();
+        !nodes.iter().any(|node| {
+            node.stack_index > target.stack_index
+                && node.pickable.should_block_lower
+                && node.rect.contains(center)
+                && !parents
+                    .iter_ancestors(node.entity)
+                    .any(|ancestor| ancestor == target.entity)
+        })
+    };
+    nodes
+        .iter()
+        .filter(|node| node.pickable.is_hoverable && node.rect.union(snap_area) != node.rect)
+        .filter_map(|node| {
+            let distance = (position.clamp(node.rect.min, node.rect.max) - position).length();
+            (distance <= radius).then_some((distance, node.rect.center().distance(position), node))
+        })
+        .filter(|(.., node)| is_hovered_at_center(node))
+        .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+        .map(|(.., node)| node.rect.center())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{message::Messages, system::RunSystemOnce};
+    use bevy_input::gamepad::{RawGamepadButtonChangedEvent, RawGamepadEvent};
+    use core::time::Duration;
+
+    fn setup() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<GamepadCursorSettings>();
+        world.init_resource::<UiScale>();
+        world.init_resource::<Messages<PointerInput>>();
+        world.insert_resource(Time::<()>::default());
+        world.spawn((Window::default(), PrimaryWindow));
+        let gamepad = world.spawn(Gamepad::default()).id();
+        (world, gamepad)
+    }
+
+    /// Advances time, runs the cursor system, and feeds the resulting moves back to the pointer.
+    fn step(world: &mut World, cursor: Entity) -> Vec<PointerInput> {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(100));
+        world.run_system_once(gamepad_cursor_input).unwrap();
+        let inputs: Vec<_> = world
+            .resource_mut::<Messages<PointerInput>>()
+            .drain()
+            .collect();
+        for input in &inputs {
+            if matches!(input.action, PointerAction::Move { .. }) {
+                world.get_mut::<PointerLocation>(cursor).unwrap().location =
+                    Some(input.location.clone());
+            }
+        }
+        inputs
+    }
+
+    fn move_delta(inputs: &[PointerInput]) -> Vec2 {
+        match inputs {
+            [PointerInput {
+                action: PointerAction::Move { delta },
+                ..
+            }] => *delta,
+            _ => panic!("expected a single move, got {inputs:?}"),
+        }
+    }
+
+    fn cursor_position(world: &World, cursor: Entity) -> Vec2 {
+        world
+            .get::<PointerLocation>(cursor)
+            .and_then(PointerLocation::location)
+            .unwrap()
+            .position
+    }
+
+    #[test]
+    fn stick_accelerates_and_buttons_click() {
+        let (mut world, gamepad) = setup();
+        world.resource_mut::<GamepadCursorSettings>().snap_strength = 0.0;
+        let cursor = world.spawn(GamepadCursor::new(gamepad)).id();
+
+        // The first update places the cursor at the center of the window.
+        assert_eq!(move_delta(&step(&mut world, cursor)), Vec2::ZERO);
+        assert_eq!(
+            cursor_position(&world, cursor),
+            Window::default().size() / 2.0
+        );
+
+        world
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .analog_mut()
+            .set(GamepadAxis::LeftStickX, 1.0);
+        let first = move_delta(&step(&mut world, cursor));
+        let second = move_delta(&step(&mut world, cursor));
+        assert!(first.x > 0.0 && second.x > first.x);
+        assert_eq!(second.y, 0.0);
+
+        world
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .digital_mut()
+            .press(GamepadButton::South);
+        assert!(step(&mut world, cursor)
+            .iter()
+            .any(|input| matches!(input.action, PointerAction::Press(PointerButton::Primary))));
+    }
+
+    #[test]
+    fn cursor_snaps_to_nearby_nodes() {
+        let (mut world, gamepad) = setup();
+        let cursor = world.spawn(GamepadCursor::new(gamepad)).id();
+        let button = Window::default().size() / 2.0 + Vec2::new(30.0, 0.0);
+        world.spawn((
+            ComputedNode {
+                size: Vec2::splat(20.0),
+                inverse_scale_factor: 1.0,
+                ..Default::default()
+            },
+            UiGlobalTransform::from_translation(button),
+            Pickable::default(),
+            InheritedVisibility::VISIBLE,
+        ));
+
+        step(&mut world, cursor);
+        let mut previous = cursor_position(&world, cursor);
+        for _ in 0..20 {
+            step(&mut world, cursor);
+            let position = cursor_position(&world, cursor);
+            assert!(position.distance(button) <= previous.distance(button));
+            previous = position;
+        }
+        assert!(previous.distance(button) < 1.0);
+    }
+
+    #[test]
+    fn snapping_respects_picking() {
+        let (mut world, gamepad) = setup();
+        let cursor = world.spawn(GamepadCursor::new(gamepad)).id();
+        let center = Window::default().size() / 2.0;
+        let spawn_node = |world: &mut World, position: Vec2, size: Vec2, stack_index| {
+            world
+                .spawn((
+                    ComputedNode {
+                        size,
+                        inverse_scale_factor: 1.0,
+                        ..Default::default()
+                    },
+                    UiGlobalTransform::from_translation(position),
+                    ComputedStackIndex(stack_index),
+                    InheritedVisibility::VISIBLE,
+                ))
+                .id()
+        };
+        let button_size = Vec2::splat(20.0);
+
+        // Layout containers covering the snap area are not snapped to.
+        spawn_node(&mut world, center, Window::default().size(), 0);
+        // Nodes that can't be hovered are not snapped to.
+        let not_hoverable = spawn_node(&mut world, center + Vec2::new(0.0, 20.0), button_size, 1);
+        world.entity_mut(not_hoverable).insert(Pickable {
+            should_block_lower: false,
+            is_hoverable: false,
+        });
+        // Nodes hidden behind a blocking node are not snapped to.
+        spawn_node(&mut world, center - Vec2::new(25.0, 0.0), button_size, 1);
+        let overlay = spawn_node(&mut world, center - Vec2::new(25.0, 0.0), button_size, 3);
+        world.entity_mut(overlay).insert(Pickable {
+            should_block_lower: true,
+            is_hoverable: false,
+        });
+        // Nodes without `Pickable` are snapped to, even when their own children cover them.
+        let button = center + Vec2::new(30.0, 0.0);
+        let button_entity = spawn_node(&mut world, button, button_size, 1);
+        let text = spawn_node(&mut world, button, Vec2::splat(10.0), 2);
+        world.entity_mut(text).insert(ChildOf(button_entity));
+
+        step(&mut world, cursor);
+        for _ in 0..20 {
+            step(&mut world, cursor);
+        }
+        assert!(cursor_position(&world, cursor).distance(button) < 1.0);
+    }
+
+    #[test]
+    fn ui_scale_is_applied() {
+        let (mut world, gamepad) = setup();
+        world.insert_resource(UiScale(2.0));
+        let cursor = world
+            .spawn((
+                GamepadCursor::new(gamepad),
+                ComputedNode {
+                    size: Vec2::splat(32.0),
+                    inverse_scale_factor: 0.5,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        // The button is 20 logical pixels wide in the window, and its transform is in physical
+        // pixels, which match the logical pixels of a window with a scale factor of 1.
+        let button = Window::default().size() / 2.0 + Vec2::new(30.0, 0.0);
+        world.spawn((
+            ComputedNode {
+                size: Vec2::splat(20.0),
+                inverse_scale_factor: 0.5,
+                ..Default::default()
+            },
+            UiGlobalTransform::from_translation(button),
+            Pickable::default(),
+            InheritedVisibility::VISIBLE,
+        ));
+
+        step(&mut world, cursor);
+        for _ in 0..20 {
+            step(&mut world, cursor);
+        }
+        let position = cursor_position(&world, cursor);
+        assert!(position.distance(button) < 1.0);
+
+        // The cursor node is 16 UI pixels wide, and is placed in UI pixels.
+        let node = world.get::<Node>(cursor).unwrap();
+        assert_eq!(node.left, Val::Px(position.x / 2.0 - 8.0));
+        assert_eq!(node.top, Val::Px(position.y / 2.0 - 8.0));
+    }
+
+    #[test]
+    fn buttons_are_read_in_the_same_frame() {
+        let mut app = App::new();
+        app.add_plugins((bevy_input::InputPlugin, GamepadCursorPlugin))
+            .init_resource::<Time>()
+            .init_resource::<UiScale>()
+            .add_message::<PointerInput>();
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.update();
+
+        app.world_mut()
+            .write_message(RawGamepadEvent::from(RawGamepadButtonChangedEvent::new(
+                gamepad,
+                GamepadButton::South,
+                1.0,
+            )));
+        app.update();
+        assert!(app
+            .world_mut()
+            .resource_mut::<Messages<PointerInput>>()
+            .drain()
+            .any(|input| matches!(input.action, PointerAction::Press(PointerButton::Primary))));
+    }
+}
@@ -18,6 +18,8 @@ pub mod measurement;
 pub mod update;
 pub mod widget;
 
+#[cfg(feature = "gamepad_cursor")]
+pub mod gamepad_cursor;
 pub mod gradients;
 #[cfg(feature = "bevy_picking")]
 pub mod picking_backend;
@@ -56,6 +58,9 @@ pub use widget::TextNodeFlags;
 pub mod prelude {
     pub use crate::accessibility::AccessibleLabel;
     #[doc(hidden)]
+    #[cfg(feature = "gamepad_cursor")]
+    pub use crate::gamepad_cursor::{GamepadCursor, GamepadCursorPlugin, GamepadCursorSettings};
+    #[doc(hidden)]
     #[cfg(feature = "bevy_picking")]
     pub use crate::picking_backend::{UiPickingCamera, UiPickingPlugin, UiPickingSettings};
     #[doc(hidden)]
@@ -10,7 +10,7 @@ use bevy_ecs::{
     entity::Entity,
     reflect::ReflectComponent,
     system::{Local, Query, Res, ResMut},
-    world::Ref,
+    world::{Mut, Ref},
 };
 use bevy_image::prelude::*;
 use bevy_input_focus::InputFocus;
@@ -20,9 +20,10 @@ use bevy_reflect::std_traits::ReflectDefault;
 use bevy_reflect::Reflect;
 use bevy_text::{
     add_glyph_to_atlas, get_glyph_atlas_info, resolve_font_source, EditableText,
-    EditableTextGeneration, Font, FontAtlasKey, FontAtlasSet, FontCx, FontHinting, FontSize,
-    GlyphCacheKey, LayoutCx, LineBreak, LineHeight, PositionedGlyph, RemSize, RunGeometry, ScaleCx,
-    TextBrush, TextFont, TextLayout, TextLayoutInfo,
+    EditableTextGeneration, EditableTextMask, EditableTextPlaceholder, Font, FontAtlasKey,
+    FontAtlasSet, FontCx, FontHinting, FontSize, GlyphCacheKey, LayoutCx, LineBreak, LineHeight,
+    PositionedGlyph, RemSize, RunGeometry, ScaleCx, TextBrush, TextFont, TextLayout,
+    TextLayoutInfo,
 };
 use bevy_time::{Real, Time};
 use parley::{BoundingBox, PositionedLayoutItem, StyleProperty};
@@ -256,6 +257,9 @@ pub fn update_editable_text_styles(
 /// Refreshes the [`EditableText`]'s layout if stale and then writes it
 /// it to [`TextLayoutInfo`] for rendering and picking.
 /// Adds required glyphs to the texture atlas
+///
+/// Inputs with an [`EditableTextMask`] are laid out with their mask characters in place of their text,
+/// and empty inputs with an [`EditableTextPlaceholder`] display the placeholder's glyphs instead.
 pub fn update_editable_text_layout(
     mut font_cx: ResMut<FontCx>,
     mut layout_cx: ResMut<LayoutCx>,
@@ -271,6 +275,8 @@ pub fn update_editable_text_layout(
         &mut TextLayoutInfo,
         Ref<ComputedNode>,
         &mut EditableTextGeneration,
+        Option<(Mut<EditableTextPlaceholder>, &TextLayout)>,
+        Option<(Mut<EditableTextMask>, &TextLayout)>,
     )>,
     rem_size: Res<RemSize>,
     input_focus: Option<Res<InputFocus>>,
@@ -288,6 +294,8 @@ pub fn update_editable_text_layout(
         mut info,
         computed_node,
         mut generation,
+        placeholder,
+        mut mask,
     ) in input_field_query.iter_mut()
     {
         let cursor_width = editable_text.cursor_width;
@@ -299,21 +307,56 @@ pub fn update_editable_text_layout(
                 .set_width(Some(computed_node.content_box().width()));
         }
 
-        let mut driver = editable_text
+        editable_text
             .editor
-            .driver(font_cx.as_mut(), layout_cx.as_mut());
+            .refresh_layout(font_cx.as_mut(), layout_cx.as_mut());
 
-        driver.refresh_layout();
+        let compose_range = editable_text.editor.raw_compose().clone();
 
-        let compose_range = driver.editor.raw_compose().clone();
-
-        let layout_changed = driver.editor.generation() != **generation;
+        let layout_changed = editable_text.editor.generation() != **generation;
         if layout_changed {
-            **generation = driver.editor.generation();
+            **generation = editable_text.editor.generation();
         }
 
-        if layout_changed || hinting.is_changed() {
-            let layout = driver.layout();
+        // Masked inputs lay out one mask character per character of their text, with the same styles and selection.
+        let mask_changed = mask.as_ref().is_some_and(|(mask, _)| mask.is_changed());
+        if let Some((mask, text_layout)) = mask.as_mut()
+            && (layout_changed || mask_changed)
+        {
+            let mask = mask.bypass_change_detection();
+            mask.sync(&editable_text.editor, font_cx.as_mut(), layout_cx.as_mut());
+            let editor = mask.editor_mut();
+            editor.set_width(Some(computed_node.content_box().width()));
+            editor.set_alignment(text_layout.justify.into());
+            editor.refresh_layout(font_cx.as_mut(), layout_cx.as_mut());
+        }
+        let editor = match mask.as_ref() {
+            Some((mask, _)) => mask.editor(),
+            None => &editable_text.editor,
+        };
+
+        // The placeholder is laid out with the same styles as the input, and replaces its glyphs while it is empty.
+        let mut placeholder = placeholder.filter(|_| editable_text.editor.raw_text().is_empty());
+        let placeholder_changed = placeholder
+            .as_ref()
+            .is_some_and(|(placeholder, _)| placeholder.is_changed());
+        if let Some((placeholder, text_layout)) = placeholder.as_mut()
+            && (layout_changed || placeholder_changed)
+        {
+            let placeholder = placeholder.bypass_change_detection();
+            let text = placeholder.text.clone();
+            let editor = placeholder.editor_mut();
+            editor.set_text(&text);
+            *editor.edit_styles() = editable_text.editor.get_styles().clone();
+            editor.set_scale(editable_text.editor.get_scale());
+            editor.set_width(Some(computed_node.content_box().width()));
+            editor.set_alignment(text_layout.justify.into());
+        }
+
+        if layout_changed || hinting.is_changed() || placeholder_changed || mask_changed {
+            let Some(layout) = editor.try_layout() else {
+                continue;
+            };
 
             info.scale_factor = layout.scale();
             info.size = (layout.full_width(), layout.height()).into();
@@ -322,6 +365,13 @@ pub fn update_editable_text_layout(
             info.glyphs.clear();
             info.run_geometry.clear();
 
+            let layout = match placeholder.as_mut() {
+                Some((placeholder, _)) => placeholder
+                    .bypass_change_detection()
+                    .editor_mut()
+                    .layout(font_cx.as_mut(), layout_cx.as_mut()),
+                None => layout,
+            };
             for (line_index, line) in layout.lines().enumerate() {
                 for item in line.items() {
                     match item {
@@ -445,8 +495,7 @@ pub fn update_editable_text_layout(
                 }
             }
 
-            info.selection_rects = driver
-                .editor
+            info.selection_rects = editor
                 .selection_geometry()
                 .iter()
                 .map(|&b| bounding_box_to_rect(b.0))
@@ -469,16 +518,14 @@ pub fn update_editable_text_layout(
                 *cursor_timer = Duration::ZERO;
             }
 
-            info.cursor = driver
-                .editor
+            info.cursor = editor
                 .cursor_geometry(
                     cursor_width * text_font.font_size.eval(target.logical_size(), rem_size.0),
                 )
                 .map(bounding_box_to_rect)
                 .map(|rect| (*cursor_timer < cursor_blink_period / 2, rect));
         } else {
-            info.cursor = driver
-                .editor
+            info.cursor = editor
                 .cursor_geometry(0.)
                 .map(bounding_box_to_rect)
                 .map(|rect| (false, rect));
@@ -510,6 +557,7 @@ pub fn scroll_editable_text(
         &mut TextScroll,
         &ComputedNode,
         &TextLayoutInfo,
+        Option<&EditableTextMask>,
     )>,
 ) {
     let current_focus = input_focus
@@ -517,7 +565,7 @@ pub fn scroll_editable_text(
         .and_then(|input_focus| input_focus.get());
     let focus_changed = *previous_focus != current_focus;
 
-    for (entity, editable_text, generation, mut scroll, node, info) in query.iter_mut() {
+    for (entity, editable_text, generation, mut scroll, node, info, mask) in query.iter_mut() {
         if !(editable_text.is_changed()
             || generation.is_changed()
             || focus_changed && (Some(entity) == *previous_focus || Some(entity) == current_focus))
@@ -535,7 +583,8 @@ pub fn scroll_editable_text(
             continue;
         };
 
-        let Some(layout) = editable_text.editor.try_layout() else {
+        let editor = mask.map_or(&editable_text.editor, EditableTextMask::editor);
+        let Some(layout) = editor.try_layout() else {
             continue;
         };
 
@@ -597,3 +646,129 @@ fn scroll_axis(v_min: f32, v_max: f32, t_min: f32, t_max: f32) -> f32 {
         v_min
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_text::DEFAULT_FONT_DATA;
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let mut font_cx = FontCx::default();
+        font_cx.collection.register_fonts(
+            parley::fontique::Blob::from(DEFAULT_FONT_DATA.to_vec()),
+            None,
+        );
+        world.insert_resource(font_cx);
+        world.init_resource::<LayoutCx>();
+        world.init_resource::<ScaleCx>();
+        world.init_resource::<FontAtlasSet>();
+        world.init_resource::<Assets<Image>>();
+        world.insert_resource(RemSize(16.));
+        world.init_resource::<Time<Real>>();
+        world
+    }
+
+    /// Spawns an input containing `text`, laid out with the default font.
+    fn spawn_input(world: &mut World, text: &str) -> Entity {
+        let mut editable_text = EditableText::new(text);
+        editable_text
+            .editor
+            .edit_styles()
+            .insert(StyleProperty::FontFamily(parley::FontFamily::Single(
+                parley::FontFamilyName::Named("Fira Mono".into()),
+            )));
+        world
+            .spawn((
+                editable_text,
+                TextLayoutInfo::default(),
+                ComputedNode {
+                    size: Vec2::new(400., 20.),
+                    ..Default::default()
+                },
+                ComputedUiRenderTargetInfo::default(),
+            ))
+            .id()
+    }
+
+    /// Lays out every input, and returns the glyphs displayed by `entity`.
+    fn glyphs(world: &mut World, entity: Entity) -> Vec<PositionedGlyph> {
+        world.run_system_once(update_editable_text_layout).unwrap();
+        world.get::<TextLayoutInfo>(entity).unwrap().glyphs.clone()
+    }
+
+    #[test]
+    fn placeholder_is_displayed_while_empty() {
+        let mut world = setup();
+        let input = spawn_input(&mut world, "");
+        world
+            .entity_mut(input)
+            .insert(EditableTextPlaceholder::new("Name"));
+        assert_eq!(glyphs(&mut world, input).len(), 4);
+
+        world
+            .get_mut::<EditableTextPlaceholder>(input)
+            .unwrap()
+            .text = "Username".into();
+        assert_eq!(glyphs(&mut world, input).len(), 8);
+
+        world
+            .get_mut::<EditableText>(input)
+            .unwrap()
+            .editor_mut()
+            .set_text("ab");
+        assert_eq!(glyphs(&mut world, input).len(), 2);
+
+        world.get_mut::<EditableText>(input).unwrap().clear();
+        assert_eq!(glyphs(&mut world, input).len(), 8);
+    }
+
+    #[test]
+    fn placeholder_uses_the_input_styles() {
+        let mut world = setup();
+        let input = spawn_input(&mut world, "");
+        world
+            .entity_mut(input)
+            .insert(EditableTextPlaceholder::new("0"));
+        let text = spawn_input(&mut world, "0");
+        let default_size = glyphs(&mut world, input)[0].atlas_info.rect;
+
+        for entity in [input, text] {
+            world
+                .get_mut::<EditableText>(entity)
+                .unwrap()
+                .editor
+                .edit_styles()
+                .insert(StyleProperty::FontSize(32.));
+        }
+        let placeholder_glyph = glyphs(&mut world, input)[0].atlas_info.rect;
+        assert_ne!(placeholder_glyph, default_size);
+        assert_eq!(
+            placeholder_glyph,
+            glyphs(&mut world, text)[0].atlas_info.rect
+        );
+    }
+
+    #[test]
+    fn mask_replaces_glyphs_but_keeps_value() {
+        let mut world = setup();
+        let plain = spawn_input(&mut world, "pass");
+        let masked = spawn_input(&mut world, "pass");
+        world
+            .entity_mut(masked)
+            .insert((EditableTextMask::new('*'), TextLayout::default()));
+
+        let plain_glyphs = glyphs(&mut world, plain);
+        let masked_glyphs = glyphs(&mut world, masked);
+        assert_eq!(masked_glyphs.len(), 4);
+        assert!(masked_glyphs
+            .iter()
+            .all(|glyph| glyph.atlas_info.rect == masked_glyphs[0].atlas_info.rect));
+        assert_ne!(
+            plain_glyphs[0].atlas_info.rect,
+            plain_glyphs[1].atlas_info.rect
+        );
+        assert_eq!(world.get::<EditableText>(masked).unwrap().value(), "pass");
+    }
+}
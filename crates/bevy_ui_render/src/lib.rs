@@ -66,8 +66,9 @@ use gradient::GradientPlugin;
 
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_text::{
-    ComputedTextBlock, PositionedGlyph, Strikethrough, StrikethroughColor, TextBackgroundColor,
-    TextColor, TextCursorStyle, TextLayoutInfo, Underline, UnderlineColor,
+    ComputedTextBlock, EditableText, EditableTextPlaceholder, PositionedGlyph, Strikethrough,
+    StrikethroughColor, TextBackgroundColor, TextColor, TextCursorStyle, TextLayoutInfo, Underline,
+    UnderlineColor,
 };
 use bevy_transform::components::GlobalTransform;
 use box_shadow::BoxShadowPlugin;
@@ -969,6 +970,7 @@ pub fn extract_text_sections(
             &TextLayoutInfo,
             Option<&TextScroll>,
             Option<&TextCursorStyle>,
+            Option<(&EditableText, &EditableTextPlaceholder)>,
         )>,
     >,
     text_styles: Extract<Query<&TextColor>>,
@@ -991,6 +993,7 @@ pub fn extract_text_sections(
         text_layout_info,
         text_scroll,
         cursor_style,
+        placeholder,
     ) in &uinode_query
     {
         // Skip if not visible or if size is set to zero (e.g. when a parent is set to `Display::None`)
@@ -1018,7 +1021,12 @@ pub fn extract_text_sections(
             maybe_clip.map(|clip| clip.clip)
         };
 
-        let mut color = text_color.0.to_linear();
+        // An empty editable text node renders its placeholder glyphs instead
+        let placeholder_color = placeholder
+            .filter(|(editable_text, _)| editable_text.editor.raw_text().is_empty())
+            .map(|(_, placeholder)| placeholder.color.to_linear());
+
+        let mut color = placeholder_color.unwrap_or_else(|| text_color.0.to_linear());
 
         let selected_text_color = cursor_style
             .and_then(|cursor_style| cursor_style.selected_text_color)
@@ -1037,6 +1045,7 @@ pub fn extract_text_sections(
         ) in text_layout_info.glyphs.iter().enumerate()
         {
             if current_section_index != *section_index
+                && placeholder_color.is_none()
                 && let Some(section_entity) = computed_block
                     .entities()
                     .get(*section_index as usize)
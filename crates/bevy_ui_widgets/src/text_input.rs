@@ -3,6 +3,9 @@
 //! This module provides systems to process keyboard input events and apply text edits
 //! to focused [`EditableText`] widgets.
 //!
+//! Pressing Enter on a single-line input (or Ctrl + Enter / Cmd + Enter on a multi-line one)
+//! triggers a [`TextInputSubmit`] event carrying the value, once the edits queued before it are applied.
+//!
 //! Note that this module is distinct from the core `bevy_text` crate to avoid pulling in
 //! [`bevy_input`] to that crate, which is intended to be usable in non-interactive contexts.
 
@@ -18,7 +21,7 @@ use bevy_math::Vec2;
 use bevy_picking::events::{Drag, Pointer, Press, Release};
 use bevy_picking::pointer::PointerButton;
 use bevy_reflect::Reflect;
-use bevy_text::{EditableText, PreeditCursor, TextEdit};
+use bevy_text::{EditableText, EditableTextSystems, PreeditCursor, TextEdit};
 use bevy_ui::widget::{scroll_editable_text, update_editable_text_layout, TextScroll};
 use bevy_ui::UiSystems;
 use bevy_ui::{
@@ -27,6 +30,21 @@ use bevy_ui::{
 };
 use bevy_window::{Ime, PrimaryWindow, Window};
 
+/// Event triggered when the user submits a focused [`EditableText`] input.
+///
+/// Single-line inputs submit on Enter, while inputs that allow newlines submit on
+/// Ctrl + Enter (Cmd + Enter on macOS). The event is not triggered while the IME is composing.
+///
+/// The event is triggered in [`PostUpdate`], after the edits typed before the submission are applied.
+#[derive(Clone, Debug, PartialEq, EntityEvent, Reflect)]
+#[reflect(Event)]
+pub struct TextInputSubmit {
+    /// The submitted [`EditableText`] entity.
+    pub entity: Entity,
+    /// The value of the input, including every edit typed before the submission.
+    pub value: String,
+}
+
 const NONE: u8 = 0;
 const SUPER: u8 = 1;
 const CTRL: u8 = 2;
@@ -57,8 +75,10 @@ fn on_focused_keyboard_input(
     mut keyboard_input: On<FocusedInput<KeyboardInput>>,
     mut query: Query<&mut EditableText>,
     keys: Res<ButtonInput<Key>>,
+    mut queued_submits: ResMut<QueuedSubmits>,
 ) {
-    let Ok(mut editable_text) = query.get_mut(keyboard_input.focused_entity) else {
+    let entity = keyboard_input.focused_entity;
+    let Ok(mut editable_text) = query.get_mut(entity) else {
         return; // Focused entity is not an EditableText, nothing to do
     };
 
@@ -81,6 +101,7 @@ fn on_focused_keyboard_input(
         | (SHIFT * u8::from(keys.pressed(Key::Shift)));
 
     let shift_pressed = (mod_flags & SHIFT) != 0;
+    let is_fresh_press = keyboard_input.input.state.is_pressed() && !keyboard_input.input.repeat;
 
     let mut should_propagate = true;
 
@@ -105,6 +126,12 @@ fn on_focused_keyboard_input(
         (COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("v") => {
             queue_edit(TextEdit::Paste);
         }
+        (COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("z") => queue_edit(TextEdit::Undo),
+        (SHIFT_COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("z") => {
+            queue_edit(TextEdit::Redo);
+        }
+        #[cfg(not(target_os = "macos"))]
+        (CTRL, Key::Character(c)) if c.eq_ignore_ascii_case("y") => queue_edit(TextEdit::Redo),
         #[cfg(not(target_os = "macos"))]
         (SHIFT, Key::Delete) => queue_edit(TextEdit::Cut),
         (WORD, Key::Backspace) => queue_edit(TextEdit::BackspaceWord),
@@ -142,6 +169,14 @@ fn on_focused_keyboard_input(
         (NONE, Key::Enter) if allow_newlines => {
             queue_edit(TextEdit::Insert("\n".into()));
         }
+        // Submission still propagates, so that parent widgets (e.g. forms) can react to Enter.
+        (NONE | COMMAND, Key::Enter)
+            if is_fresh_press
+                && allow_newlines == (mod_flags == COMMAND)
+                && !queued_submits.0.contains(&entity) =>
+        {
+            queued_submits.0.push(entity);
+        }
         _ => {
             // Ignore and propagate to allow for tab navigation and submit actions.
         }
@@ -457,6 +492,35 @@ fn apply_queued_select_all(
     }
 }
 
+/// Resource to track submitted [`EditableText`] inputs.
+/// The [`TextInputSubmit`] event is triggered once the edits queued before the submission are applied.
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+struct QueuedSubmits(Vec<Entity>);
+
+/// Triggers [`TextInputSubmit`] for the inputs submitted by `on_focused_keyboard_input`,
+/// now that the [`apply_text_edits`](`bevy_text::apply_text_edits`) system has applied their pending edits.
+fn trigger_queued_submits(
+    mut queued_submits: ResMut<QueuedSubmits>,
+    q_text_input: Query<&EditableText>,
+    mut commands: Commands,
+) {
+    queued_submits.0.retain(|&entity| {
+        let Ok(editable_text) = q_text_input.get(entity) else {
+            return false;
+        };
+        // Wait for pastes still reading the clipboard, so that the value includes them.
+        if editable_text.pending_paste.is_some() {
+            return true;
+        }
+        commands.trigger(TextInputSubmit {
+            entity,
+            value: editable_text.value().to_string(),
+        });
+        false
+    });
+}
+
 /// System sets for IME-related systems used by [`EditableTextInputPlugin`].
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ImeSystems {
@@ -488,6 +552,7 @@ pub struct EditableTextInputPlugin;
 impl Plugin for EditableTextInputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<QueuedSelectAll>()
+            .init_resource::<QueuedSubmits>()
             .add_observer(on_focused_keyboard_input)
             .add_observer(on_pointer_drag)
             .add_observer(on_pointer_press)
@@ -521,6 +586,10 @@ impl Plugin for EditableTextInputPlugin {
                 apply_queued_select_all
                     .in_set(UiSystems::PostLayout)
                     .before(update_editable_text_layout),
+            )
+            .add_systems(
+                PostUpdate,
+                trigger_queued_submits.after(EditableTextSystems),
             );
 
         // These components cannot be registered in `bevy_text` where `EditableText` is defined,
@@ -531,3 +600,142 @@ impl Plugin for EditableTextInputPlugin {
             .register_required_components::<EditableText, TextScroll>();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_clipboard::{Clipboard, ClipboardRead};
+    use bevy_ecs::{message::Messages, system::RunSystemOnce};
+    use bevy_input::{
+        keyboard::{KeyCode, NativeKeyCode},
+        ButtonState,
+    };
+    use bevy_input_focus::dispatch_focused_input;
+    use bevy_platform::sync::{Arc, Mutex};
+    use bevy_text::{apply_text_edits, FontCx, LayoutCx};
+
+    /// The values of every [`TextInputSubmit`] triggered so far.
+    #[derive(Resource, Default)]
+    struct Submitted(Vec<String>);
+
+    fn setup(allow_newlines: bool) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<Key>>();
+        world.init_resource::<Messages<KeyboardInput>>();
+        world.init_resource::<QueuedSubmits>();
+        world.init_resource::<FontCx>();
+        world.init_resource::<LayoutCx>();
+        world.init_resource::<Clipboard>();
+        world.init_resource::<Submitted>();
+        world.spawn((Window::default(), PrimaryWindow));
+        let input = world
+            .spawn(EditableText {
+                allow_newlines,
+                ..Default::default()
+            })
+            .id();
+        world.insert_resource(InputFocus::from_entity(input));
+        world.add_observer(on_focused_keyboard_input);
+        world.add_observer(
+            |submit: On<TextInputSubmit>, mut submitted: ResMut<Submitted>| {
+                submitted.0.push(submit.value.clone());
+            },
+        );
+        (world, input)
+    }
+
+    /// Presses and releases each key on the focused input.
+    fn type_keys(world: &mut World, keys: &[Key]) {
+        let window = world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(world)
+            .unwrap();
+        for key in keys {
+            let text = match key {
+                Key::Character(text) => Some(text.clone()),
+                _ => None,
+            };
+            for state in [ButtonState::Pressed, ButtonState::Released] {
+                world.write_message(KeyboardInput {
+                    key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+                    logical_key: key.clone(),
+                    state,
+                    text: text.clone(),
+                    repeat: false,
+                    window,
+                });
+            }
+        }
+        world
+            .run_system_once(dispatch_focused_input::<KeyboardInput>)
+            .unwrap();
+        world.resource_mut::<Messages<KeyboardInput>>().clear();
+    }
+
+    /// Applies the queued edits, then returns the values submitted since the last call.
+    fn submitted(world: &mut World) -> Vec<String> {
+        world.run_system_once(apply_text_edits).unwrap();
+        world.run_system_once(trigger_queued_submits).unwrap();
+        core::mem::take(&mut world.resource_mut::<Submitted>().0)
+    }
+
+    fn command_key() -> Key {
+        if cfg!(target_os = "macos") {
+            Key::Super
+        } else {
+            Key::Control
+        }
+    }
+
+    #[test]
+    fn enter_submits_single_line_inputs() {
+        let (mut world, _) = setup(false);
+
+        // The value includes the edits typed in the same frame as the submission.
+        type_keys(
+            &mut world,
+            &[
+                Key::Character("h".into()),
+                Key::Character("i".into()),
+                Key::Enter,
+            ],
+        );
+        assert_eq!(submitted(&mut world), ["hi"]);
+
+        world
+            .resource_mut::<ButtonInput<Key>>()
+            .press(command_key());
+        type_keys(&mut world, &[Key::Enter]);
+        assert!(submitted(&mut world).is_empty());
+    }
+
+    #[test]
+    fn ctrl_enter_submits_multi_line_inputs() {
+        let (mut world, input) = setup(true);
+
+        type_keys(&mut world, &[Key::Character("a".into()), Key::Enter]);
+        assert!(submitted(&mut world).is_empty());
+        assert_eq!(world.get::<EditableText>(input).unwrap().value(), "a\n");
+
+        world
+            .resource_mut::<ButtonInput<Key>>()
+            .press(command_key());
+        type_keys(&mut world, &[Key::Enter]);
+        assert_eq!(submitted(&mut world), ["a\n"]);
+    }
+
+    #[test]
+    fn submit_waits_for_pending_paste() {
+        let (mut world, input) = setup(false);
+        let read = Arc::new(Mutex::new(None));
+        world.get_mut::<EditableText>(input).unwrap().pending_paste =
+            Some(ClipboardRead::Pending(read.clone()));
+
+        type_keys(&mut world, &[Key::Enter]);
+        assert!(submitted(&mut world).is_empty());
+        assert!(submitted(&mut world).is_empty());
+
+        *read.lock().unwrap() = Some(Ok("pasted".into()));
+        assert_eq!(submitted(&mut world), ["pasted"]);
+    }
+}
@@ -4,9 +4,10 @@ use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
     reflect::{AppTypeRegistry, ReflectComponent},
+    stable_id::StableId,
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use bevy_reflect::{FromReflect, PartialReflect, TypePath, TypeRegistry};
 
 use bevy_ecs::component::ComponentCloneBehavior;
 use bevy_ecs::relationship::RelationshipHookMode;
@@ -40,6 +41,18 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn PartialReflect>>,
 }
 
+impl DynamicEntity {
+    /// Returns the [`StableId`] of this entity, if it has one.
+    pub fn stable_id(&self) -> Option<StableId> {
+        self.components.iter().find_map(|component| {
+            component
+                .get_represented_type_info()
+                .filter(|info| info.is::<StableId>())
+                .and_then(|_| StableId::from_reflect(component.as_partial_reflect()))
+        })
+    }
+}
+
 impl DynamicWorld {
     /// Create a new dynamic world from a given world.
     ///
@@ -86,6 +99,9 @@ impl DynamicWorld {
 
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
+    /// Dynamic entities that are not in the `entity_map` yet are written to the live entity with the same
+    /// [`StableId`], if they have one and such an entity exists. Otherwise, a new entity is spawned.
+    ///
     /// This method will return a [`WorldInstanceSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
@@ -98,12 +114,15 @@ impl DynamicWorld {
         // First ensure that every entity in the dynamic world has a corresponding world
         // entity in the entity map.
         for dynamic_entity in &self.entities {
-            // Fetch the entity with the given entity id from the `entity_map`
-            // or spawn a new entity with a transiently unique id if there is
-            // no corresponding entry.
-            entity_map
-                .entry(dynamic_entity.entity)
-                .or_insert_with(|| world.spawn_empty().id());
+            // Fetch the entity with the given entity id from the `entity_map`,
+            // or the live entity with the same stable id, or spawn a new entity
+            // with a transiently unique id if there is no corresponding entry.
+            entity_map.entry(dynamic_entity.entity).or_insert_with(|| {
+                dynamic_entity
+                    .stable_id()
+                    .and_then(|id| world.entity_by_stable_id(id))
+                    .unwrap_or_else(|| world.spawn_empty().id())
+            });
         }
 
         for dynamic_entity in &self.entities {
@@ -251,6 +270,7 @@ mod tests {
         hierarchy::ChildOf,
         reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
         resource::Resource,
+        stable_id::StableId,
         world::World,
     };
 
@@ -435,4 +455,47 @@ mod tests {
             .write_to_world(&mut dst_world, &mut Default::default())
             .unwrap();
     }
+
+    #[test]
+    fn entities_with_stable_id_are_written_to_live_entity() {
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Health(u32);
+
+        let reg = AppTypeRegistry::default();
+        {
+            let mut reg_write = reg.write();
+            reg_write.register::<StableId>();
+            reg_write.register::<Health>();
+        }
+
+        let mut world = World::new();
+        world.insert_resource(reg.clone());
+        let with_id = world.spawn((StableId(7), Health(10))).id();
+        let without_id = world.spawn(Health(20)).id();
+        let dynamic_world = {
+            let type_registry = reg.read();
+            DynamicWorldBuilder::from_world(&world, &type_registry)
+                .extract_entity(without_id)
+                .extract_entity(with_id)
+                .build()
+        };
+        assert_eq!(dynamic_world.entities[1].stable_id(), Some(StableId(7)));
+        assert_eq!(dynamic_world.entities[0].stable_id(), None);
+
+        // Entities with a stable id are serialized first.
+        let serialized = dynamic_world.serialize(&reg.read()).unwrap();
+        assert!(serialized.find("Health\": (10)") < serialized.find("Health\": (20)"));
+
+        world.entity_mut(with_id).insert(Health(0));
+        let mut entity_map = EntityHashMap::default();
+        dynamic_world
+            .write_to_world(&mut world, &mut entity_map)
+            .unwrap();
+
+        assert_eq!(entity_map[&with_id], with_id);
+        assert_eq!(world.get::<Health>(with_id), Some(&Health(10)));
+        assert_ne!(entity_map[&without_id], without_id);
+        assert_eq!(world.entity_by_stable_id(StableId(7)), Some(with_id));
+    }
 }
@@ -113,8 +113,16 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
     where
         S: Serializer,
     {
+        // Entities with a `StableId` are written first, in the order of their ids,
+        // so that saving the same world twice gives the same output regardless of spawn order.
+        let mut entities: Vec<&DynamicEntity> = self.entities.iter().collect();
+        entities.sort_by_cached_key(|entity| {
+            let id = entity.stable_id();
+            (id.is_none(), id)
+        });
+
         let mut state = serializer.serialize_map(Some(self.entities.len()))?;
-        for entity in self.entities {
+        for entity in entities {
             state.serialize_entry(
                 &entity.entity,
                 &EntitySerializer {